@@ -2,6 +2,13 @@ use clap::builder::{IntoResettable, OsStr};
 use clap::{value_parser, Arg, ArgAction};
 use std::path::PathBuf;
 
+use picturify_core::conversions::icc::WorkingSpace;
+
+use crate::commands::parsers::crop_border::CropBorderValueParser;
+use crate::commands::parsers::image_format::ImageFormatValueParser;
+use crate::commands::parsers::size::SizeValueParser;
+use crate::common::batch::ExistingPolicyValueParser;
+
 pub trait PicturifyArg {
     fn create(default_value: impl IntoResettable<OsStr>) -> Arg;
     fn id() -> &'static str;
@@ -14,7 +21,7 @@ impl PicturifyArg for InputArg {
         Arg::new(Self::id())
             .short('i')
             .long("input")
-            .help("Input path")
+            .help("Input path, directory or glob pattern, use - to read from stdin")
             .required(true)
             .default_value(default_value)
             .value_parser(value_parser!(PathBuf))
@@ -32,7 +39,9 @@ impl PicturifyArg for OutputArg {
         Arg::new(Self::id())
             .short('o')
             .long("output")
-            .help("Output path")
+            .help(
+                "Output path or directory when processing multiple files, use - to write to stdout",
+            )
             .required(true)
             .default_value(default_value)
             .value_parser(value_parser!(PathBuf))
@@ -43,6 +52,151 @@ impl PicturifyArg for OutputArg {
     }
 }
 
+pub struct FormatArg;
+
+impl PicturifyArg for FormatArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("format")
+            .help("Output format (e.g. png, jpg), required when writing to stdout")
+            .default_value(default_value)
+            .value_parser(ImageFormatValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "format"
+    }
+}
+
+pub struct ExistingArg;
+
+impl PicturifyArg for ExistingArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("existing")
+            .help("What to do when the output file already exists (overwrite, skip)")
+            .default_value(default_value)
+            .value_parser(ExistingPolicyValueParser)
+    }
+
+    fn id() -> &'static str {
+        "existing"
+    }
+}
+
+pub struct NameTemplateArg;
+
+impl PicturifyArg for NameTemplateArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("name-template")
+            .help("Output file name template used when processing multiple files, supports {stem}, {filter} and {ext}")
+            .default_value(default_value)
+            .value_parser(value_parser!(String))
+    }
+
+    fn id() -> &'static str {
+        "name-template"
+    }
+}
+
+pub struct TileHeightArg;
+
+impl PicturifyArg for TileHeightArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("tile-height")
+            .help("Stream the image in tiles of this many rows to bound memory usage (png and tiff only)")
+            .default_value(default_value)
+            .value_parser(value_parser!(usize))
+    }
+
+    fn id() -> &'static str {
+        "tile-height"
+    }
+}
+
+pub struct RoiArg;
+
+impl PicturifyArg for RoiArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("roi")
+            .help("Only process this region, in format <width>x<height>+<left>+<top>")
+            .default_value(default_value)
+            .value_parser(CropBorderValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "roi"
+    }
+}
+
+pub struct MaskArg;
+
+impl PicturifyArg for MaskArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("mask")
+            .help("Grayscale mask image, the filter is applied where the mask is white")
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "mask"
+    }
+}
+
+pub struct FeatherArg;
+
+impl PicturifyArg for FeatherArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("feather")
+            .help("Radius used to soften the mask edge")
+            .default_value(default_value)
+            .value_parser(value_parser!(usize))
+    }
+
+    fn id() -> &'static str {
+        "feather"
+    }
+}
+
+pub struct WorkingSpaceArg;
+
+impl PicturifyArg for WorkingSpaceArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("working-space")
+            .help("Color space the image is processed in (srgb, linear-srgb, display-p3, rec2020), embedded RGB and gray ICC profiles are converted into it, output is converted back to sRGB")
+            .default_value(default_value)
+            .value_parser(value_parser!(WorkingSpace))
+    }
+
+    fn id() -> &'static str {
+        "working-space"
+    }
+}
+
+pub struct IndexedArg;
+
+impl PicturifyArg for IndexedArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("indexed")
+            .help("Write a paletted png or gif, images with more than 256 colors are quantized")
+            .action(ArgAction::SetTrue)
+            .default_value(default_value)
+            .value_parser(value_parser!(bool))
+    }
+
+    fn id() -> &'static str {
+        "indexed"
+    }
+}
+
 pub struct FastArg;
 
 impl PicturifyArg for FastArg {
@@ -78,3 +232,35 @@ impl PicturifyArg for GpuArg {
         "gpu"
     }
 }
+
+pub struct SeedArg;
+
+impl PicturifyArg for SeedArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("seed")
+            .help("Seed for the random number generator, random when omitted")
+            .default_value(default_value)
+            .value_parser(value_parser!(u64))
+    }
+
+    fn id() -> &'static str {
+        "seed"
+    }
+}
+
+pub struct SizeArg;
+
+impl PicturifyArg for SizeArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("size")
+            .help("Size of the generated image, <width>x<height>")
+            .default_value(default_value)
+            .value_parser(SizeValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "size"
+    }
+}
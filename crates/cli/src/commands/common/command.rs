@@ -1,7 +1,36 @@
-use crate::commands::common::args::common::{InputArg, OutputArg, PicturifyArg};
+use crate::commands::common::args::common::{
+    ExistingArg, FeatherArg, FormatArg, IndexedArg, InputArg, MaskArg, NameTemplateArg, OutputArg,
+    PicturifyArg, RoiArg, SizeArg, TileHeightArg, WorkingSpaceArg,
+};
 use crate::common::filter_group::Group;
 use colored::Colorize;
 
+struct ImageBaseDefaultArgs {
+    existing: &'static str,
+    name_template: &'static str,
+    feather: &'static str,
+    working_space: &'static str,
+    indexed: &'static str,
+}
+
+const IMAGE_BASE_DEFAULT_ARGS: ImageBaseDefaultArgs = ImageBaseDefaultArgs {
+    existing: "overwrite",
+    name_template: "{stem}_{filter}.{ext}",
+    feather: "0",
+    working_space: "srgb",
+    indexed: "false",
+};
+
+struct GeneratorBaseDefaultArgs {
+    size: &'static str,
+    indexed: &'static str,
+}
+
+const GENERATOR_BASE_DEFAULT_ARGS: GeneratorBaseDefaultArgs = GeneratorBaseDefaultArgs {
+    size: "512x512",
+    indexed: "false",
+};
+
 pub trait Command {
     fn create() -> clap::Command;
 }
@@ -13,6 +42,19 @@ pub trait CommandForImage {
             .about(Self::about())
             .arg(InputArg::create(None))
             .arg(OutputArg::create(None))
+            .arg(FormatArg::create(None))
+            .arg(ExistingArg::create(IMAGE_BASE_DEFAULT_ARGS.existing))
+            .arg(NameTemplateArg::create(
+                IMAGE_BASE_DEFAULT_ARGS.name_template,
+            ))
+            .arg(TileHeightArg::create(None))
+            .arg(RoiArg::create(None))
+            .arg(MaskArg::create(None))
+            .arg(FeatherArg::create(IMAGE_BASE_DEFAULT_ARGS.feather))
+            .arg(WorkingSpaceArg::create(
+                IMAGE_BASE_DEFAULT_ARGS.working_space,
+            ))
+            .arg(IndexedArg::create(IMAGE_BASE_DEFAULT_ARGS.indexed))
     }
     fn name() -> &'static str;
     fn display_name() -> String {
@@ -31,7 +73,10 @@ pub trait CommandForImage {
 pub trait CommandForMovie {
     fn get() -> clap::Command;
     fn get_base() -> clap::Command {
-        clap::Command::new(Self::name()).about(Self::about())
+        clap::Command::new(Self::name())
+            .about(Self::about())
+            .arg(InputArg::create(None))
+            .arg(OutputArg::create(None))
     }
     fn name() -> &'static str;
     fn group() -> Group;
@@ -39,3 +84,19 @@ pub trait CommandForMovie {
         format!("{} Run {} filter on the movie", Self::group(), Self::name())
     }
 }
+
+pub trait CommandForGenerator {
+    fn get() -> clap::Command;
+    fn get_base() -> clap::Command {
+        clap::Command::new(Self::name())
+            .about(Self::about())
+            .arg(OutputArg::create(None))
+            .arg(FormatArg::create(None))
+            .arg(SizeArg::create(GENERATOR_BASE_DEFAULT_ARGS.size))
+            .arg(IndexedArg::create(GENERATOR_BASE_DEFAULT_ARGS.indexed))
+    }
+    fn name() -> &'static str;
+    fn about() -> String {
+        format!("Generate a {} image", Self::name().replace('-', " ").bold())
+    }
+}
@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg, ArgAction};
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::Command;
+
+struct CompareDefaultArgs {
+    json: &'static str,
+    threshold: &'static str,
+}
+
+const DEFAULT_ARGS: CompareDefaultArgs = CompareDefaultArgs {
+    json: "false",
+    threshold: "0",
+};
+
+pub struct CompareFirstArg;
+
+impl PicturifyArg for CompareFirstArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .help("Reference image path")
+            .required(true)
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "first"
+    }
+}
+
+pub struct CompareSecondArg;
+
+impl PicturifyArg for CompareSecondArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .help("Image path compared against the reference, must have the same size")
+            .required(true)
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "second"
+    }
+}
+
+pub struct CompareJsonArg;
+
+impl PicturifyArg for CompareJsonArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("json")
+            .help("Print the statistics and metrics as JSON, infinite PSNR is written as null")
+            .action(ArgAction::SetTrue)
+            .default_value(default_value)
+            .value_parser(value_parser!(bool))
+    }
+
+    fn id() -> &'static str {
+        "json"
+    }
+}
+
+pub struct CompareDiffArg;
+
+impl PicturifyArg for CompareDiffArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('d')
+            .long("diff")
+            .help("Write an image with the changed pixels highlighted in red to this path")
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "diff"
+    }
+}
+
+pub struct CompareThresholdArg;
+
+impl PicturifyArg for CompareThresholdArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('t')
+            .long("threshold")
+            .help("Largest channel difference (0-255) not highlighted in the diff image")
+            .default_value(default_value)
+            .value_parser(value_parser!(u8))
+    }
+
+    fn id() -> &'static str {
+        "threshold"
+    }
+}
+
+pub struct CompareCommand;
+
+impl Command for CompareCommand {
+    fn create() -> clap::Command {
+        clap::Command::new("compare")
+            .about("Print statistics of two images and metrics comparing them")
+            .disable_version_flag(true)
+            .arg(CompareFirstArg::create(None))
+            .arg(CompareSecondArg::create(None))
+            .arg(CompareJsonArg::create(DEFAULT_ARGS.json))
+            .arg(CompareDiffArg::create(None))
+            .arg(CompareThresholdArg::create(DEFAULT_ARGS.threshold))
+    }
+}
@@ -0,0 +1,23 @@
+use crate::commands::common::command::{Command, CommandForGenerator};
+use crate::commands::generate::checkerboard::CheckerboardCommand;
+use crate::commands::generate::color_bars::ColorBarsCommand;
+use crate::commands::generate::gradient::GradientCommand;
+use crate::commands::generate::perlin_noise::PerlinNoiseCommand;
+use crate::commands::generate::zone_plate::ZonePlateCommand;
+
+pub struct GenerateCommand;
+
+impl Command for GenerateCommand {
+    fn create() -> clap::Command {
+        clap::Command::new("generate")
+            .about("Generate a synthetic image")
+            .disable_version_flag(true)
+            .subcommands(&[
+                CheckerboardCommand::get(),
+                GradientCommand::get(),
+                ColorBarsCommand::get(),
+                ZonePlateCommand::get(),
+                PerlinNoiseCommand::get(),
+            ])
+    }
+}
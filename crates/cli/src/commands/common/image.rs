@@ -1,23 +1,51 @@
 use crate::commands::common::command::{Command, CommandForImage};
 use crate::commands::image::blob::laplacian_of_gaussian::LaplacianOfGaussianCommand;
 use crate::commands::image::color::brightness::BrightnessCommand;
+use crate::commands::image::color::channel_extraction::ChannelExtractionCommand;
+use crate::commands::image::color::channel_merge::ChannelMergeCommand;
+use crate::commands::image::color::curves::CurvesCommand;
+use crate::commands::image::color::extract_alpha::ExtractAlphaCommand;
+use crate::commands::image::color::flatten::FlattenCommand;
 use crate::commands::image::color::grayscale::GrayscaleCommand;
+use crate::commands::image::color::hue_rotation::HueRotationCommand;
+use crate::commands::image::color::levels::LevelsCommand;
+use crate::commands::image::color::lightness::LightnessCommand;
+use crate::commands::image::color::lut::LutCommand;
 use crate::commands::image::color::negative::NegativeCommand;
+use crate::commands::image::color::palette_quantization::PaletteQuantizationCommand;
 use crate::commands::image::color::quantization::QuantizationCommand;
+use crate::commands::image::color::saturation::SaturationCommand;
 use crate::commands::image::color::sepia::SepiaCommand;
+use crate::commands::image::color::set_alpha::SetAlphaCommand;
+use crate::commands::image::color::temperature::TemperatureCommand;
+use crate::commands::image::color::white_balance::WhiteBalanceCommand;
 use crate::commands::image::common::passthrough::PassthroughCommand;
+use crate::commands::image::edge::cartoon::CartoonCommand;
+use crate::commands::image::edge::difference_of_gaussians::DifferenceOfGaussiansCommand;
 use crate::commands::image::edge::emboss::EmbossCommand;
+use crate::commands::image::edge::gradient::GradientCommand;
 use crate::commands::image::edge::prewitt::PrewittCommand;
 use crate::commands::image::edge::sobel::SobelCommand;
+use crate::commands::image::edge::xdog::XDogCommand;
 use crate::commands::image::geometry::crop::CropCommand;
 use crate::commands::image::geometry::rotate::RotateCommand;
 use crate::commands::image::geometry::scale::ScaleCommand;
+use crate::commands::image::noise::anisotropic_diffusion::AnisotropicDiffusionCommand;
 use crate::commands::image::noise::bilateral_blur::BilateralBlurCommand;
+use crate::commands::image::noise::convolve::ConvolveCommand;
 use crate::commands::image::noise::gaussian_blur::GaussianBlurCommand;
+use crate::commands::image::noise::gaussian_noise::GaussianNoiseCommand;
 use crate::commands::image::noise::kuwahara::KuwaharaCommand;
 use crate::commands::image::noise::mean_blur::MeanBlurCommand;
 use crate::commands::image::noise::median_blur::MedianBlurCommand;
+use crate::commands::image::noise::motion_blur::MotionBlurCommand;
+use crate::commands::image::noise::non_local_means::NonLocalMeansCommand;
+use crate::commands::image::noise::poisson_noise::PoissonNoiseCommand;
+use crate::commands::image::noise::radial_blur::RadialBlurCommand;
+use crate::commands::image::noise::salt_and_pepper_noise::SaltAndPepperNoiseCommand;
 use crate::commands::image::noise::sharpen::SharpenCommand;
+use crate::commands::image::noise::speckle_noise::SpeckleNoiseCommand;
+use crate::commands::image::noise::zoom_blur::ZoomBlurCommand;
 
 pub struct ImageCommand;
 
@@ -37,6 +65,20 @@ impl Command for ImageCommand {
                 GrayscaleCommand::get(),
                 BrightnessCommand::get(),
                 QuantizationCommand::get(),
+                ExtractAlphaCommand::get(),
+                SetAlphaCommand::get(),
+                FlattenCommand::get(),
+                HueRotationCommand::get(),
+                SaturationCommand::get(),
+                LightnessCommand::get(),
+                ChannelExtractionCommand::get(),
+                ChannelMergeCommand::get(),
+                WhiteBalanceCommand::get(),
+                TemperatureCommand::get(),
+                LevelsCommand::get(),
+                CurvesCommand::get(),
+                LutCommand::get(),
+                PaletteQuantizationCommand::get(),
                 // noise
                 KuwaharaCommand::get(),
                 MedianBlurCommand::get(),
@@ -44,10 +86,24 @@ impl Command for ImageCommand {
                 SharpenCommand::get(),
                 GaussianBlurCommand::get(),
                 BilateralBlurCommand::get(),
+                ConvolveCommand::get(),
+                NonLocalMeansCommand::get(),
+                AnisotropicDiffusionCommand::get(),
+                MotionBlurCommand::get(),
+                RadialBlurCommand::get(),
+                ZoomBlurCommand::get(),
+                GaussianNoiseCommand::get(),
+                SaltAndPepperNoiseCommand::get(),
+                PoissonNoiseCommand::get(),
+                SpeckleNoiseCommand::get(),
                 // edge
                 SobelCommand::get(),
                 PrewittCommand::get(),
                 EmbossCommand::get(),
+                DifferenceOfGaussiansCommand::get(),
+                XDogCommand::get(),
+                CartoonCommand::get(),
+                GradientCommand::get(),
                 // geometry
                 RotateCommand::get(),
                 CropCommand::get(),
@@ -1,6 +1,8 @@
 pub mod args;
 pub mod command;
+pub mod compare;
 pub mod completions;
+pub mod generate;
 pub mod image;
 pub mod movie;
 pub mod picturify;
@@ -1,4 +1,5 @@
 use crate::commands::common::command::{Command, CommandForMovie};
+use crate::commands::movie::color::lut::LutCommand;
 use crate::commands::movie::color::negative::NegativeCommand;
 use crate::commands::movie::edge::sobel::SobelCommand;
 
@@ -9,6 +10,10 @@ impl Command for MovieCommand {
         clap::Command::new("movie")
             .about("Run processing pipeline on the movie")
             .disable_version_flag(true)
-            .subcommands(&[NegativeCommand::get(), SobelCommand::get()])
+            .subcommands(&[
+                NegativeCommand::get(),
+                LutCommand::get(),
+                SobelCommand::get(),
+            ])
     }
 }
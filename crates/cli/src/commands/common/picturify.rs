@@ -3,7 +3,9 @@ use clap::Arg;
 
 use crate::commands::common::args::common::PicturifyArg;
 use crate::commands::common::command::Command;
+use crate::commands::common::compare::CompareCommand;
 use crate::commands::common::completions::CompletionsCommand;
+use crate::commands::common::generate::GenerateCommand;
 use crate::commands::common::image::ImageCommand;
 use crate::commands::common::movie::MovieCommand;
 use crate::common::logging::LogLevelValueParser;
@@ -80,6 +82,8 @@ impl Command for PicturifyCommand {
             .subcommands(vec![
                 ImageCommand::create(),
                 MovieCommand::create(),
+                GenerateCommand::create(),
+                CompareCommand::create(),
                 CompletionsCommand::create(),
             ])
             .arg(PicturifyVerbosityArg::create(DEFAULT_ARGS.verbosity))
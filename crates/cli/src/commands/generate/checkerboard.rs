@@ -0,0 +1,82 @@
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForGenerator;
+use crate::commands::parsers::color::ColorValueParser;
+
+struct CheckerboardDefaultArgs {
+    cell_size: &'static str,
+    first: &'static str,
+    second: &'static str,
+}
+
+const DEFAULT_ARGS: CheckerboardDefaultArgs = CheckerboardDefaultArgs {
+    cell_size: "32",
+    first: "#ffffff",
+    second: "#000000",
+};
+
+pub struct CheckerboardCellSizeArg;
+
+impl PicturifyArg for CheckerboardCellSizeArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('c')
+            .long("cell-size")
+            .help("Size of a single square in pixels")
+            .default_value(default_value)
+            .value_parser(value_parser!(u32).range(1..))
+    }
+
+    fn id() -> &'static str {
+        "cell-size"
+    }
+}
+
+pub struct CheckerboardFirstArg;
+
+impl PicturifyArg for CheckerboardFirstArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("first")
+            .help("Color of the top left square, #rrggbb, #rrggbbaa or <r>,<g>,<b>[,<a>]")
+            .default_value(default_value)
+            .value_parser(ColorValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "first"
+    }
+}
+
+pub struct CheckerboardSecondArg;
+
+impl PicturifyArg for CheckerboardSecondArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("second")
+            .help("Color of the other squares, #rrggbb, #rrggbbaa or <r>,<g>,<b>[,<a>]")
+            .default_value(default_value)
+            .value_parser(ColorValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "second"
+    }
+}
+
+pub struct CheckerboardCommand;
+
+impl CommandForGenerator for CheckerboardCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(CheckerboardCellSizeArg::create(DEFAULT_ARGS.cell_size))
+            .arg(CheckerboardFirstArg::create(DEFAULT_ARGS.first))
+            .arg(CheckerboardSecondArg::create(DEFAULT_ARGS.second))
+    }
+
+    fn name() -> &'static str {
+        "checkerboard"
+    }
+}
@@ -0,0 +1,39 @@
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForGenerator;
+
+struct ColorBarsDefaultArgs {
+    intensity: &'static str,
+}
+
+const DEFAULT_ARGS: ColorBarsDefaultArgs = ColorBarsDefaultArgs { intensity: "0.75" };
+
+pub struct ColorBarsIntensityArg;
+
+impl PicturifyArg for ColorBarsIntensityArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("intensity")
+            .help("Level of the lit channels (0-1)")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "intensity"
+    }
+}
+
+pub struct ColorBarsCommand;
+
+impl CommandForGenerator for ColorBarsCommand {
+    fn get() -> clap::Command {
+        Self::get_base().arg(ColorBarsIntensityArg::create(DEFAULT_ARGS.intensity))
+    }
+
+    fn name() -> &'static str {
+        "color-bars"
+    }
+}
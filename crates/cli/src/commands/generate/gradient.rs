@@ -0,0 +1,105 @@
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+use picturify_processing::generators::gradient::GradientKind;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForGenerator;
+use crate::commands::parsers::angle::AngleValueParser;
+use crate::commands::parsers::color::ColorValueParser;
+
+struct GradientDefaultArgs {
+    kind: &'static str,
+    start: &'static str,
+    end: &'static str,
+    angle: &'static str,
+}
+
+const DEFAULT_ARGS: GradientDefaultArgs = GradientDefaultArgs {
+    kind: "linear",
+    start: "#000000",
+    end: "#ffffff",
+    angle: "0deg",
+};
+
+pub struct GradientKindArg;
+
+impl PicturifyArg for GradientKindArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('k')
+            .long("kind")
+            .help("Gradient kind")
+            .default_value(default_value)
+            .value_parser(value_parser!(GradientKind))
+    }
+
+    fn id() -> &'static str {
+        "kind"
+    }
+}
+
+pub struct GradientStartArg;
+
+impl PicturifyArg for GradientStartArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("start")
+            .help("Start color, #rrggbb, #rrggbbaa or <r>,<g>,<b>[,<a>]")
+            .default_value(default_value)
+            .value_parser(ColorValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "start"
+    }
+}
+
+pub struct GradientEndArg;
+
+impl PicturifyArg for GradientEndArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("end")
+            .help("End color, #rrggbb, #rrggbbaa or <r>,<g>,<b>[,<a>]")
+            .default_value(default_value)
+            .value_parser(ColorValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "end"
+    }
+}
+
+pub struct GradientAngleArg;
+
+impl PicturifyArg for GradientAngleArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('a')
+            .long("angle")
+            .help("Direction of a linear gradient (e.g. 90deg, 2rad), 0 runs left to right")
+            .default_value(default_value)
+            .value_parser(AngleValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "angle"
+    }
+}
+
+pub struct GradientCommand;
+
+impl CommandForGenerator for GradientCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(GradientKindArg::create(DEFAULT_ARGS.kind))
+            .arg(GradientStartArg::create(DEFAULT_ARGS.start))
+            .arg(GradientEndArg::create(DEFAULT_ARGS.end))
+            .arg(GradientAngleArg::create(DEFAULT_ARGS.angle))
+    }
+
+    fn name() -> &'static str {
+        "gradient"
+    }
+}
@@ -0,0 +1,81 @@
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+use crate::commands::common::args::common::{PicturifyArg, SeedArg};
+use crate::commands::common::command::CommandForGenerator;
+
+struct PerlinNoiseDefaultArgs {
+    scale: &'static str,
+    octaves: &'static str,
+    persistence: &'static str,
+}
+
+const DEFAULT_ARGS: PerlinNoiseDefaultArgs = PerlinNoiseDefaultArgs {
+    scale: "64.0",
+    octaves: "4",
+    persistence: "0.5",
+};
+
+pub struct PerlinNoiseScaleArg;
+
+impl PicturifyArg for PerlinNoiseScaleArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("scale")
+            .help("Size of the coarsest noise features in pixels")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "scale"
+    }
+}
+
+pub struct PerlinNoiseOctavesArg;
+
+impl PicturifyArg for PerlinNoiseOctavesArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("octaves")
+            .help("Number of noise layers, each twice as fine as the previous")
+            .default_value(default_value)
+            .value_parser(value_parser!(u32).range(1..))
+    }
+
+    fn id() -> &'static str {
+        "octaves"
+    }
+}
+
+pub struct PerlinNoisePersistenceArg;
+
+impl PicturifyArg for PerlinNoisePersistenceArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("persistence")
+            .help("Amplitude of every octave relative to the previous one")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "persistence"
+    }
+}
+
+pub struct PerlinNoiseCommand;
+
+impl CommandForGenerator for PerlinNoiseCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(PerlinNoiseScaleArg::create(DEFAULT_ARGS.scale))
+            .arg(PerlinNoiseOctavesArg::create(DEFAULT_ARGS.octaves))
+            .arg(PerlinNoisePersistenceArg::create(DEFAULT_ARGS.persistence))
+            .arg(SeedArg::create(None))
+    }
+
+    fn name() -> &'static str {
+        "perlin-noise"
+    }
+}
@@ -0,0 +1,39 @@
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForGenerator;
+
+struct ZonePlateDefaultArgs {
+    frequency: &'static str,
+}
+
+const DEFAULT_ARGS: ZonePlateDefaultArgs = ZonePlateDefaultArgs { frequency: "0.5" };
+
+pub struct ZonePlateFrequencyArg;
+
+impl PicturifyArg for ZonePlateFrequencyArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("frequency")
+            .help("Cycles per pixel at the middle of the shorter edge, 0.5 is the Nyquist limit")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "frequency"
+    }
+}
+
+pub struct ZonePlateCommand;
+
+impl CommandForGenerator for ZonePlateCommand {
+    fn get() -> clap::Command {
+        Self::get_base().arg(ZonePlateFrequencyArg::create(DEFAULT_ARGS.frequency))
+    }
+
+    fn name() -> &'static str {
+        "zone-plate"
+    }
+}
@@ -0,0 +1,68 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+use picturify_processing::common::color_space::ColorSpace;
+
+struct ChannelExtractionDefaultArgs {
+    space: &'static str,
+    channel: &'static str,
+}
+
+const DEFAULT_ARGS: ChannelExtractionDefaultArgs = ChannelExtractionDefaultArgs {
+    space: "lab",
+    channel: "0",
+};
+
+pub struct ChannelExtractionSpaceArg;
+
+impl PicturifyArg for ChannelExtractionSpaceArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("space")
+            .help("Color space (rgb, hsv, hsl, lab, lch, oklab, oklch, ycbcr, xyz)")
+            .default_value(default_value)
+            .value_parser(value_parser!(ColorSpace))
+    }
+
+    fn id() -> &'static str {
+        "space"
+    }
+}
+
+pub struct ChannelExtractionChannelArg;
+
+impl PicturifyArg for ChannelExtractionChannelArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('c')
+            .long("channel")
+            .help("Index of the channel in the color space, e.g. 0 is L* in lab")
+            .default_value(default_value)
+            .value_parser(value_parser!(u8).range(0..=2))
+    }
+
+    fn id() -> &'static str {
+        "channel"
+    }
+}
+
+pub struct ChannelExtractionCommand;
+
+impl CommandForImage for ChannelExtractionCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(ChannelExtractionSpaceArg::create(DEFAULT_ARGS.space))
+            .arg(ChannelExtractionChannelArg::create(DEFAULT_ARGS.channel))
+    }
+
+    fn name() -> &'static str {
+        "channel-extraction"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+use picturify_processing::common::color_space::ColorSpace;
+
+struct ChannelMergeDefaultArgs {
+    space: &'static str,
+}
+
+const DEFAULT_ARGS: ChannelMergeDefaultArgs = ChannelMergeDefaultArgs { space: "lab" };
+
+pub struct ChannelMergeSpaceArg;
+
+impl PicturifyArg for ChannelMergeSpaceArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("space")
+            .help("Color space (rgb, hsv, hsl, lab, lch, oklab, oklch, ycbcr, xyz)")
+            .default_value(default_value)
+            .value_parser(value_parser!(ColorSpace))
+    }
+
+    fn id() -> &'static str {
+        "space"
+    }
+}
+
+pub struct ChannelMergeFirstArg;
+
+impl PicturifyArg for ChannelMergeFirstArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("first")
+            .help("Grayscale image replacing the first channel")
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "first"
+    }
+}
+
+pub struct ChannelMergeSecondArg;
+
+impl PicturifyArg for ChannelMergeSecondArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("second")
+            .help("Grayscale image replacing the second channel")
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "second"
+    }
+}
+
+pub struct ChannelMergeThirdArg;
+
+impl PicturifyArg for ChannelMergeThirdArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("third")
+            .help("Grayscale image replacing the third channel")
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "third"
+    }
+}
+
+pub struct ChannelMergeCommand;
+
+impl CommandForImage for ChannelMergeCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(ChannelMergeSpaceArg::create(DEFAULT_ARGS.space))
+            .arg(ChannelMergeFirstArg::create(None))
+            .arg(ChannelMergeSecondArg::create(None))
+            .arg(ChannelMergeThirdArg::create(None))
+    }
+
+    fn name() -> &'static str {
+        "channel-merge"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,105 @@
+use crate::commands::common::args::common::{FastArg, PicturifyArg};
+use crate::commands::common::command::CommandForImage;
+use crate::commands::parsers::curve::CurveValueParser;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::Arg;
+
+struct CurvesDefaultArgs {
+    curve: &'static str,
+    fast: &'static str,
+}
+
+const DEFAULT_ARGS: CurvesDefaultArgs = CurvesDefaultArgs {
+    curve: "0:0,255:255",
+    fast: "false",
+};
+
+pub struct CurvesMasterArg;
+
+impl PicturifyArg for CurvesMasterArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('m')
+            .long("master")
+            .help("Curve applied to all channels, in format <in>:<out>,<in>:<out>,...")
+            .default_value(default_value)
+            .value_parser(CurveValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "master"
+    }
+}
+
+pub struct CurvesRedArg;
+
+impl PicturifyArg for CurvesRedArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('r')
+            .long("red")
+            .help("Curve applied to the red channel")
+            .default_value(default_value)
+            .value_parser(CurveValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "red"
+    }
+}
+
+pub struct CurvesGreenArg;
+
+impl PicturifyArg for CurvesGreenArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('g')
+            .long("green")
+            .help("Curve applied to the green channel")
+            .default_value(default_value)
+            .value_parser(CurveValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "green"
+    }
+}
+
+pub struct CurvesBlueArg;
+
+impl PicturifyArg for CurvesBlueArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('b')
+            .long("blue")
+            .help("Curve applied to the blue channel")
+            .default_value(default_value)
+            .value_parser(CurveValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "blue"
+    }
+}
+
+pub struct CurvesCommand;
+
+impl CommandForImage for CurvesCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(CurvesMasterArg::create(DEFAULT_ARGS.curve))
+            .arg(CurvesRedArg::create(DEFAULT_ARGS.curve))
+            .arg(CurvesGreenArg::create(DEFAULT_ARGS.curve))
+            .arg(CurvesBlueArg::create(DEFAULT_ARGS.curve))
+            .arg(FastArg::create(DEFAULT_ARGS.fast))
+    }
+
+    fn name() -> &'static str {
+        "curves"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,18 @@
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+
+pub struct ExtractAlphaCommand;
+
+impl CommandForImage for ExtractAlphaCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+    }
+
+    fn name() -> &'static str {
+        "extract-alpha"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,47 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::commands::parsers::color::ColorValueParser;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::Arg;
+
+struct FlattenDefaultArgs {
+    background: &'static str,
+}
+
+const DEFAULT_ARGS: FlattenDefaultArgs = FlattenDefaultArgs {
+    background: "#ffffff",
+};
+
+pub struct FlattenBackgroundArg;
+
+impl PicturifyArg for FlattenBackgroundArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('b')
+            .long("background")
+            .help("Background color, #rrggbb, #rrggbbaa or <r>,<g>,<b>[,<a>]")
+            .default_value(default_value)
+            .value_parser(ColorValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "background"
+    }
+}
+
+pub struct FlattenCommand;
+
+impl CommandForImage for FlattenCommand {
+    fn get() -> clap::Command {
+        Self::get_base().arg(FlattenBackgroundArg::create(DEFAULT_ARGS.background))
+    }
+
+    fn name() -> &'static str {
+        "flatten"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,47 @@
+use clap::builder::{IntoResettable, OsStr};
+use clap::Arg;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::commands::parsers::angle::AngleValueParser;
+use crate::common::filter_group::Group;
+
+struct HueRotationDefaultArgs {
+    angle: &'static str,
+}
+
+const DEFAULT_ARGS: HueRotationDefaultArgs = HueRotationDefaultArgs { angle: "180deg" };
+
+pub struct HueRotationAngleArg;
+
+impl PicturifyArg for HueRotationAngleArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('a')
+            .long("angle")
+            .help("Hue rotation angle (e.g. 90deg, -1rad)")
+            .allow_hyphen_values(true)
+            .default_value(default_value)
+            .value_parser(AngleValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "angle"
+    }
+}
+
+pub struct HueRotationCommand;
+
+impl CommandForImage for HueRotationCommand {
+    fn get() -> clap::Command {
+        Self::get_base().arg(HueRotationAngleArg::create(DEFAULT_ARGS.angle))
+    }
+
+    fn name() -> &'static str {
+        "hue-rotation"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
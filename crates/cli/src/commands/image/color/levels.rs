@@ -0,0 +1,126 @@
+use crate::commands::common::args::common::{FastArg, PicturifyArg};
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct LevelsDefaultArgs {
+    input_black: &'static str,
+    input_white: &'static str,
+    gamma: &'static str,
+    output_black: &'static str,
+    output_white: &'static str,
+    fast: &'static str,
+}
+
+const DEFAULT_ARGS: LevelsDefaultArgs = LevelsDefaultArgs {
+    input_black: "0",
+    input_white: "255",
+    gamma: "1.0",
+    output_black: "0",
+    output_white: "255",
+    fast: "false",
+};
+
+pub struct LevelsInputBlackArg;
+
+impl PicturifyArg for LevelsInputBlackArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("input-black")
+            .help("Input value that becomes black")
+            .default_value(default_value)
+            .value_parser(value_parser!(u8))
+    }
+
+    fn id() -> &'static str {
+        "input-black"
+    }
+}
+
+pub struct LevelsInputWhiteArg;
+
+impl PicturifyArg for LevelsInputWhiteArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("input-white")
+            .help("Input value that becomes white")
+            .default_value(default_value)
+            .value_parser(value_parser!(u8))
+    }
+
+    fn id() -> &'static str {
+        "input-white"
+    }
+}
+
+pub struct LevelsGammaArg;
+
+impl PicturifyArg for LevelsGammaArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('g')
+            .long("gamma")
+            .help("Midtone gamma, values above 1 brighten the midtones")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "gamma"
+    }
+}
+
+pub struct LevelsOutputBlackArg;
+
+impl PicturifyArg for LevelsOutputBlackArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("output-black")
+            .help("Output value of black")
+            .default_value(default_value)
+            .value_parser(value_parser!(u8))
+    }
+
+    fn id() -> &'static str {
+        "output-black"
+    }
+}
+
+pub struct LevelsOutputWhiteArg;
+
+impl PicturifyArg for LevelsOutputWhiteArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("output-white")
+            .help("Output value of white")
+            .default_value(default_value)
+            .value_parser(value_parser!(u8))
+    }
+
+    fn id() -> &'static str {
+        "output-white"
+    }
+}
+
+pub struct LevelsCommand;
+
+impl CommandForImage for LevelsCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(LevelsInputBlackArg::create(DEFAULT_ARGS.input_black))
+            .arg(LevelsInputWhiteArg::create(DEFAULT_ARGS.input_white))
+            .arg(LevelsGammaArg::create(DEFAULT_ARGS.gamma))
+            .arg(LevelsOutputBlackArg::create(DEFAULT_ARGS.output_black))
+            .arg(LevelsOutputWhiteArg::create(DEFAULT_ARGS.output_white))
+            .arg(FastArg::create(DEFAULT_ARGS.fast))
+    }
+
+    fn name() -> &'static str {
+        "levels"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,68 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+use picturify_processing::processors::color::lightness::LightnessSpace;
+
+struct LightnessDefaultArgs {
+    factor: &'static str,
+    space: &'static str,
+}
+
+const DEFAULT_ARGS: LightnessDefaultArgs = LightnessDefaultArgs {
+    factor: "1.0",
+    space: "oklab",
+};
+
+pub struct LightnessFactorArg;
+
+impl PicturifyArg for LightnessFactorArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('f')
+            .long("factor")
+            .help("Lightness factor")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "factor"
+    }
+}
+
+pub struct LightnessSpaceArg;
+
+impl PicturifyArg for LightnessSpaceArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("space")
+            .help("Color space the lightness is adjusted in (oklab, lch)")
+            .default_value(default_value)
+            .value_parser(value_parser!(LightnessSpace))
+    }
+
+    fn id() -> &'static str {
+        "space"
+    }
+}
+
+pub struct LightnessCommand;
+
+impl CommandForImage for LightnessCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(LightnessFactorArg::create(DEFAULT_ARGS.factor))
+            .arg(LightnessSpaceArg::create(DEFAULT_ARGS.space))
+    }
+
+    fn name() -> &'static str {
+        "lightness"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
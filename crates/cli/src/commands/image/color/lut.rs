@@ -0,0 +1,69 @@
+use std::path::PathBuf;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+use picturify_processing::common::lut3d::Lut3dInterpolation;
+
+// shared with the movie command
+pub struct LutDefaultArgs {
+    pub interpolation: &'static str,
+}
+
+pub const DEFAULT_ARGS: LutDefaultArgs = LutDefaultArgs {
+    interpolation: "tetrahedral",
+};
+
+pub struct LutFileArg;
+
+impl PicturifyArg for LutFileArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("file")
+            .help("3D LUT as an Adobe / Resolve .cube file or a HALD CLUT image")
+            .required(true)
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "file"
+    }
+}
+
+pub struct LutInterpolationArg;
+
+impl PicturifyArg for LutInterpolationArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('m')
+            .long("interpolation")
+            .help("Interpolation between LUT entries (trilinear, tetrahedral)")
+            .default_value(default_value)
+            .value_parser(value_parser!(Lut3dInterpolation))
+    }
+
+    fn id() -> &'static str {
+        "interpolation"
+    }
+}
+
+pub struct LutCommand;
+
+impl CommandForImage for LutCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(LutFileArg::create(None))
+            .arg(LutInterpolationArg::create(DEFAULT_ARGS.interpolation))
+    }
+
+    fn name() -> &'static str {
+        "lut"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,126 @@
+use std::path::PathBuf;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+use picturify_processing::common::palette_generation::PaletteMethod;
+use picturify_processing::processors::color::palette_quantization::Dithering;
+
+struct PaletteQuantizationDefaultArgs {
+    colors: &'static str,
+    method: &'static str,
+    dithering: &'static str,
+}
+
+const DEFAULT_ARGS: PaletteQuantizationDefaultArgs = PaletteQuantizationDefaultArgs {
+    colors: "16",
+    method: "median-cut",
+    dithering: "none",
+};
+
+pub struct PaletteColorsArg;
+
+impl PicturifyArg for PaletteColorsArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('c')
+            .long("colors")
+            .help("Number of palette colors (1-256), ignored when a palette file is given")
+            .default_value(default_value)
+            .value_parser(value_parser!(u16).range(1..=256))
+    }
+
+    fn id() -> &'static str {
+        "colors"
+    }
+}
+
+pub struct PaletteMethodArg;
+
+impl PicturifyArg for PaletteMethodArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('m')
+            .long("method")
+            .help("How the palette is computed (median-cut, k-means, octree)")
+            .default_value(default_value)
+            .value_parser(value_parser!(PaletteMethod))
+    }
+
+    fn id() -> &'static str {
+        "method"
+    }
+}
+
+pub struct PaletteFileArg;
+
+impl PicturifyArg for PaletteFileArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('p')
+            .long("palette")
+            .help("Fixed palette as a GIMP .gpl file, a .hex list or an image of swatches")
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "palette"
+    }
+}
+
+pub struct DitheringArg;
+
+impl PicturifyArg for DitheringArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('d')
+            .long("dithering")
+            .help("Dithering (none, floyd-steinberg, atkinson, jarvis, bayer)")
+            .default_value(default_value)
+            .value_parser(value_parser!(Dithering))
+    }
+
+    fn id() -> &'static str {
+        "dithering"
+    }
+}
+
+pub struct ExportPaletteArg;
+
+impl PicturifyArg for ExportPaletteArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("export-palette")
+            .help("Writes the palette used to a .gpl or .hex file")
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "export-palette"
+    }
+}
+
+pub struct PaletteQuantizationCommand;
+
+impl CommandForImage for PaletteQuantizationCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(PaletteColorsArg::create(DEFAULT_ARGS.colors))
+            .arg(PaletteMethodArg::create(DEFAULT_ARGS.method))
+            .arg(PaletteFileArg::create(None))
+            .arg(DitheringArg::create(DEFAULT_ARGS.dithering))
+            .arg(ExportPaletteArg::create(None))
+    }
+
+    fn name() -> &'static str {
+        "palette-quantization"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
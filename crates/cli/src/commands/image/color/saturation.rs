@@ -0,0 +1,68 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct SaturationDefaultArgs {
+    factor: &'static str,
+    vibrance: &'static str,
+}
+
+const DEFAULT_ARGS: SaturationDefaultArgs = SaturationDefaultArgs {
+    factor: "1.0",
+    vibrance: "0.0",
+};
+
+pub struct SaturationFactorArg;
+
+impl PicturifyArg for SaturationFactorArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('f')
+            .long("factor")
+            .help("Saturation factor, 0 removes all color")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "factor"
+    }
+}
+
+pub struct SaturationVibranceArg;
+
+impl PicturifyArg for SaturationVibranceArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('v')
+            .long("vibrance")
+            .help("Extra saturation applied mostly to muted colors")
+            .allow_negative_numbers(true)
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "vibrance"
+    }
+}
+
+pub struct SaturationCommand;
+
+impl CommandForImage for SaturationCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(SaturationFactorArg::create(DEFAULT_ARGS.factor))
+            .arg(SaturationVibranceArg::create(DEFAULT_ARGS.vibrance))
+    }
+
+    fn name() -> &'static str {
+        "saturation"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+pub struct SetAlphaImageArg;
+
+impl PicturifyArg for SetAlphaImageArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('a')
+            .long("alpha")
+            .help("Grayscale image used as the new alpha channel")
+            .required(true)
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "alpha"
+    }
+}
+
+pub struct SetAlphaCommand;
+
+impl CommandForImage for SetAlphaCommand {
+    fn get() -> clap::Command {
+        Self::get_base().arg(SetAlphaImageArg::create(None))
+    }
+
+    fn name() -> &'static str {
+        "set-alpha"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,67 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct TemperatureDefaultArgs {
+    temperature: &'static str,
+    tint: &'static str,
+}
+
+const DEFAULT_ARGS: TemperatureDefaultArgs = TemperatureDefaultArgs {
+    temperature: "6500",
+    tint: "0.0",
+};
+
+pub struct TemperatureKelvinArg;
+
+impl PicturifyArg for TemperatureKelvinArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('t')
+            .long("temperature")
+            .help("Color temperature of the scene light in Kelvin, lower values make the image cooler")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "temperature"
+    }
+}
+
+pub struct TemperatureTintArg;
+
+impl PicturifyArg for TemperatureTintArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("tint")
+            .help("Green-magenta correction, positive values remove a green cast")
+            .allow_negative_numbers(true)
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "tint"
+    }
+}
+
+pub struct TemperatureCommand;
+
+impl CommandForImage for TemperatureCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(TemperatureKelvinArg::create(DEFAULT_ARGS.temperature))
+            .arg(TemperatureTintArg::create(DEFAULT_ARGS.tint))
+    }
+
+    fn name() -> &'static str {
+        "temperature"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,68 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::commands::parsers::coord::CoordValueParser;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+use picturify_processing::processors::color::white_balance::WhiteBalanceMethod;
+
+struct WhiteBalanceDefaultArgs {
+    method: &'static str,
+}
+
+const DEFAULT_ARGS: WhiteBalanceDefaultArgs = WhiteBalanceDefaultArgs {
+    method: "gray-world",
+};
+
+pub struct WhiteBalanceMethodArg;
+
+impl PicturifyArg for WhiteBalanceMethodArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('m')
+            .long("method")
+            .help("How the neutral color is found (gray-world, white-patch, neutral)")
+            .default_value(default_value)
+            .value_parser(value_parser!(WhiteBalanceMethod))
+    }
+
+    fn id() -> &'static str {
+        "method"
+    }
+}
+
+pub struct WhiteBalancePointArg;
+
+impl PicturifyArg for WhiteBalancePointArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('p')
+            .long("point")
+            .help("Point that should become neutral in format <x>,<y>, used by the neutral method")
+            .required_if_eq(WhiteBalanceMethodArg::id(), "neutral")
+            .default_value(default_value)
+            .value_parser(CoordValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "point"
+    }
+}
+
+pub struct WhiteBalanceCommand;
+
+impl CommandForImage for WhiteBalanceCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(WhiteBalanceMethodArg::create(DEFAULT_ARGS.method))
+            .arg(WhiteBalancePointArg::create(None))
+    }
+
+    fn name() -> &'static str {
+        "white-balance"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -0,0 +1,103 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::commands::image::edge::xdog::{
+    XDogEpsilonArg, XDogPhiArg, XDogSharpnessArg, XDogSigmaArg,
+};
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+use picturify_pipeline::edge::cartoon::CartoonSmoothing;
+
+struct CartoonDefaultArgs {
+    smoothing: &'static str,
+    radius: &'static str,
+    levels: &'static str,
+    sigma: &'static str,
+    sharpness: &'static str,
+    epsilon: &'static str,
+    phi: &'static str,
+}
+
+const DEFAULT_ARGS: CartoonDefaultArgs = CartoonDefaultArgs {
+    smoothing: "bilateral",
+    radius: "4",
+    levels: "6",
+    sigma: "1.0",
+    sharpness: "100.0",
+    epsilon: "0.15",
+    phi: "50.0",
+};
+
+pub struct CartoonSmoothingArg;
+
+impl PicturifyArg for CartoonSmoothingArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('m')
+            .long("smoothing")
+            .help("Edge preserving blur flattening the colors (bilateral, kuwahara)")
+            .default_value(default_value)
+            .value_parser(value_parser!(CartoonSmoothing))
+    }
+
+    fn id() -> &'static str {
+        "smoothing"
+    }
+}
+
+pub struct CartoonRadiusArg;
+
+impl PicturifyArg for CartoonRadiusArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('r')
+            .long("radius")
+            .help("Radius of the smoothing")
+            .default_value(default_value)
+            .value_parser(value_parser!(usize))
+    }
+
+    fn id() -> &'static str {
+        "radius"
+    }
+}
+
+pub struct CartoonLevelsArg;
+
+impl PicturifyArg for CartoonLevelsArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('l')
+            .long("levels")
+            .help("Number of quantization levels")
+            .default_value(default_value)
+            .value_parser(value_parser!(u8))
+    }
+
+    fn id() -> &'static str {
+        "levels"
+    }
+}
+
+pub struct CartoonCommand;
+
+impl CommandForImage for CartoonCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(CartoonSmoothingArg::create(DEFAULT_ARGS.smoothing))
+            .arg(CartoonRadiusArg::create(DEFAULT_ARGS.radius))
+            .arg(CartoonLevelsArg::create(DEFAULT_ARGS.levels))
+            .arg(XDogSigmaArg::create(DEFAULT_ARGS.sigma))
+            .arg(XDogSharpnessArg::create(DEFAULT_ARGS.sharpness))
+            .arg(XDogEpsilonArg::create(DEFAULT_ARGS.epsilon))
+            .arg(XDogPhiArg::create(DEFAULT_ARGS.phi))
+    }
+
+    fn name() -> &'static str {
+        "cartoon"
+    }
+
+    fn group() -> Group {
+        Group::Edge
+    }
+}
@@ -0,0 +1,87 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct DifferenceOfGaussiansDefaultArgs {
+    sigma: &'static str,
+    k: &'static str,
+    gain: &'static str,
+}
+
+const DEFAULT_ARGS: DifferenceOfGaussiansDefaultArgs = DifferenceOfGaussiansDefaultArgs {
+    sigma: "1.0",
+    k: "1.6",
+    gain: "10.0",
+};
+
+pub struct DifferenceOfGaussiansSigmaArg;
+
+impl PicturifyArg for DifferenceOfGaussiansSigmaArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("sigma")
+            .help("Standard deviation of the narrow gaussian")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "sigma"
+    }
+}
+
+pub struct DifferenceOfGaussiansKArg;
+
+impl PicturifyArg for DifferenceOfGaussiansKArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('k')
+            .long("k")
+            .help("Ratio between the wide and the narrow gaussian")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "k"
+    }
+}
+
+pub struct DifferenceOfGaussiansGainArg;
+
+impl PicturifyArg for DifferenceOfGaussiansGainArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('g')
+            .long("gain")
+            .help("Multiplier of the edge response")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "gain"
+    }
+}
+
+pub struct DifferenceOfGaussiansCommand;
+
+impl CommandForImage for DifferenceOfGaussiansCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(DifferenceOfGaussiansSigmaArg::create(DEFAULT_ARGS.sigma))
+            .arg(DifferenceOfGaussiansKArg::create(DEFAULT_ARGS.k))
+            .arg(DifferenceOfGaussiansGainArg::create(DEFAULT_ARGS.gain))
+    }
+
+    fn name() -> &'static str {
+        "difference-of-gaussians"
+    }
+
+    fn group() -> Group {
+        Group::Edge
+    }
+}
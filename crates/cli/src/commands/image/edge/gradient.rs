@@ -0,0 +1,91 @@
+use crate::commands::common::args::common::{FastArg, PicturifyArg};
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+use picturify_processing::processors::edge::gradient::{GradientMode, GradientOperator};
+
+struct GradientDefaultArgs {
+    fast: &'static str,
+    operator: &'static str,
+    mode: &'static str,
+    gain: &'static str,
+}
+
+const DEFAULT_ARGS: GradientDefaultArgs = GradientDefaultArgs {
+    fast: "false",
+    operator: "sobel",
+    mode: "orientation",
+    gain: "1.0",
+};
+
+pub struct GradientOperatorArg;
+
+impl PicturifyArg for GradientOperatorArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('k')
+            .long("operator")
+            .help("Kernels the derivatives are taken with")
+            .default_value(default_value)
+            .value_parser(value_parser!(GradientOperator))
+    }
+
+    fn id() -> &'static str {
+        "operator"
+    }
+}
+
+pub struct GradientModeArg;
+
+impl PicturifyArg for GradientModeArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('m')
+            .long("mode")
+            .help("Rendered part of the gradient, orientation is hue coded, dx and dy are signed around mid gray")
+            .default_value(default_value)
+            .value_parser(value_parser!(GradientMode))
+    }
+
+    fn id() -> &'static str {
+        "mode"
+    }
+}
+
+pub struct GradientGainArg;
+
+impl PicturifyArg for GradientGainArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('g')
+            .long("gain")
+            .help("Multiplier applied after scaling by the strongest gradient")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "gain"
+    }
+}
+
+pub struct GradientCommand;
+
+impl CommandForImage for GradientCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(FastArg::create(DEFAULT_ARGS.fast))
+            .arg(GradientOperatorArg::create(DEFAULT_ARGS.operator))
+            .arg(GradientModeArg::create(DEFAULT_ARGS.mode))
+            .arg(GradientGainArg::create(DEFAULT_ARGS.gain))
+    }
+
+    fn name() -> &'static str {
+        "gradient"
+    }
+
+    fn group() -> Group {
+        Group::Edge
+    }
+}
@@ -0,0 +1,126 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct XDogDefaultArgs {
+    sigma: &'static str,
+    k: &'static str,
+    sharpness: &'static str,
+    epsilon: &'static str,
+    phi: &'static str,
+}
+
+const DEFAULT_ARGS: XDogDefaultArgs = XDogDefaultArgs {
+    sigma: "1.5",
+    k: "1.6",
+    sharpness: "30.0",
+    epsilon: "0.5",
+    phi: "20.0",
+};
+
+pub struct XDogSigmaArg;
+
+impl PicturifyArg for XDogSigmaArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("sigma")
+            .help("Standard deviation of the narrow gaussian")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "sigma"
+    }
+}
+
+pub struct XDogKArg;
+
+impl PicturifyArg for XDogKArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('k')
+            .long("k")
+            .help("Ratio between the wide and the narrow gaussian")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "k"
+    }
+}
+
+pub struct XDogSharpnessArg;
+
+impl PicturifyArg for XDogSharpnessArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('p')
+            .long("sharpness")
+            .help("Weight of the gaussian difference, higher values give bolder lines")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "sharpness"
+    }
+}
+
+pub struct XDogEpsilonArg;
+
+impl PicturifyArg for XDogEpsilonArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('e')
+            .long("epsilon")
+            .help("Threshold from 0 to 1, darker areas get shaded")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "epsilon"
+    }
+}
+
+pub struct XDogPhiArg;
+
+impl PicturifyArg for XDogPhiArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("phi")
+            .help("Steepness of the soft threshold, large values give hard black and white")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "phi"
+    }
+}
+
+pub struct XDogCommand;
+
+impl CommandForImage for XDogCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(XDogSigmaArg::create(DEFAULT_ARGS.sigma))
+            .arg(XDogKArg::create(DEFAULT_ARGS.k))
+            .arg(XDogSharpnessArg::create(DEFAULT_ARGS.sharpness))
+            .arg(XDogEpsilonArg::create(DEFAULT_ARGS.epsilon))
+            .arg(XDogPhiArg::create(DEFAULT_ARGS.phi))
+    }
+
+    fn name() -> &'static str {
+        "xdog"
+    }
+
+    fn group() -> Group {
+        Group::Edge
+    }
+}
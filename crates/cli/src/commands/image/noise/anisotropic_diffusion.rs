@@ -0,0 +1,111 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+use picturify_processing::processors::noise::anisotropic_diffusion::ConductionFunction;
+
+struct AnisotropicDiffusionDefaultArgs {
+    iterations: &'static str,
+    kappa: &'static str,
+    lambda: &'static str,
+    function: &'static str,
+}
+
+const DEFAULT_ARGS: AnisotropicDiffusionDefaultArgs = AnisotropicDiffusionDefaultArgs {
+    iterations: "10",
+    kappa: "20.0",
+    lambda: "0.2",
+    function: "exponential",
+};
+
+pub struct AnisotropicDiffusionIterationsArg;
+
+impl PicturifyArg for AnisotropicDiffusionIterationsArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('n')
+            .long("iterations")
+            .help("Number of diffusion steps")
+            .default_value(default_value)
+            .value_parser(value_parser!(usize))
+    }
+
+    fn id() -> &'static str {
+        "iterations"
+    }
+}
+
+pub struct AnisotropicDiffusionKappaArg;
+
+impl PicturifyArg for AnisotropicDiffusionKappaArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('k')
+            .long("kappa")
+            .help("Gradient magnitude in 0-255 channel units treated as an edge")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "kappa"
+    }
+}
+
+pub struct AnisotropicDiffusionLambdaArg;
+
+impl PicturifyArg for AnisotropicDiffusionLambdaArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('l')
+            .long("lambda")
+            .help("Step size, at most 0.25")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "lambda"
+    }
+}
+
+pub struct AnisotropicDiffusionFunctionArg;
+
+impl PicturifyArg for AnisotropicDiffusionFunctionArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("function")
+            .help("Conduction function")
+            .default_value(default_value)
+            .value_parser(value_parser!(ConductionFunction))
+    }
+
+    fn id() -> &'static str {
+        "function"
+    }
+}
+
+pub struct AnisotropicDiffusionCommand;
+
+impl CommandForImage for AnisotropicDiffusionCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(AnisotropicDiffusionIterationsArg::create(
+                DEFAULT_ARGS.iterations,
+            ))
+            .arg(AnisotropicDiffusionKappaArg::create(DEFAULT_ARGS.kappa))
+            .arg(AnisotropicDiffusionLambdaArg::create(DEFAULT_ARGS.lambda))
+            .arg(AnisotropicDiffusionFunctionArg::create(
+                DEFAULT_ARGS.function,
+            ))
+    }
+
+    fn name() -> &'static str {
+        "anisotropic-diffusion"
+    }
+
+    fn group() -> Group {
+        Group::Noise
+    }
+}
@@ -0,0 +1,175 @@
+use std::path::PathBuf;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::commands::parsers::channels::ChannelsValueParser;
+use crate::commands::parsers::coord::CoordValueParser;
+use crate::commands::parsers::kernel::KernelValueParser;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg, ArgAction};
+
+struct ConvolveDefaultArgs {
+    normalize: &'static str,
+    divisor: &'static str,
+    bias: &'static str,
+    channels: &'static str,
+}
+
+const DEFAULT_ARGS: ConvolveDefaultArgs = ConvolveDefaultArgs {
+    normalize: "false",
+    divisor: "1.0",
+    bias: "0.0",
+    channels: "rgb",
+};
+
+pub struct ConvolveKernelArg;
+
+impl PicturifyArg for ConvolveKernelArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('k')
+            .long("kernel")
+            .help(
+                "Kernel rows separated by ';' and values by ',', for example 0,-1,0;-1,5,-1;0,-1,0",
+            )
+            .required_unless_present(ConvolveFileArg::id())
+            .allow_hyphen_values(true)
+            .default_value(default_value)
+            .value_parser(KernelValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "kernel"
+    }
+}
+
+pub struct ConvolveFileArg;
+
+impl PicturifyArg for ConvolveFileArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("file")
+            .help("Kernel as a .json array of rows or a .csv file with one row per line")
+            .conflicts_with(ConvolveKernelArg::id())
+            .default_value(default_value)
+            .value_parser(value_parser!(PathBuf))
+    }
+
+    fn id() -> &'static str {
+        "file"
+    }
+}
+
+pub struct ConvolveNormalizeArg;
+
+impl PicturifyArg for ConvolveNormalizeArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('n')
+            .long("normalize")
+            .help("Divide by the sum of the kernel instead of the divisor")
+            .action(ArgAction::SetTrue)
+            .default_value(default_value)
+            .value_parser(value_parser!(bool))
+    }
+
+    fn id() -> &'static str {
+        "normalize"
+    }
+}
+
+pub struct ConvolveDivisorArg;
+
+impl PicturifyArg for ConvolveDivisorArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('d')
+            .long("divisor")
+            .help("Value the weighted sum is divided by")
+            .allow_negative_numbers(true)
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "divisor"
+    }
+}
+
+pub struct ConvolveBiasArg;
+
+impl PicturifyArg for ConvolveBiasArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('b')
+            .long("bias")
+            .help("Value added after the division, in 0-255 channel units")
+            .allow_negative_numbers(true)
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "bias"
+    }
+}
+
+pub struct ConvolveAnchorArg;
+
+impl PicturifyArg for ConvolveAnchorArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('p')
+            .long("anchor")
+            .help(
+                "Kernel cell placed over the output pixel in format <x>,<y>, the center by default",
+            )
+            .default_value(default_value)
+            .value_parser(CoordValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "anchor"
+    }
+}
+
+pub struct ConvolveChannelsArg;
+
+impl PicturifyArg for ConvolveChannelsArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('c')
+            .long("channels")
+            .help("Channels to convolve as letters r, g, b and a, the others are kept")
+            .default_value(default_value)
+            .value_parser(ChannelsValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "channels"
+    }
+}
+
+pub struct ConvolveCommand;
+
+impl CommandForImage for ConvolveCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(ConvolveKernelArg::create(None))
+            .arg(ConvolveFileArg::create(None))
+            .arg(ConvolveNormalizeArg::create(DEFAULT_ARGS.normalize))
+            .arg(ConvolveDivisorArg::create(DEFAULT_ARGS.divisor))
+            .arg(ConvolveBiasArg::create(DEFAULT_ARGS.bias))
+            .arg(ConvolveAnchorArg::create(None))
+            .arg(ConvolveChannelsArg::create(DEFAULT_ARGS.channels))
+    }
+
+    fn name() -> &'static str {
+        "convolve"
+    }
+
+    fn group() -> Group {
+        Group::Noise
+    }
+}
@@ -0,0 +1,46 @@
+use crate::commands::common::args::common::{PicturifyArg, SeedArg};
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct GaussianNoiseDefaultArgs {
+    sigma: &'static str,
+}
+
+const DEFAULT_ARGS: GaussianNoiseDefaultArgs = GaussianNoiseDefaultArgs { sigma: "16.0" };
+
+pub struct GaussianNoiseSigmaArg;
+
+impl PicturifyArg for GaussianNoiseSigmaArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("sigma")
+            .help("Standard deviation of the noise in 0-255 channel units")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "sigma"
+    }
+}
+
+pub struct GaussianNoiseCommand;
+
+impl CommandForImage for GaussianNoiseCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(GaussianNoiseSigmaArg::create(DEFAULT_ARGS.sigma))
+            .arg(SeedArg::create(None))
+    }
+
+    fn name() -> &'static str {
+        "gaussian-noise"
+    }
+
+    fn group() -> Group {
+        Group::Noise
+    }
+}
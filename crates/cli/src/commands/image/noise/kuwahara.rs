@@ -3,15 +3,22 @@ use crate::commands::common::command::CommandForImage;
 use crate::common::filter_group::Group;
 use clap::builder::{IntoResettable, OsStr};
 use clap::{value_parser, Arg};
+use picturify_processing::processors::noise::kuwahara::KuwaharaMethod;
 
 struct KuwaharaBlurDefaultArgs {
     radius: &'static str,
     fast: &'static str,
+    method: &'static str,
+    sectors: &'static str,
+    sharpness: &'static str,
 }
 
 const DEFAULT_ARGS: KuwaharaBlurDefaultArgs = KuwaharaBlurDefaultArgs {
     radius: "1",
     fast: "false",
+    method: "classic",
+    sectors: "8",
+    sharpness: "8.0",
 };
 
 pub struct KuwaharaRadiusArg;
@@ -31,6 +38,56 @@ impl PicturifyArg for KuwaharaRadiusArg {
     }
 }
 
+pub struct KuwaharaMethodArg;
+
+impl PicturifyArg for KuwaharaMethodArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('m')
+            .long("method")
+            .help("Kuwahara variant, anisotropic windows follow the local edge direction")
+            .default_value(default_value)
+            .value_parser(value_parser!(KuwaharaMethod))
+    }
+
+    fn id() -> &'static str {
+        "method"
+    }
+}
+
+pub struct KuwaharaSectorsArg;
+
+impl PicturifyArg for KuwaharaSectorsArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("sectors")
+            .help("Number of window sectors, ignored by the classic method")
+            .default_value(default_value)
+            .value_parser(value_parser!(u32).range(2..=32))
+    }
+
+    fn id() -> &'static str {
+        "sectors"
+    }
+}
+
+pub struct KuwaharaSharpnessArg;
+
+impl PicturifyArg for KuwaharaSharpnessArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('q')
+            .long("sharpness")
+            .help("How strongly flat sectors win over the others, ignored by the classic method")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "sharpness"
+    }
+}
+
 pub struct KuwaharaCommand;
 
 impl CommandForImage for KuwaharaCommand {
@@ -38,6 +95,9 @@ impl CommandForImage for KuwaharaCommand {
         Self::get_base()
             .arg(FastArg::create(DEFAULT_ARGS.fast))
             .arg(KuwaharaRadiusArg::create(DEFAULT_ARGS.radius))
+            .arg(KuwaharaMethodArg::create(DEFAULT_ARGS.method))
+            .arg(KuwaharaSectorsArg::create(DEFAULT_ARGS.sectors))
+            .arg(KuwaharaSharpnessArg::create(DEFAULT_ARGS.sharpness))
     }
 
     fn name() -> &'static str {
@@ -0,0 +1,68 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::commands::parsers::angle::AngleValueParser;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct MotionBlurDefaultArgs {
+    angle: &'static str,
+    length: &'static str,
+}
+
+const DEFAULT_ARGS: MotionBlurDefaultArgs = MotionBlurDefaultArgs {
+    angle: "0deg",
+    length: "10.0",
+};
+
+pub struct MotionBlurAngleArg;
+
+impl PicturifyArg for MotionBlurAngleArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('a')
+            .long("angle")
+            .help("Direction of the motion, 0 is horizontal (e.g. 45deg, 1rad)")
+            .default_value(default_value)
+            .value_parser(AngleValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "angle"
+    }
+}
+
+pub struct MotionBlurLengthArg;
+
+impl PicturifyArg for MotionBlurLengthArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('l')
+            .long("length")
+            .help("Length of the streak in pixels")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "length"
+    }
+}
+
+pub struct MotionBlurCommand;
+
+impl CommandForImage for MotionBlurCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(MotionBlurAngleArg::create(DEFAULT_ARGS.angle))
+            .arg(MotionBlurLengthArg::create(DEFAULT_ARGS.length))
+    }
+
+    fn name() -> &'static str {
+        "motion-blur"
+    }
+
+    fn group() -> Group {
+        Group::Noise
+    }
+}
@@ -0,0 +1,91 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct NonLocalMeansDefaultArgs {
+    search_radius: &'static str,
+    patch_radius: &'static str,
+    strength: &'static str,
+}
+
+const DEFAULT_ARGS: NonLocalMeansDefaultArgs = NonLocalMeansDefaultArgs {
+    search_radius: "5",
+    patch_radius: "2",
+    strength: "15.0",
+};
+
+pub struct NonLocalMeansSearchRadiusArg;
+
+impl PicturifyArg for NonLocalMeansSearchRadiusArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('r')
+            .long("search-radius")
+            .help("Radius of the window searched for similar patches")
+            .default_value(default_value)
+            .value_parser(value_parser!(usize))
+    }
+
+    fn id() -> &'static str {
+        "search-radius"
+    }
+}
+
+pub struct NonLocalMeansPatchRadiusArg;
+
+impl PicturifyArg for NonLocalMeansPatchRadiusArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('p')
+            .long("patch-radius")
+            .help("Radius of the compared patches")
+            .default_value(default_value)
+            .value_parser(value_parser!(usize))
+    }
+
+    fn id() -> &'static str {
+        "patch-radius"
+    }
+}
+
+pub struct NonLocalMeansStrengthArg;
+
+impl PicturifyArg for NonLocalMeansStrengthArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("strength")
+            .help("Filter strength in 0-255 channel units, higher removes more noise and texture")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "strength"
+    }
+}
+
+pub struct NonLocalMeansCommand;
+
+impl CommandForImage for NonLocalMeansCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(NonLocalMeansSearchRadiusArg::create(
+                DEFAULT_ARGS.search_radius,
+            ))
+            .arg(NonLocalMeansPatchRadiusArg::create(
+                DEFAULT_ARGS.patch_radius,
+            ))
+            .arg(NonLocalMeansStrengthArg::create(DEFAULT_ARGS.strength))
+    }
+
+    fn name() -> &'static str {
+        "non-local-means"
+    }
+
+    fn group() -> Group {
+        Group::Noise
+    }
+}
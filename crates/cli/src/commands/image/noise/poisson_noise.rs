@@ -0,0 +1,46 @@
+use crate::commands::common::args::common::{PicturifyArg, SeedArg};
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct PoissonNoiseDefaultArgs {
+    scale: &'static str,
+}
+
+const DEFAULT_ARGS: PoissonNoiseDefaultArgs = PoissonNoiseDefaultArgs { scale: "1.0" };
+
+pub struct PoissonNoiseScaleArg;
+
+impl PicturifyArg for PoissonNoiseScaleArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("scale")
+            .help("Photons counted per channel unit, higher values give less noise")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "scale"
+    }
+}
+
+pub struct PoissonNoiseCommand;
+
+impl CommandForImage for PoissonNoiseCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(PoissonNoiseScaleArg::create(DEFAULT_ARGS.scale))
+            .arg(SeedArg::create(None))
+    }
+
+    fn name() -> &'static str {
+        "poisson-noise"
+    }
+
+    fn group() -> Group {
+        Group::Noise
+    }
+}
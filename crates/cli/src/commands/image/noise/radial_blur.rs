@@ -0,0 +1,65 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::commands::parsers::angle::AngleValueParser;
+use crate::commands::parsers::coord::CoordValueParser;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::Arg;
+
+struct RadialBlurDefaultArgs {
+    angle: &'static str,
+}
+
+const DEFAULT_ARGS: RadialBlurDefaultArgs = RadialBlurDefaultArgs { angle: "10deg" };
+
+pub struct RadialBlurCenterArg;
+
+impl PicturifyArg for RadialBlurCenterArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('c')
+            .long("center")
+            .help("Center of rotation in format <x>,<y>, the image center by default")
+            .default_value(default_value)
+            .value_parser(CoordValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "center"
+    }
+}
+
+pub struct RadialBlurAngleArg;
+
+impl PicturifyArg for RadialBlurAngleArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('a')
+            .long("angle")
+            .help("Arc every pixel is blurred over (e.g. 10deg, 0.2rad)")
+            .default_value(default_value)
+            .value_parser(AngleValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "angle"
+    }
+}
+
+pub struct RadialBlurCommand;
+
+impl CommandForImage for RadialBlurCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(RadialBlurCenterArg::create(None))
+            .arg(RadialBlurAngleArg::create(DEFAULT_ARGS.angle))
+    }
+
+    fn name() -> &'static str {
+        "radial-blur"
+    }
+
+    fn group() -> Group {
+        Group::Noise
+    }
+}
@@ -0,0 +1,69 @@
+use crate::commands::common::args::common::{PicturifyArg, SeedArg};
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct SaltAndPepperNoiseDefaultArgs {
+    amount: &'static str,
+    salt_ratio: &'static str,
+}
+
+const DEFAULT_ARGS: SaltAndPepperNoiseDefaultArgs = SaltAndPepperNoiseDefaultArgs {
+    amount: "0.05",
+    salt_ratio: "0.5",
+};
+
+pub struct SaltAndPepperNoiseAmountArg;
+
+impl PicturifyArg for SaltAndPepperNoiseAmountArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('a')
+            .long("amount")
+            .help("Fraction of pixels replaced by white or black")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "amount"
+    }
+}
+
+pub struct SaltAndPepperNoiseSaltRatioArg;
+
+impl PicturifyArg for SaltAndPepperNoiseSaltRatioArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .long("salt-ratio")
+            .help("Fraction of the replaced pixels that become white")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "salt-ratio"
+    }
+}
+
+pub struct SaltAndPepperNoiseCommand;
+
+impl CommandForImage for SaltAndPepperNoiseCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(SaltAndPepperNoiseAmountArg::create(DEFAULT_ARGS.amount))
+            .arg(SaltAndPepperNoiseSaltRatioArg::create(
+                DEFAULT_ARGS.salt_ratio,
+            ))
+            .arg(SeedArg::create(None))
+    }
+
+    fn name() -> &'static str {
+        "salt-and-pepper-noise"
+    }
+
+    fn group() -> Group {
+        Group::Noise
+    }
+}
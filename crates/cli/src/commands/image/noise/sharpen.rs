@@ -1,18 +1,147 @@
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
 use crate::commands::common::command::CommandForImage;
 use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg, ArgAction};
+use picturify_processing::processors::noise::sharpen::SharpenMethod;
 
 struct SharpenDefaultArgs {
     fast: &'static str,
+    method: &'static str,
+    radius: &'static str,
+    sigma: &'static str,
+    amount: &'static str,
+    threshold: &'static str,
+    luminance_only: &'static str,
 }
 
-const DEFAULT_ARGS: SharpenDefaultArgs = SharpenDefaultArgs { fast: "false" };
+const DEFAULT_ARGS: SharpenDefaultArgs = SharpenDefaultArgs {
+    fast: "false",
+    method: "unsharp-mask",
+    radius: "2",
+    sigma: "1.0",
+    amount: "1.0",
+    threshold: "0.0",
+    luminance_only: "false",
+};
+
+pub struct SharpenMethodArg;
+
+impl PicturifyArg for SharpenMethodArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('m')
+            .long("method")
+            .help("Sharpening method, kernel is the fixed 3x3 kernel")
+            .default_value(default_value)
+            .value_parser(value_parser!(SharpenMethod))
+    }
+
+    fn id() -> &'static str {
+        "method"
+    }
+}
+
+pub struct SharpenRadiusArg;
+
+impl PicturifyArg for SharpenRadiusArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('r')
+            .long("radius")
+            .help("Gaussian blur radius")
+            .default_value(default_value)
+            .value_parser(value_parser!(usize))
+    }
+
+    fn id() -> &'static str {
+        "radius"
+    }
+}
+
+pub struct SharpenSigmaArg;
+
+impl PicturifyArg for SharpenSigmaArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("sigma")
+            .help("Gaussian blur sigma")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "sigma"
+    }
+}
+
+pub struct SharpenAmountArg;
+
+impl PicturifyArg for SharpenAmountArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('a')
+            .long("amount")
+            .help("Sharpening strength, 1 is the full effect")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "amount"
+    }
+}
+
+pub struct SharpenThresholdArg;
+
+impl PicturifyArg for SharpenThresholdArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('t')
+            .long("threshold")
+            .help(
+                "Smallest difference in 0-255 channel units that gets sharpened, unsharp mask only",
+            )
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "threshold"
+    }
+}
+
+pub struct SharpenLuminanceOnlyArg;
+
+impl PicturifyArg for SharpenLuminanceOnlyArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('l')
+            .long("luminance-only")
+            .help("Sharpen only the Lab lightness, so color noise is not amplified")
+            .action(ArgAction::SetTrue)
+            .default_value(default_value)
+            .value_parser(value_parser!(bool))
+    }
+
+    fn id() -> &'static str {
+        "luminance-only"
+    }
+}
 
 pub struct SharpenCommand;
 
 impl CommandForImage for SharpenCommand {
     fn get() -> clap::Command {
-        Self::get_base().arg(FastArg::create(DEFAULT_ARGS.fast))
+        Self::get_base()
+            .arg(FastArg::create(DEFAULT_ARGS.fast))
+            .arg(SharpenMethodArg::create(DEFAULT_ARGS.method))
+            .arg(SharpenRadiusArg::create(DEFAULT_ARGS.radius))
+            .arg(SharpenSigmaArg::create(DEFAULT_ARGS.sigma))
+            .arg(SharpenAmountArg::create(DEFAULT_ARGS.amount))
+            .arg(SharpenThresholdArg::create(DEFAULT_ARGS.threshold))
+            .arg(SharpenLuminanceOnlyArg::create(DEFAULT_ARGS.luminance_only))
     }
 
     fn name() -> &'static str {
@@ -0,0 +1,46 @@
+use crate::commands::common::args::common::{PicturifyArg, SeedArg};
+use crate::commands::common::command::CommandForImage;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct SpeckleNoiseDefaultArgs {
+    sigma: &'static str,
+}
+
+const DEFAULT_ARGS: SpeckleNoiseDefaultArgs = SpeckleNoiseDefaultArgs { sigma: "0.2" };
+
+pub struct SpeckleNoiseSigmaArg;
+
+impl PicturifyArg for SpeckleNoiseSigmaArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("sigma")
+            .help("Standard deviation of the noise relative to the channel value")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "sigma"
+    }
+}
+
+pub struct SpeckleNoiseCommand;
+
+impl CommandForImage for SpeckleNoiseCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(SpeckleNoiseSigmaArg::create(DEFAULT_ARGS.sigma))
+            .arg(SeedArg::create(None))
+    }
+
+    fn name() -> &'static str {
+        "speckle-noise"
+    }
+
+    fn group() -> Group {
+        Group::Noise
+    }
+}
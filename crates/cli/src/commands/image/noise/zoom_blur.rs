@@ -0,0 +1,64 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForImage;
+use crate::commands::parsers::coord::CoordValueParser;
+use crate::common::filter_group::Group;
+use clap::builder::{IntoResettable, OsStr};
+use clap::{value_parser, Arg};
+
+struct ZoomBlurDefaultArgs {
+    strength: &'static str,
+}
+
+const DEFAULT_ARGS: ZoomBlurDefaultArgs = ZoomBlurDefaultArgs { strength: "0.2" };
+
+pub struct ZoomBlurCenterArg;
+
+impl PicturifyArg for ZoomBlurCenterArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('c')
+            .long("center")
+            .help("Point the blur zooms into in format <x>,<y>, the image center by default")
+            .default_value(default_value)
+            .value_parser(CoordValueParser::new())
+    }
+
+    fn id() -> &'static str {
+        "center"
+    }
+}
+
+pub struct ZoomBlurStrengthArg;
+
+impl PicturifyArg for ZoomBlurStrengthArg {
+    fn create(default_value: impl IntoResettable<OsStr>) -> Arg {
+        Arg::new(Self::id())
+            .short('s')
+            .long("strength")
+            .help("Fraction of the distance to the center every pixel is blurred over (0-1)")
+            .default_value(default_value)
+            .value_parser(value_parser!(f32))
+    }
+
+    fn id() -> &'static str {
+        "strength"
+    }
+}
+
+pub struct ZoomBlurCommand;
+
+impl CommandForImage for ZoomBlurCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(ZoomBlurCenterArg::create(None))
+            .arg(ZoomBlurStrengthArg::create(DEFAULT_ARGS.strength))
+    }
+
+    fn name() -> &'static str {
+        "zoom-blur"
+    }
+
+    fn group() -> Group {
+        Group::Noise
+    }
+}
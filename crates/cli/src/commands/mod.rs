@@ -1,4 +1,5 @@
 pub mod common;
+pub mod generate;
 pub mod image;
 pub mod movie;
 pub mod parsers;
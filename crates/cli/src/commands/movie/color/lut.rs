@@ -0,0 +1,22 @@
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::command::CommandForMovie;
+use crate::commands::image::color::lut::{LutFileArg, LutInterpolationArg, DEFAULT_ARGS};
+use crate::common::filter_group::Group;
+
+pub struct LutCommand;
+
+impl CommandForMovie for LutCommand {
+    fn get() -> clap::Command {
+        Self::get_base()
+            .arg(LutFileArg::create(None))
+            .arg(LutInterpolationArg::create(DEFAULT_ARGS.interpolation))
+    }
+
+    fn name() -> &'static str {
+        "lut"
+    }
+
+    fn group() -> Group {
+        Group::Color
+    }
+}
@@ -1 +1,2 @@
+pub mod lut;
 pub mod negative;
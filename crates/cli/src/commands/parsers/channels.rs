@@ -0,0 +1,60 @@
+use std::ffi::OsStr;
+
+use clap::builder::TypedValueParser;
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+
+use picturify_processing::processors::noise::convolve::ConvolveChannels;
+
+#[derive(Debug, Copy, Clone)]
+pub struct ChannelsValueParser;
+
+impl ChannelsValueParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TypedValueParser for ChannelsValueParser {
+    type Value = ConvolveChannels;
+
+    fn parse_ref(
+        &self,
+        _cmd: &Command,
+        _arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let error = || {
+            Error::raw(
+                ErrorKind::InvalidValue,
+                "Invalid channels, expected any of the letters r, g, b and a, for example: rgb\n",
+            )
+        };
+        let value = value.to_str().ok_or_else(error)?;
+        if value.is_empty() {
+            return Err(error());
+        }
+
+        let mut channels = ConvolveChannels {
+            red: false,
+            green: false,
+            blue: false,
+            alpha: false,
+        };
+        for letter in value.chars() {
+            let channel = match letter.to_ascii_lowercase() {
+                'r' => &mut channels.red,
+                'g' => &mut channels.green,
+                'b' => &mut channels.blue,
+                'a' => &mut channels.alpha,
+                _ => return Err(error()),
+            };
+            if *channel {
+                return Err(error());
+            }
+            *channel = true;
+        }
+
+        Ok(channels)
+    }
+}
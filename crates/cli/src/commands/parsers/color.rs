@@ -0,0 +1,57 @@
+use std::ffi::OsStr;
+
+use clap::builder::TypedValueParser;
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+use regex::Regex;
+
+use picturify_core::image::Rgba;
+
+#[derive(Debug, Copy, Clone)]
+pub struct ColorValueParser;
+
+impl ColorValueParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TypedValueParser for ColorValueParser {
+    type Value = Rgba<u8>;
+
+    fn parse_ref(
+        &self,
+        _cmd: &Command,
+        _arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let error = || {
+            Error::raw(
+                ErrorKind::InvalidValue,
+                "Invalid color, expected format: #rrggbb, #rrggbbaa or <r>,<g>,<b>[,<a>]\n",
+            )
+        };
+        let value = value.to_str().ok_or_else(error)?;
+
+        let hex_regex = Regex::new(r"^#([0-9a-fA-F]{6}|[0-9a-fA-F]{8})$").unwrap();
+        if let Some(captures) = hex_regex.captures(value) {
+            let hex = captures.get(1).unwrap().as_str();
+            let mut channels = [u8::MAX; 4];
+            for (i, channel) in channels.iter_mut().enumerate().take(hex.len() / 2) {
+                *channel = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+            }
+            return Ok(Rgba(channels));
+        }
+
+        let list_regex = Regex::new(r"^(\d+),(\d+),(\d+)(?:,(\d+))?$").unwrap();
+        let captures = list_regex.captures(value).ok_or_else(error)?;
+        let mut channels = [u8::MAX; 4];
+        for (i, channel) in channels.iter_mut().enumerate() {
+            if let Some(capture) = captures.get(i + 1) {
+                *channel = capture.as_str().parse::<u8>().map_err(|_| error())?;
+            }
+        }
+
+        Ok(Rgba(channels))
+    }
+}
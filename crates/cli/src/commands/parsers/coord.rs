@@ -0,0 +1,43 @@
+use std::ffi::OsStr;
+
+use clap::builder::TypedValueParser;
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+use regex::Regex;
+
+use picturify_core::geometry::coord::Coord;
+
+#[derive(Debug, Copy, Clone)]
+pub struct CoordValueParser;
+
+impl CoordValueParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TypedValueParser for CoordValueParser {
+    type Value = Coord;
+
+    fn parse_ref(
+        &self,
+        _cmd: &Command,
+        _arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let error = || {
+            Error::raw(
+                ErrorKind::InvalidValue,
+                "Invalid point, expected format: <x>,<y>\n",
+            )
+        };
+        let value = value.to_str().ok_or_else(error)?;
+
+        let regex = Regex::new(r"^(\d+),(\d+)$").unwrap();
+        let captures = regex.captures(value).ok_or_else(error)?;
+        let x = captures[1].parse::<i32>().map_err(|_| error())?;
+        let y = captures[2].parse::<i32>().map_err(|_| error())?;
+
+        Ok(Coord::new(x, y))
+    }
+}
@@ -25,19 +25,20 @@ impl TypedValueParser for CropBorderValueParser {
         _arg: Option<&Arg>,
         value: &OsStr,
     ) -> Result<Self::Value, Error> {
-        let regex = Regex::new(r"(\d+)x(\d+)\+(\d+)\+(\d+)").unwrap();
-        let value = value.to_str().unwrap();
-        let captures = regex.captures(value).ok_or_else(|| {
+        let error = || {
             Error::raw(
                 ErrorKind::InvalidValue,
                 "Invalid crop border, expected format: <width>x<height>+<x>+<y>\n",
             )
-        })?;
+        };
+        let regex = Regex::new(r"(\d+)x(\d+)\+(\d+)\+(\d+)").unwrap();
+        let value = value.to_str().ok_or_else(error)?;
+        let captures = regex.captures(value).ok_or_else(error)?;
 
-        let width = captures.get(1).unwrap().as_str().parse::<usize>().unwrap();
-        let height = captures.get(2).unwrap().as_str().parse::<usize>().unwrap();
-        let x_offset = captures.get(3).unwrap().as_str().parse::<usize>().unwrap();
-        let y_offset = captures.get(4).unwrap().as_str().parse::<usize>().unwrap();
+        let width = captures[1].parse::<usize>().map_err(|_| error())?;
+        let height = captures[2].parse::<usize>().map_err(|_| error())?;
+        let x_offset = captures[3].parse::<usize>().map_err(|_| error())?;
+        let y_offset = captures[4].parse::<usize>().map_err(|_| error())?;
 
         Ok(CropBorder::new(width, height, x_offset, y_offset))
     }
@@ -0,0 +1,54 @@
+use std::ffi::OsStr;
+
+use clap::builder::TypedValueParser;
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+use regex::Regex;
+
+use picturify_processing::processors::color::curves::Curve;
+
+#[derive(Debug, Copy, Clone)]
+pub struct CurveValueParser;
+
+impl CurveValueParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TypedValueParser for CurveValueParser {
+    type Value = Curve;
+
+    fn parse_ref(
+        &self,
+        _cmd: &Command,
+        _arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let error = || {
+            Error::raw(
+                ErrorKind::InvalidValue,
+                "Invalid curve, expected at least two points in format: <in>:<out>,<in>:<out>,... with values from 0 to 255\n",
+            )
+        };
+        let value = value.to_str().ok_or_else(error)?;
+
+        let regex = Regex::new(r"^(\d+):(\d+)$").unwrap();
+        let points = value
+            .split(',')
+            .map(|point| {
+                let captures = regex.captures(point).ok_or_else(error)?;
+                let x = captures[1].parse::<u8>().map_err(|_| error())?;
+                let y = captures[2].parse::<u8>().map_err(|_| error())?;
+                Ok((x as f32 / 255.0, y as f32 / 255.0))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let curve = Curve::new(points);
+        if !curve.is_valid() {
+            return Err(error());
+        }
+
+        Ok(curve)
+    }
+}
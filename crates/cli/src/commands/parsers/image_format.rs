@@ -0,0 +1,37 @@
+use std::ffi::OsStr;
+
+use clap::builder::TypedValueParser;
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+
+use picturify_core::image::ImageFormat;
+
+#[derive(Debug, Copy, Clone)]
+pub struct ImageFormatValueParser;
+
+impl ImageFormatValueParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TypedValueParser for ImageFormatValueParser {
+    type Value = ImageFormat;
+
+    fn parse_ref(
+        &self,
+        _cmd: &Command,
+        _arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let value = value.to_str().unwrap_or("");
+        ImageFormat::from_extension(value)
+            .filter(|format| format.writing_enabled())
+            .ok_or_else(|| {
+                Error::raw(
+                    ErrorKind::InvalidValue,
+                    "Invalid image format, expected an extension (e.g. png, jpg, webp)\n",
+                )
+            })
+    }
+}
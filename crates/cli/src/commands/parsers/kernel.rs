@@ -0,0 +1,37 @@
+use std::ffi::OsStr;
+
+use clap::builder::TypedValueParser;
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+
+use picturify_processing::common::kernels::convolution::ConvolutionKernel;
+
+#[derive(Debug, Copy, Clone)]
+pub struct KernelValueParser;
+
+impl KernelValueParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TypedValueParser for KernelValueParser {
+    type Value = ConvolutionKernel;
+
+    fn parse_ref(
+        &self,
+        _cmd: &Command,
+        _arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let error = || {
+            Error::raw(
+                ErrorKind::InvalidValue,
+                "Invalid kernel, expected rows of equal length in format: <v>,<v>,...;<v>,<v>,...;...\n",
+            )
+        };
+        let value = value.to_str().ok_or_else(error)?;
+
+        ConvolutionKernel::from_text(value).map_err(|_| error())
+    }
+}
@@ -1,4 +1,10 @@
 pub mod angle;
+pub mod channels;
+pub mod color;
+pub mod coord;
 pub mod crop_border;
+pub mod curve;
+pub mod image_format;
+pub mod kernel;
 pub mod scale_strategy;
 pub mod size;
@@ -22,7 +22,7 @@ impl TypedValueParser for ScaleStrategyValueParser {
         _arg: Option<&Arg>,
         value: &OsStr,
     ) -> Result<Self::Value, Error> {
-        match value.to_str().unwrap() {
+        match value.to_str().unwrap_or("") {
             "nearest-neighbor" => Ok(ScaleStrategy::NearestNeighbor),
             "nn" => Ok(ScaleStrategy::NearestNeighbor),
             "bilinear" => Ok(ScaleStrategy::Bilinear),
@@ -22,17 +22,18 @@ impl TypedValueParser for SizeValueParser {
         _arg: Option<&Arg>,
         value: &OsStr,
     ) -> Result<Self::Value, Error> {
-        let regex = regex::Regex::new(r"(\d+)x(\d+)").unwrap();
-        let value = value.to_str().unwrap();
-        let captures = regex.captures(value).ok_or_else(|| {
+        let error = || {
             Error::raw(
                 ErrorKind::InvalidValue,
                 "Invalid size, expected format: <width>x<height>\n",
             )
-        })?;
+        };
+        let regex = regex::Regex::new(r"(\d+)x(\d+)").unwrap();
+        let value = value.to_str().ok_or_else(error)?;
+        let captures = regex.captures(value).ok_or_else(error)?;
 
-        let width = captures.get(1).unwrap().as_str().parse::<usize>().unwrap();
-        let height = captures.get(2).unwrap().as_str().parse::<usize>().unwrap();
+        let width = captures[1].parse::<usize>().map_err(|_| error())?;
+        let height = captures[2].parse::<usize>().map_err(|_| error())?;
 
         Ok(Size::new(width, height))
     }
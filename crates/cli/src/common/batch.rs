@@ -0,0 +1,48 @@
+use clap::builder::TypedValueParser;
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+use std::ffi::OsStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExistingPolicy {
+    Overwrite,
+    Skip,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExistingPolicyValueParser;
+
+impl TypedValueParser for ExistingPolicyValueParser {
+    type Value = ExistingPolicy;
+
+    fn parse_ref(
+        &self,
+        _cmd: &Command,
+        _arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        match value.to_str() {
+            Some("overwrite") => Ok(ExistingPolicy::Overwrite),
+            Some("skip") => Ok(ExistingPolicy::Skip),
+            _ => Err(Error::raw(
+                ErrorKind::InvalidValue,
+                "Invalid existing file policy, expected 'overwrite' or 'skip'\n",
+            )),
+        }
+    }
+}
+
+pub struct NameTemplate<'a> {
+    pub stem: &'a str,
+    pub filter: &'a str,
+    pub extension: &'a str,
+}
+
+impl NameTemplate<'_> {
+    pub fn render(&self, template: &str) -> String {
+        template
+            .replace("{stem}", self.stem)
+            .replace("{filter}", self.filter)
+            .replace("{ext}", self.extension)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod batch;
 pub mod filter_group;
 pub mod logging;
 pub mod threading;
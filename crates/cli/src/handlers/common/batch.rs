@@ -0,0 +1,258 @@
+use std::fs::{create_dir_all, read_dir};
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+
+use picturify_core::core::animation::AnimatedImage;
+use picturify_core::core::io::{ReadFromFile, WriteToFile};
+use picturify_core::image::ImageFormat;
+use picturify_core::rayon::prelude::*;
+use picturify_core::{log_error, log_info};
+use picturify_pipeline::common::animation_pipeline::AnimationPipeline;
+use picturify_pipeline::common::tiled_pipeline::{TiledPipeline, TiledPipelineOptions};
+use picturify_processing::common::region::Region;
+
+use crate::commands::common::args::common::{
+    ExistingArg, FormatArg, IndexedArg, InputArg, NameTemplateArg, OutputArg, PicturifyArg,
+    TileHeightArg,
+};
+use crate::commands::common::command::CommandForImage;
+use crate::common::batch::{ExistingPolicy, NameTemplate};
+use crate::error::{CliPicturifyError, CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::color_management::{apply_color_management, read_icc_profile};
+use crate::handlers::common::handler::PipelineCommandHandler;
+use crate::handlers::common::image_io::{
+    is_animated_io, is_stdio_path, read_image_from_file, write_image_to_file,
+};
+use crate::handlers::common::region::{apply_region, read_region};
+use crate::progress::batch_progress_bar::create_progress_bar_for_batch;
+
+const GLOB_CHARACTERS: [char; 3] = ['*', '?', '['];
+const DEFAULT_EXTENSION: &str = "png";
+
+enum BatchItemOutcome {
+    Processed,
+    Skipped,
+}
+
+struct BatchOptions<'a> {
+    output_dir: &'a Path,
+    name_template: &'a str,
+    format: Option<ImageFormat>,
+    indexed: bool,
+    existing: ExistingPolicy,
+    tile_height: Option<usize>,
+    region: Region,
+}
+
+// an existing file is processed on its own, even when its name contains glob characters
+pub fn is_batch_input(input: &Path) -> bool {
+    input.is_dir() || (!input.is_file() && is_glob_pattern(input))
+}
+
+fn is_glob_pattern(input: &Path) -> bool {
+    input
+        .to_str()
+        .is_some_and(|input| input.contains(GLOB_CHARACTERS))
+}
+
+pub fn run_batch<H>(handler: &H, args: ArgMatches) -> CliPicturifyResult<()>
+where
+    H: PipelineCommandHandler,
+{
+    let input = args
+        .get_one::<PathBuf>(InputArg::id())
+        .map_to_unknown_error()?;
+    let output_dir = args
+        .get_one::<PathBuf>(OutputArg::id())
+        .map_to_unknown_error()?;
+    let name_template = args
+        .get_one::<String>(NameTemplateArg::id())
+        .map_to_unknown_error()?;
+    let existing = args
+        .get_one::<ExistingPolicy>(ExistingArg::id())
+        .map_to_unknown_error()?;
+    let format = args.get_one::<ImageFormat>(FormatArg::id()).copied();
+    let tile_height = args.get_one::<usize>(TileHeightArg::id()).copied();
+    let indexed = args.get_flag(IndexedArg::id());
+
+    if tile_height.is_some() && format.is_some() {
+        return Err(CliPicturifyError::Command(
+            "tiled processing does not support --format".to_string(),
+        ));
+    }
+    if tile_height.is_some() && indexed {
+        return Err(CliPicturifyError::Command(
+            "tiled processing does not support --indexed".to_string(),
+        ));
+    }
+
+    if is_stdio_path(output_dir) {
+        return Err(CliPicturifyError::Command(
+            "output must be a directory when processing multiple files".to_string(),
+        ));
+    }
+    if let Some(id) = handler
+        .single_image_args()
+        .into_iter()
+        .find(|id| args.get_raw(id).is_some())
+    {
+        return Err(CliPicturifyError::Command(format!(
+            "--{} can not be used when processing multiple files",
+            id
+        )));
+    }
+
+    let inputs = collect_inputs(input)?;
+    if inputs.is_empty() {
+        return Err(CliPicturifyError::Command(format!(
+            "no images found for input {}",
+            input.display()
+        )));
+    }
+
+    create_dir_all(output_dir)
+        .map_err(|_| CliPicturifyError::Command("error creating output directory".to_string()))?;
+
+    let options = BatchOptions {
+        output_dir,
+        name_template,
+        format,
+        indexed,
+        existing: *existing,
+        tile_height,
+        region: read_region(&args)?,
+    };
+
+    let bar = create_progress_bar_for_batch(inputs.len());
+    let results = inputs
+        .par_iter()
+        .map(|input| {
+            let result = process_batch_item(handler, &args, &options, input);
+            bar.inc(1);
+            (input, result)
+        })
+        .collect::<Vec<_>>();
+    bar.finish();
+    eprintln!();
+
+    let mut processed = 0;
+    let mut skipped = 0;
+    let mut failures = Vec::new();
+    for (input, result) in results {
+        match result {
+            Ok(BatchItemOutcome::Processed) => processed += 1,
+            Ok(BatchItemOutcome::Skipped) => skipped += 1,
+            Err(e) => failures.push((input, e)),
+        }
+    }
+
+    log_info!(format!(
+        "Processed {} files, skipped {}, failed {}",
+        processed,
+        skipped,
+        failures.len()
+    ));
+
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    for (input, e) in &failures {
+        log_error!(format!("{}: {}", input.display(), e));
+    }
+
+    Err(CliPicturifyError::Command(format!(
+        "{} of {} files failed",
+        failures.len(),
+        inputs.len()
+    )))
+}
+
+fn collect_inputs(input: &Path) -> CliPicturifyResult<Vec<PathBuf>> {
+    let mut inputs = if input.is_dir() {
+        read_dir(input)
+            .map_err(|_| CliPicturifyError::Command("error reading input directory".to_string()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && is_readable_image(path))
+            .collect::<Vec<_>>()
+    } else {
+        let pattern = input.to_str().ok_or(CliPicturifyError::Command(
+            "invalid glob pattern".to_string(),
+        ))?;
+        glob::glob(pattern)
+            .map_err(|e| CliPicturifyError::Command(format!("invalid glob pattern: {}", e)))?
+            .filter_map(|path| path.ok())
+            .filter(|path| path.is_file())
+            .collect::<Vec<_>>()
+    };
+    inputs.sort();
+
+    Ok(inputs)
+}
+
+fn is_readable_image(path: &Path) -> bool {
+    ImageFormat::from_path(path).is_ok_and(|format| format.reading_enabled())
+}
+
+fn process_batch_item<H>(
+    handler: &H,
+    args: &ArgMatches,
+    options: &BatchOptions,
+    input: &Path,
+) -> CliPicturifyResult<BatchItemOutcome>
+where
+    H: PipelineCommandHandler,
+{
+    let output = get_batch_output_path::<H>(options, input);
+    if options.existing == ExistingPolicy::Skip && output.exists() {
+        return Ok(BatchItemOutcome::Skipped);
+    }
+
+    let pipeline = apply_region(handler.create_pipeline(args)?, &options.region);
+    let pipeline = apply_color_management(pipeline, read_icc_profile(input).as_deref(), args)?;
+    if let Some(tile_height) = options.tile_height {
+        let tiled_pipeline = TiledPipeline::new(TiledPipelineOptions { tile_height }, pipeline);
+        tiled_pipeline.run(input, &output, None)?;
+        return Ok(BatchItemOutcome::Processed);
+    }
+
+    if is_animated_io(input, &output, options.format)? {
+        let animation = AnimatedImage::read_from_file(input)?;
+        let result_animation = AnimationPipeline::new(pipeline).run(animation, None)?;
+        result_animation.write_to_file(&output)?;
+        return Ok(BatchItemOutcome::Processed);
+    }
+
+    let image = read_image_from_file(input)?;
+    let result_image = pipeline.run(image, None)?;
+    write_image_to_file(&result_image, &output, options.format, options.indexed)?;
+
+    Ok(BatchItemOutcome::Processed)
+}
+
+fn get_batch_output_path<H>(options: &BatchOptions, input: &Path) -> PathBuf
+where
+    H: PipelineCommandHandler,
+{
+    let stem = input
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    let extension = match options.format {
+        Some(format) => format.extensions_str()[0].to_string(),
+        None => input
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_string())
+            .unwrap_or(DEFAULT_EXTENSION.to_string()),
+    };
+
+    let name = NameTemplate {
+        stem: &stem,
+        filter: H::Command::name(),
+        extension: &extension,
+    }
+    .render(options.name_template);
+
+    options.output_dir.join(name)
+}
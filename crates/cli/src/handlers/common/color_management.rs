@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use picturify_core::conversions::icc::{
+    read_icc_profile_from_file, ColorProfile, ColorTransform, WorkingSpace,
+};
+use picturify_pipeline::common::color_managed_pipeline::ColorManagedPipeline;
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::{PicturifyArg, WorkingSpaceArg};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+
+pub fn read_icc_profile(path: &Path) -> Option<Vec<u8>> {
+    read_icc_profile_from_file(path).ok().flatten()
+}
+
+// Wraps the pipeline so that it runs in the working space, images without an ICC profile
+// are assumed to be sRGB and the output is always converted to sRGB. Profiles that can't be
+// applied are an error, processing them as sRGB would silently shift their colors.
+pub fn apply_color_management(
+    pipeline: Box<dyn Pipeline>,
+    icc_profile: Option<&[u8]>,
+    args: &ArgMatches,
+) -> CliPicturifyResult<Box<dyn Pipeline>> {
+    let working_space = args
+        .get_one::<WorkingSpace>(WorkingSpaceArg::id())
+        .map_to_unknown_error()?;
+
+    let input_profile = match icc_profile {
+        Some(icc_profile) => ColorProfile::from_icc(icc_profile)?,
+        None if *working_space == WorkingSpace::Srgb => return Ok(pipeline),
+        None => WorkingSpace::Srgb.profile(),
+    };
+
+    let working_profile = working_space.profile();
+    let input = ColorTransform::new(&input_profile, &working_profile)?;
+    let output = ColorTransform::new(&working_profile, &WorkingSpace::Srgb.profile())?;
+    if input.is_identity() && output.is_identity() {
+        return Ok(pipeline);
+    }
+
+    Ok(Box::new(ColorManagedPipeline::new(input, output, pipeline)))
+}
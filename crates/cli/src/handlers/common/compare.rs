@@ -0,0 +1,112 @@
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+use serde_json::json;
+
+use picturify_core::error::pipeline::PipelinePicturifyError;
+use picturify_processing::common::metrics::{
+    difference_image, ChannelDifference, ChannelStatistics, ComparisonMetrics, ImageStatistics,
+};
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::common::compare::{
+    CompareDiffArg, CompareFirstArg, CompareJsonArg, CompareSecondArg, CompareThresholdArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::CommandHandler;
+use crate::handlers::common::image_io::{read_image_from_file, write_image_to_file};
+
+pub struct CompareCommandHandler;
+
+impl CommandHandler for CompareCommandHandler {
+    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
+        let first_path = args
+            .get_one::<PathBuf>(CompareFirstArg::id())
+            .map_to_unknown_error()?;
+        let second_path = args
+            .get_one::<PathBuf>(CompareSecondArg::id())
+            .map_to_unknown_error()?;
+        let first = read_image_from_file(first_path)?;
+        let second = read_image_from_file(second_path)?;
+
+        let metrics =
+            ComparisonMetrics::new(&first, &second).map_err(PipelinePicturifyError::from)?;
+        let first_statistics = ImageStatistics::new(&first);
+        let second_statistics = ImageStatistics::new(&second);
+
+        if let Some(diff_path) = args.get_one::<PathBuf>(CompareDiffArg::id()) {
+            let threshold = *args
+                .get_one::<u8>(CompareThresholdArg::id())
+                .map_to_unknown_error()?;
+            let diff = difference_image(&first, &second, threshold)
+                .map_err(PipelinePicturifyError::from)?;
+            write_image_to_file(&diff, diff_path, None, false)?;
+        }
+
+        if args.get_flag(CompareJsonArg::id()) {
+            let report = json!({
+                "first": first_statistics,
+                "second": second_statistics,
+                "metrics": metrics,
+            });
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&report).map_to_unknown_error()?
+            );
+            return Ok(());
+        }
+
+        println!("{:<10}{:>12}", "metric", "value");
+        println!("{:<10}{:>12.4}", "mae", metrics.mae);
+        println!("{:<10}{:>12.4}", "mse", metrics.mse);
+        println!("{:<10}{:>12.4}", "psnr", metrics.psnr);
+        println!("{:<10}{:>12.6}", "ssim", metrics.ssim);
+        println!("{:<10}{:>12.6}", "ms-ssim", metrics.ms_ssim);
+
+        println!();
+        println!(
+            "{:<10}{:>12}{:>12}{:>12}{:>12}",
+            "channel", "mae", "mse", "psnr", "max delta"
+        );
+        for (name, difference) in channel_differences(&metrics) {
+            println!(
+                "{:<10}{:>12.4}{:>12.4}{:>12.4}{:>12}",
+                name, difference.mae, difference.mse, difference.psnr, difference.max_delta
+            );
+        }
+
+        for (title, statistics) in [("first", first_statistics), ("second", second_statistics)] {
+            println!();
+            println!(
+                "{:<10}{:>12}{:>12}{:>12}{:>12}",
+                title, "mean", "std dev", "min", "max"
+            );
+            for (name, channel) in channel_statistics(&statistics) {
+                println!(
+                    "{:<10}{:>12.4}{:>12.4}{:>12}{:>12}",
+                    name, channel.mean, channel.std_dev, channel.min, channel.max
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn channel_differences(metrics: &ComparisonMetrics) -> [(&'static str, ChannelDifference); 4] {
+    [
+        ("red", metrics.red),
+        ("green", metrics.green),
+        ("blue", metrics.blue),
+        ("alpha", metrics.alpha),
+    ]
+}
+
+fn channel_statistics(statistics: &ImageStatistics) -> [(&'static str, ChannelStatistics); 4] {
+    [
+        ("red", statistics.red),
+        ("green", statistics.green),
+        ("blue", statistics.blue),
+        ("alpha", statistics.alpha),
+    ]
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use clap::ArgMatches;
+
+use picturify_core::error::pipeline::PipelinePicturifyError;
+use picturify_core::geometry::size::Size;
+use picturify_processing::common::generators::CpuGenerator;
+
+use crate::commands::common::args::common::{PicturifyArg, SizeArg};
+use crate::commands::common::command::{Command, CommandForGenerator};
+use crate::commands::common::generate::GenerateCommand;
+use crate::commands::generate::checkerboard::CheckerboardCommand;
+use crate::commands::generate::color_bars::ColorBarsCommand;
+use crate::commands::generate::gradient::GradientCommand;
+use crate::commands::generate::perlin_noise::PerlinNoiseCommand;
+use crate::commands::generate::zone_plate::ZonePlateCommand;
+use crate::common::logging::log_help;
+use crate::error::{CliPicturifyError, CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::{CommandHandler, PipelineProgressBar};
+use crate::handlers::common::image_io::write_image;
+use crate::handlers::generate::checkerboard::CheckerboardCommandHandler;
+use crate::handlers::generate::color_bars::ColorBarsCommandHandler;
+use crate::handlers::generate::gradient::GradientCommandHandler;
+use crate::handlers::generate::perlin_noise::PerlinNoiseCommandHandler;
+use crate::handlers::generate::zone_plate::ZonePlateCommandHandler;
+
+pub fn run_generator(
+    args: &ArgMatches,
+    generator: Box<dyn CpuGenerator>,
+) -> CliPicturifyResult<()> {
+    let size = args.get_one::<Size>(SizeArg::id()).map_to_unknown_error()?;
+
+    let bar = PipelineProgressBar::start();
+    let mut pipeline_progress = bar.get_pipeline_progress();
+    pipeline_progress.new_individual(generator.name().to_string());
+    pipeline_progress.setup_combined(1);
+
+    let image = generator
+        .generate(*size, pipeline_progress.get_current_individual_progress())
+        .map_err(PipelinePicturifyError::from)?;
+    pipeline_progress.increment_combined();
+
+    bar.stop()?;
+
+    write_image(image, args.clone())
+}
+
+pub struct GenerateCommandHandler;
+
+impl CommandHandler for GenerateCommandHandler {
+    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
+        match args.subcommand() {
+            Some((name, args)) => {
+                let mut handlers: HashMap<&str, Box<dyn CommandHandler>> = HashMap::new();
+
+                handlers.insert(
+                    CheckerboardCommand::name(),
+                    Box::new(CheckerboardCommandHandler),
+                );
+                handlers.insert(GradientCommand::name(), Box::new(GradientCommandHandler));
+                handlers.insert(ColorBarsCommand::name(), Box::new(ColorBarsCommandHandler));
+                handlers.insert(ZonePlateCommand::name(), Box::new(ZonePlateCommandHandler));
+                handlers.insert(
+                    PerlinNoiseCommand::name(),
+                    Box::new(PerlinNoiseCommandHandler),
+                );
+
+                if let Some(handler) = handlers.get(name) {
+                    handler.handle(args.clone())
+                } else {
+                    Err(CliPicturifyError::invalid_subcommand())
+                }
+            }
+            None => {
+                log_help(&mut GenerateCommand::create());
+                Err(CliPicturifyError::missing_subcommand())
+            }
+        }
+    }
+}
@@ -1,12 +1,28 @@
+use std::path::{Path, PathBuf};
 use std::thread::{spawn, JoinHandle};
 
 use clap::ArgMatches;
 
+use picturify_core::core::animation::AnimatedImage;
 use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::{ReadFromFile, WriteToFile};
+use picturify_core::image::ImageFormat;
+use picturify_core::log_info;
+use picturify_pipeline::common::animation_pipeline::AnimationPipeline;
 use picturify_pipeline::common::pipeline_progress::PipelineProgress;
+use picturify_pipeline::common::tiled_pipeline::{TiledPipeline, TiledPipelineOptions};
 use picturify_pipeline::pipeline::Pipeline;
 
-use crate::error::{CliPicturifyError, CliPicturifyResult};
+use crate::commands::common::args::common::{
+    ExistingArg, FormatArg, IndexedArg, InputArg, OutputArg, PicturifyArg, TileHeightArg,
+};
+use crate::commands::common::command::CommandForImage;
+use crate::common::batch::ExistingPolicy;
+use crate::error::{CliPicturifyError, CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::batch::{is_batch_input, run_batch};
+use crate::handlers::common::color_management::{apply_color_management, read_icc_profile};
+use crate::handlers::common::image_io::{is_animated_io, is_stdio_path, read_image, write_image};
+use crate::handlers::common::region::{apply_region, read_region};
 use crate::progress::pipeline_progress_bar::run_progress_bar_for_pipeline;
 
 pub fn run_pipeline(
@@ -22,6 +38,40 @@ pub fn run_pipeline(
     Ok(result_image)
 }
 
+pub fn run_tiled_pipeline(
+    input: &Path,
+    output: &Path,
+    pipeline: Box<dyn Pipeline>,
+    tile_height: usize,
+) -> CliPicturifyResult<()> {
+    let bar = PipelineProgressBar::start();
+
+    let tiled_pipeline = TiledPipeline::new(TiledPipelineOptions { tile_height }, pipeline);
+    tiled_pipeline.run(input, output, Some(bar.get_pipeline_progress()))?;
+
+    bar.stop()?;
+
+    Ok(())
+}
+
+pub fn run_animated_pipeline(
+    input: &Path,
+    output: &Path,
+    pipeline: Box<dyn Pipeline>,
+) -> CliPicturifyResult<()> {
+    let animation = AnimatedImage::read_from_file(input)?;
+    let bar = PipelineProgressBar::start();
+
+    let animation_pipeline = AnimationPipeline::new(pipeline);
+    let result_animation = animation_pipeline.run(animation, Some(bar.get_pipeline_progress()))?;
+
+    bar.stop()?;
+
+    result_animation.write_to_file(output)?;
+
+    Ok(())
+}
+
 pub struct PipelineProgressBar {
     pipeline_progress: PipelineProgress,
     join_handle: JoinHandle<()>,
@@ -56,3 +106,78 @@ impl PipelineProgressBar {
 pub trait CommandHandler {
     fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()>;
 }
+
+pub trait PipelineCommandHandler: Sync {
+    type Command: CommandForImage;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>>;
+
+    // arguments naming a single output file, rejected when processing multiple files
+    fn single_image_args(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+}
+
+impl<T> CommandHandler for T
+where
+    T: PipelineCommandHandler,
+{
+    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
+        let input = args
+            .get_one::<PathBuf>(InputArg::id())
+            .map_to_unknown_error()?;
+        if is_batch_input(input) {
+            return run_batch(self, args);
+        }
+
+        let output = args
+            .get_one::<PathBuf>(OutputArg::id())
+            .map_to_unknown_error()?;
+        let existing = args
+            .get_one::<ExistingPolicy>(ExistingArg::id())
+            .map_to_unknown_error()?;
+        if *existing == ExistingPolicy::Skip && !is_stdio_path(output) && output.exists() {
+            log_info!(format!(
+                "Skipping {}, output already exists",
+                output.display()
+            ));
+            return Ok(());
+        }
+
+        let region = read_region(&args)?;
+        let pipeline = apply_region(self.create_pipeline(&args)?, &region);
+
+        if let Some(tile_height) = args.get_one::<usize>(TileHeightArg::id()) {
+            let format = args.get_one::<ImageFormat>(FormatArg::id());
+            if is_stdio_path(input)
+                || is_stdio_path(output)
+                || format.is_some()
+                || args.get_flag(IndexedArg::id())
+            {
+                return Err(CliPicturifyError::Command(
+                    "tiled processing requires file input and output, without --format or --indexed"
+                        .to_string(),
+                ));
+            }
+            let icc_profile = read_icc_profile(input);
+            let pipeline = apply_color_management(pipeline, icc_profile.as_deref(), &args)?;
+            return run_tiled_pipeline(input, output, pipeline, *tile_height);
+        }
+
+        let format = args.get_one::<ImageFormat>(FormatArg::id()).copied();
+        if is_animated_io(input, output, format)? {
+            let icc_profile = read_icc_profile(input);
+            let pipeline = apply_color_management(pipeline, icc_profile.as_deref(), &args)?;
+            return run_animated_pipeline(input, output, pipeline);
+        }
+
+        let (image, icc_profile) = read_image(args.clone())?;
+        let pipeline = apply_color_management(pipeline, icc_profile.as_deref(), &args)?;
+
+        let result_image = run_pipeline(image, pipeline)?;
+
+        write_image(result_image, args.clone())?;
+
+        Ok(())
+    }
+}
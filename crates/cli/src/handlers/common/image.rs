@@ -6,45 +6,101 @@ use crate::commands::common::command::{Command, CommandForImage};
 use crate::commands::common::image::ImageCommand;
 use crate::commands::image::blob::laplacian_of_gaussian::LaplacianOfGaussianCommand;
 use crate::commands::image::color::brightness::BrightnessCommand;
+use crate::commands::image::color::channel_extraction::ChannelExtractionCommand;
+use crate::commands::image::color::channel_merge::ChannelMergeCommand;
+use crate::commands::image::color::curves::CurvesCommand;
+use crate::commands::image::color::extract_alpha::ExtractAlphaCommand;
+use crate::commands::image::color::flatten::FlattenCommand;
 use crate::commands::image::color::grayscale::GrayscaleCommand;
+use crate::commands::image::color::hue_rotation::HueRotationCommand;
+use crate::commands::image::color::levels::LevelsCommand;
+use crate::commands::image::color::lightness::LightnessCommand;
+use crate::commands::image::color::lut::LutCommand;
 use crate::commands::image::color::negative::NegativeCommand;
+use crate::commands::image::color::palette_quantization::PaletteQuantizationCommand;
 use crate::commands::image::color::quantization::QuantizationCommand;
+use crate::commands::image::color::saturation::SaturationCommand;
 use crate::commands::image::color::sepia::SepiaCommand;
+use crate::commands::image::color::set_alpha::SetAlphaCommand;
+use crate::commands::image::color::temperature::TemperatureCommand;
+use crate::commands::image::color::white_balance::WhiteBalanceCommand;
 use crate::commands::image::common::passthrough::PassthroughCommand;
+use crate::commands::image::edge::cartoon::CartoonCommand;
+use crate::commands::image::edge::difference_of_gaussians::DifferenceOfGaussiansCommand;
 use crate::commands::image::edge::emboss::EmbossCommand;
+use crate::commands::image::edge::gradient::GradientCommand;
 use crate::commands::image::edge::prewitt::PrewittCommand;
 use crate::commands::image::edge::sobel::SobelCommand;
+use crate::commands::image::edge::xdog::XDogCommand;
 use crate::commands::image::geometry::crop::CropCommand;
 use crate::commands::image::geometry::rotate::RotateCommand;
 use crate::commands::image::geometry::scale::ScaleCommand;
+use crate::commands::image::noise::anisotropic_diffusion::AnisotropicDiffusionCommand;
 use crate::commands::image::noise::bilateral_blur::BilateralBlurCommand;
+use crate::commands::image::noise::convolve::ConvolveCommand;
 use crate::commands::image::noise::gaussian_blur::GaussianBlurCommand;
+use crate::commands::image::noise::gaussian_noise::GaussianNoiseCommand;
 use crate::commands::image::noise::kuwahara::KuwaharaCommand;
 use crate::commands::image::noise::mean_blur::MeanBlurCommand;
 use crate::commands::image::noise::median_blur::MedianBlurCommand;
+use crate::commands::image::noise::motion_blur::MotionBlurCommand;
+use crate::commands::image::noise::non_local_means::NonLocalMeansCommand;
+use crate::commands::image::noise::poisson_noise::PoissonNoiseCommand;
+use crate::commands::image::noise::radial_blur::RadialBlurCommand;
+use crate::commands::image::noise::salt_and_pepper_noise::SaltAndPepperNoiseCommand;
 use crate::commands::image::noise::sharpen::SharpenCommand;
+use crate::commands::image::noise::speckle_noise::SpeckleNoiseCommand;
+use crate::commands::image::noise::zoom_blur::ZoomBlurCommand;
 use crate::common::logging::log_help;
 use crate::error::{CliPicturifyError, CliPicturifyResult};
 use crate::handlers::common::handler::CommandHandler;
 use crate::handlers::image::blob::laplacian_of_gaussian::LaplacianOfGaussianCommandHandler;
 use crate::handlers::image::color::brightness::BrightnessCommandHandler;
+use crate::handlers::image::color::channel_extraction::ChannelExtractionCommandHandler;
+use crate::handlers::image::color::channel_merge::ChannelMergeCommandHandler;
+use crate::handlers::image::color::curves::CurvesCommandHandler;
+use crate::handlers::image::color::extract_alpha::ExtractAlphaCommandHandler;
+use crate::handlers::image::color::flatten::FlattenCommandHandler;
 use crate::handlers::image::color::grayscale::GrayscaleCommandHandler;
+use crate::handlers::image::color::hue_rotation::HueRotationCommandHandler;
+use crate::handlers::image::color::levels::LevelsCommandHandler;
+use crate::handlers::image::color::lightness::LightnessCommandHandler;
+use crate::handlers::image::color::lut::LutCommandHandler;
 use crate::handlers::image::color::negative::NegativeCommandHandler;
+use crate::handlers::image::color::palette_quantization::PaletteQuantizationCommandHandler;
 use crate::handlers::image::color::quantization::QuantizationCommandHandler;
+use crate::handlers::image::color::saturation::SaturationCommandHandler;
 use crate::handlers::image::color::sepia::SepiaCommandHandler;
+use crate::handlers::image::color::set_alpha::SetAlphaCommandHandler;
+use crate::handlers::image::color::temperature::TemperatureCommandHandler;
+use crate::handlers::image::color::white_balance::WhiteBalanceCommandHandler;
 use crate::handlers::image::common::passthrough::PassthroughCommandHandler;
+use crate::handlers::image::edge::cartoon::CartoonCommandHandler;
+use crate::handlers::image::edge::difference_of_gaussians::DifferenceOfGaussiansCommandHandler;
 use crate::handlers::image::edge::emboss::EmbossCommandHandler;
+use crate::handlers::image::edge::gradient::GradientCommandHandler;
 use crate::handlers::image::edge::prewitt::PrewittCommandHandler;
 use crate::handlers::image::edge::sobel::SobelCommandHandler;
+use crate::handlers::image::edge::xdog::XDogCommandHandler;
 use crate::handlers::image::geometry::crop::CropCommandHandler;
 use crate::handlers::image::geometry::rotate::RotateCommandHandler;
 use crate::handlers::image::geometry::scale::ScaleCommandHandler;
+use crate::handlers::image::noise::anisotropic_diffusion::AnisotropicDiffusionCommandHandler;
 use crate::handlers::image::noise::bilateral_blur::BilateralBlurCommandHandler;
+use crate::handlers::image::noise::convolve::ConvolveCommandHandler;
 use crate::handlers::image::noise::gaussian_blur::GaussianBlurCommandHandler;
+use crate::handlers::image::noise::gaussian_noise::GaussianNoiseCommandHandler;
 use crate::handlers::image::noise::kuwahara::KuwaharaCommandHandler;
 use crate::handlers::image::noise::mean_blur::MeanBlurCommandHandler;
 use crate::handlers::image::noise::median_blur::MedianBlurCommandHandler;
+use crate::handlers::image::noise::motion_blur::MotionBlurCommandHandler;
+use crate::handlers::image::noise::non_local_means::NonLocalMeansCommandHandler;
+use crate::handlers::image::noise::poisson_noise::PoissonNoiseCommandHandler;
+use crate::handlers::image::noise::radial_blur::RadialBlurCommandHandler;
+use crate::handlers::image::noise::salt_and_pepper_noise::SaltAndPepperNoiseCommandHandler;
 use crate::handlers::image::noise::sharpen::SharpenCommandHandler;
+use crate::handlers::image::noise::speckle_noise::SpeckleNoiseCommandHandler;
+use crate::handlers::image::noise::zoom_blur::ZoomBlurCommandHandler;
 
 pub struct ImageCommandHandler;
 
@@ -78,6 +134,44 @@ impl CommandHandler for ImageCommandHandler {
                     QuantizationCommand::name(),
                     Box::new(QuantizationCommandHandler),
                 );
+                handlers.insert(
+                    ExtractAlphaCommand::name(),
+                    Box::new(ExtractAlphaCommandHandler),
+                );
+                handlers.insert(SetAlphaCommand::name(), Box::new(SetAlphaCommandHandler));
+                handlers.insert(FlattenCommand::name(), Box::new(FlattenCommandHandler));
+                handlers.insert(
+                    HueRotationCommand::name(),
+                    Box::new(HueRotationCommandHandler),
+                );
+                handlers.insert(
+                    SaturationCommand::name(),
+                    Box::new(SaturationCommandHandler),
+                );
+                handlers.insert(LightnessCommand::name(), Box::new(LightnessCommandHandler));
+                handlers.insert(
+                    ChannelExtractionCommand::name(),
+                    Box::new(ChannelExtractionCommandHandler),
+                );
+                handlers.insert(
+                    ChannelMergeCommand::name(),
+                    Box::new(ChannelMergeCommandHandler),
+                );
+                handlers.insert(
+                    WhiteBalanceCommand::name(),
+                    Box::new(WhiteBalanceCommandHandler),
+                );
+                handlers.insert(
+                    TemperatureCommand::name(),
+                    Box::new(TemperatureCommandHandler),
+                );
+                handlers.insert(LevelsCommand::name(), Box::new(LevelsCommandHandler));
+                handlers.insert(CurvesCommand::name(), Box::new(CurvesCommandHandler));
+                handlers.insert(LutCommand::name(), Box::new(LutCommandHandler));
+                handlers.insert(
+                    PaletteQuantizationCommand::name(),
+                    Box::new(PaletteQuantizationCommandHandler),
+                );
 
                 // noise
                 handlers.insert(KuwaharaCommand::name(), Box::new(KuwaharaCommandHandler));
@@ -95,11 +189,52 @@ impl CommandHandler for ImageCommandHandler {
                     BilateralBlurCommand::name(),
                     Box::new(BilateralBlurCommandHandler),
                 );
+                handlers.insert(ConvolveCommand::name(), Box::new(ConvolveCommandHandler));
+                handlers.insert(
+                    NonLocalMeansCommand::name(),
+                    Box::new(NonLocalMeansCommandHandler),
+                );
+                handlers.insert(
+                    AnisotropicDiffusionCommand::name(),
+                    Box::new(AnisotropicDiffusionCommandHandler),
+                );
+                handlers.insert(
+                    MotionBlurCommand::name(),
+                    Box::new(MotionBlurCommandHandler),
+                );
+                handlers.insert(
+                    RadialBlurCommand::name(),
+                    Box::new(RadialBlurCommandHandler),
+                );
+                handlers.insert(ZoomBlurCommand::name(), Box::new(ZoomBlurCommandHandler));
+                handlers.insert(
+                    GaussianNoiseCommand::name(),
+                    Box::new(GaussianNoiseCommandHandler),
+                );
+                handlers.insert(
+                    SaltAndPepperNoiseCommand::name(),
+                    Box::new(SaltAndPepperNoiseCommandHandler),
+                );
+                handlers.insert(
+                    PoissonNoiseCommand::name(),
+                    Box::new(PoissonNoiseCommandHandler),
+                );
+                handlers.insert(
+                    SpeckleNoiseCommand::name(),
+                    Box::new(SpeckleNoiseCommandHandler),
+                );
 
                 // edge
                 handlers.insert(SobelCommand::name(), Box::new(SobelCommandHandler));
                 handlers.insert(PrewittCommand::name(), Box::new(PrewittCommandHandler));
                 handlers.insert(EmbossCommand::name(), Box::new(EmbossCommandHandler));
+                handlers.insert(
+                    DifferenceOfGaussiansCommand::name(),
+                    Box::new(DifferenceOfGaussiansCommandHandler),
+                );
+                handlers.insert(XDogCommand::name(), Box::new(XDogCommandHandler));
+                handlers.insert(CartoonCommand::name(), Box::new(CartoonCommandHandler));
+                handlers.insert(GradientCommand::name(), Box::new(GradientCommandHandler));
 
                 // geometry
                 handlers.insert(RotateCommand::name(), Box::new(RotateCommandHandler));
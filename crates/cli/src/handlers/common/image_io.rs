@@ -1,40 +1,191 @@
-use std::path::PathBuf;
+use std::fs::write;
+use std::io::{stdin, stdout, Read, Write};
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use clap::ArgMatches;
 
+use picturify_core::conversions::icc::{read_icc_profile_from_bytes, read_icc_profile_from_file};
+use picturify_core::core::animation::{is_animated, supports_animated_output};
+use picturify_core::core::color_palette::MAX_PALETTE_SIZE;
 use picturify_core::core::fast_image::FastImage;
-use picturify_core::core::io::{ReadFromFile, WriteToFile};
-use picturify_core::log_debug;
+use picturify_core::core::indexed_image::IndexedImage;
+use picturify_core::core::io::{ReadFromBytes, ReadFromFile, WriteToBytes, WriteToFile};
+use picturify_core::image::ImageFormat;
+use picturify_core::{log_debug, log_warn};
+use picturify_processing::common::palette_generation::{generate_palette, PaletteMethod};
 
-use crate::commands::common::args::common::{InputArg, OutputArg, PicturifyArg};
+use crate::commands::common::args::common::{
+    FormatArg, IndexedArg, InputArg, OutputArg, PicturifyArg,
+};
 use crate::error::{CliPicturifyError, CliPicturifyResult, MapToCliPicturifyResult};
 
-pub fn read_image(args: ArgMatches) -> CliPicturifyResult<FastImage> {
+const STDIO_PATH: &str = "-";
+
+pub fn is_stdio_path(path: &Path) -> bool {
+    path.as_os_str() == STDIO_PATH
+}
+
+// animated inputs keep every frame when written to a gif or png file without --format,
+// animated webp output is rejected rather than silently losing frames
+pub fn is_animated_io(
+    input: &Path,
+    output: &Path,
+    format: Option<ImageFormat>,
+) -> CliPicturifyResult<bool> {
+    if is_stdio_path(input) || !is_animated(input).unwrap_or(false) {
+        return Ok(false);
+    }
+    let output_format = format.or_else(|| ImageFormat::from_path(output).ok());
+    if output_format == Some(ImageFormat::WebP) {
+        return Err(CliPicturifyError::Command(
+            "writing animated webp is not supported, use a gif or png output".to_string(),
+        ));
+    }
+    if is_stdio_path(output) || format.is_some() || !supports_animated_output(output) {
+        log_warn!(format!(
+            "{} is animated, only the first frame is written",
+            input.display()
+        ));
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+// returns the image together with its embedded ICC profile, if any
+pub fn read_image(args: ArgMatches) -> CliPicturifyResult<(FastImage, Option<Vec<u8>>)> {
     let input = args
         .get_one::<PathBuf>(InputArg::id())
         .map_to_unknown_error()?;
 
     let read_start = Instant::now();
-    let image = FastImage::read_from_file(input)
-        .map_err(|_| CliPicturifyError::Command("error reading image".to_string()))?;
+    let (image, icc_profile) = if is_stdio_path(input) {
+        read_image_from_stdin()?
+    } else {
+        (
+            read_image_from_file(input)?,
+            read_icc_profile_from_file(input).ok().flatten(),
+        )
+    };
     let read_elapsed_ms = read_start.elapsed().as_millis();
     log_debug!(format!("Reading image took {}ms", read_elapsed_ms));
 
-    Ok(image)
+    Ok((image, icc_profile))
 }
 
 pub fn write_image(image: FastImage, args: ArgMatches) -> CliPicturifyResult<()> {
     let output = args
         .get_one::<PathBuf>(OutputArg::id())
         .map_to_unknown_error()?;
+    let format = args.get_one::<ImageFormat>(FormatArg::id()).copied();
+    let indexed = args.get_flag(IndexedArg::id());
 
     let write_start = Instant::now();
-    image
-        .write_to_file(output)
-        .map_err(|_| CliPicturifyError::Command("error writing image".to_string()))?;
+    match (is_stdio_path(output), format) {
+        (true, Some(format)) => write_image_to_stdout(&image, format, indexed)?,
+        (true, None) => {
+            return Err(CliPicturifyError::Command(
+                "--format is required when writing to stdout".to_string(),
+            ))
+        }
+        (false, format) => write_image_to_file(&image, output, format, indexed)?,
+    }
     let write_elapsed_ms = write_start.elapsed().as_millis();
     log_debug!(format!("Writing image took {}ms", write_elapsed_ms));
 
     Ok(())
 }
+
+pub fn read_image_from_file(path: &Path) -> CliPicturifyResult<FastImage> {
+    FastImage::read_from_file(path)
+        .map_err(|_| CliPicturifyError::Command("error reading image".to_string()))
+}
+
+pub fn write_image_to_file(
+    image: &FastImage,
+    path: &Path,
+    format: Option<ImageFormat>,
+    indexed: bool,
+) -> CliPicturifyResult<()> {
+    if indexed {
+        return write_indexed_image_to_file(image, path, format);
+    }
+
+    match format {
+        Some(format) => {
+            let bytes = image
+                .write_to_bytes(format)
+                .map_err(|_| CliPicturifyError::Command("error writing image".to_string()))?;
+            write(path, bytes)
+                .map_err(|_| CliPicturifyError::Command("error writing image".to_string()))
+        }
+        None => image
+            .write_to_file(path)
+            .map_err(|_| CliPicturifyError::Command("error writing image".to_string())),
+    }
+}
+
+// palette images keep their error, it explains why the image could not be indexed
+fn write_indexed_image_to_file(
+    image: &FastImage,
+    path: &Path,
+    format: Option<ImageFormat>,
+) -> CliPicturifyResult<()> {
+    let indexed_image = to_indexed_image(image)?;
+    match format {
+        Some(format) => {
+            let bytes = indexed_image.write_to_bytes(format)?;
+            write(path, bytes)
+                .map_err(|_| CliPicturifyError::Command("error writing image".to_string()))
+        }
+        None => Ok(indexed_image.write_to_file(path)?),
+    }
+}
+
+// images with too many colors are quantized, leaving one palette entry for transparency
+fn to_indexed_image(image: &FastImage) -> CliPicturifyResult<IndexedImage> {
+    if let Ok(indexed_image) = IndexedImage::from_fast_image_exact(image) {
+        return Ok(indexed_image);
+    }
+
+    log_warn!(format!(
+        "Image has more than {} colors, quantizing it",
+        MAX_PALETTE_SIZE
+    ));
+    let palette = generate_palette(image, MAX_PALETTE_SIZE - 1, PaletteMethod::MedianCut)?;
+    Ok(IndexedImage::from_fast_image(image, &palette)?)
+}
+
+fn read_image_from_stdin() -> CliPicturifyResult<(FastImage, Option<Vec<u8>>)> {
+    let mut bytes = Vec::new();
+    stdin()
+        .lock()
+        .read_to_end(&mut bytes)
+        .map_err(|_| CliPicturifyError::Command("error reading stdin".to_string()))?;
+
+    let image = FastImage::read_from_bytes(&bytes)
+        .map_err(|_| CliPicturifyError::Command("error reading image".to_string()))?;
+
+    Ok((image, read_icc_profile_from_bytes(&bytes).ok().flatten()))
+}
+
+fn write_image_to_stdout(
+    image: &FastImage,
+    format: ImageFormat,
+    indexed: bool,
+) -> CliPicturifyResult<()> {
+    let bytes = if indexed {
+        to_indexed_image(image)?.write_to_bytes(format)?
+    } else {
+        image
+            .write_to_bytes(format)
+            .map_err(|_| CliPicturifyError::Command("error writing image".to_string()))?
+    };
+
+    let mut stdout = stdout().lock();
+    stdout
+        .write_all(&bytes)
+        .and_then(|_| stdout.flush())
+        .map_err(|_| CliPicturifyError::Command("error writing stdout".to_string()))
+}
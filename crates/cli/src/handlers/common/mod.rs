@@ -1,6 +1,11 @@
+pub mod batch;
+pub mod color_management;
+pub mod compare;
 pub mod completions;
+pub mod generate;
 pub mod handler;
 pub mod image;
 pub mod image_io;
 pub mod movie;
 pub mod picturify;
+pub mod region;
@@ -1,17 +1,56 @@
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::thread::spawn;
 
 use clap::ArgMatches;
 use picturify_core::log_warn;
+use picturify_movie::movie_pipe::MoviePipe;
+use picturify_movie::progress::MovieProgress;
+use picturify_pipeline::pipeline::Pipeline;
 
+use crate::commands::common::args::common::{InputArg, OutputArg, PicturifyArg};
 use crate::commands::common::command::{Command, CommandForMovie};
 use crate::commands::common::movie::MovieCommand;
+use crate::commands::movie::color::lut::LutCommand;
 use crate::commands::movie::color::negative::NegativeCommand;
 use crate::commands::movie::edge::sobel::SobelCommand;
 use crate::common::logging::log_help;
-use crate::error::{CliPicturifyError, CliPicturifyResult};
+use crate::error::{CliPicturifyError, CliPicturifyResult, MapToCliPicturifyResult};
 use crate::handlers::common::handler::CommandHandler;
+use crate::handlers::movie::color::lut::LutCommandHandler;
 use crate::handlers::movie::color::negative::NegativeCommandHandler;
 use crate::handlers::movie::edge::sobel::SobelCommandHandler;
+use crate::progress::movie_progress_bar::run_progress_bar_for_movie;
+
+pub fn run_movie_pipeline(
+    args: &ArgMatches,
+    pipeline: Box<dyn Pipeline>,
+) -> CliPicturifyResult<()> {
+    let source = args
+        .get_one::<PathBuf>(InputArg::id())
+        .map_to_unknown_error()?;
+    let destination = args
+        .get_one::<PathBuf>(OutputArg::id())
+        .map_to_unknown_error()?;
+
+    let movie_progress = Arc::new(RwLock::new(MovieProgress::default()));
+    let movie_progress_clone = movie_progress.clone();
+
+    let handle = spawn(move || {
+        run_progress_bar_for_movie(movie_progress_clone);
+    });
+
+    MoviePipe::process(
+        source.to_string_lossy().to_string(),
+        destination.to_string_lossy().to_string(),
+        pipeline,
+        movie_progress.clone(),
+    )?;
+    handle.join().map_err(|_| CliPicturifyError::Threading)?;
+
+    Ok(())
+}
 
 pub struct MovieCommandHandler;
 
@@ -23,6 +62,7 @@ impl CommandHandler for MovieCommandHandler {
                 let mut handlers: HashMap<&str, Box<dyn CommandHandler>> = HashMap::new();
 
                 handlers.insert(NegativeCommand::name(), Box::new(NegativeCommandHandler));
+                handlers.insert(LutCommand::name(), Box::new(LutCommandHandler));
                 handlers.insert(SobelCommand::name(), Box::new(SobelCommandHandler));
 
                 if let Some(handler) = handlers.get(name) {
@@ -10,7 +10,9 @@ use crate::commands::common::picturify::{PicturifyCommand, PicturifyCpuCountArg}
 use crate::common::logging::log_help;
 use crate::common::threading::CpuCount;
 use crate::error::{CliPicturifyError, CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::compare::CompareCommandHandler;
 use crate::handlers::common::completions::CompletionsCommandHandler;
+use crate::handlers::common::generate::GenerateCommandHandler;
 use crate::handlers::common::handler::CommandHandler;
 use crate::handlers::common::image::ImageCommandHandler;
 use crate::handlers::common::movie::MovieCommandHandler;
@@ -33,6 +35,12 @@ impl CommandHandler for PicturifyCommandHandler {
             Some(("movie", args)) => {
                 MovieCommandHandler::handle(&MovieCommandHandler, args.clone())
             }
+            Some(("generate", args)) => {
+                GenerateCommandHandler::handle(&GenerateCommandHandler, args.clone())
+            }
+            Some(("compare", args)) => {
+                CompareCommandHandler::handle(&CompareCommandHandler, args.clone())
+            }
             Some(("completions", args)) => {
                 CompletionsCommandHandler::handle(&CompletionsCommandHandler, args.clone())
             }
@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+
+use picturify_pipeline::common::region_pipeline::RegionPipeline;
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::common::region::{Mask, Region};
+use picturify_processing::processors::geometry::crop::CropBorder;
+
+use crate::commands::common::args::common::{FeatherArg, MaskArg, PicturifyArg, RoiArg};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::image_io::read_image_from_file;
+
+pub fn read_region(args: &ArgMatches) -> CliPicturifyResult<Region> {
+    let roi = args.get_one::<CropBorder>(RoiArg::id()).copied();
+    let feather = args
+        .get_one::<usize>(FeatherArg::id())
+        .map_to_unknown_error()?;
+
+    let mask = match args.get_one::<PathBuf>(MaskArg::id()) {
+        Some(path) => {
+            let image = read_image_from_file(path)?;
+            Some(Mask::from_image(&image).feather(*feather))
+        }
+        None => None,
+    };
+
+    Ok(Region { roi, mask })
+}
+
+pub fn apply_region(pipeline: Box<dyn Pipeline>, region: &Region) -> Box<dyn Pipeline> {
+    match region.is_full() {
+        true => pipeline,
+        false => Box::new(RegionPipeline::new(region.clone(), pipeline)),
+    }
+}
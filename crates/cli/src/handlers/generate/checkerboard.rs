@@ -0,0 +1,38 @@
+use clap::ArgMatches;
+
+use picturify_core::image::Rgba;
+use picturify_processing::generators::checkerboard::{
+    CheckerboardGenerator, CheckerboardGeneratorOptions,
+};
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::generate::checkerboard::{
+    CheckerboardCellSizeArg, CheckerboardFirstArg, CheckerboardSecondArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::generate::run_generator;
+use crate::handlers::common::handler::CommandHandler;
+
+pub struct CheckerboardCommandHandler;
+
+impl CommandHandler for CheckerboardCommandHandler {
+    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
+        let cell_size = args
+            .get_one::<u32>(CheckerboardCellSizeArg::id())
+            .map_to_unknown_error()?;
+        let first = args
+            .get_one::<Rgba<u8>>(CheckerboardFirstArg::id())
+            .map_to_unknown_error()?;
+        let second = args
+            .get_one::<Rgba<u8>>(CheckerboardSecondArg::id())
+            .map_to_unknown_error()?;
+
+        let generator = CheckerboardGenerator::new(CheckerboardGeneratorOptions {
+            cell_size: *cell_size as usize,
+            first: *first,
+            second: *second,
+        });
+
+        run_generator(&args, Box::new(generator))
+    }
+}
@@ -0,0 +1,25 @@
+use clap::ArgMatches;
+
+use picturify_processing::generators::color_bars::{ColorBarsGenerator, ColorBarsGeneratorOptions};
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::generate::color_bars::ColorBarsIntensityArg;
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::generate::run_generator;
+use crate::handlers::common::handler::CommandHandler;
+
+pub struct ColorBarsCommandHandler;
+
+impl CommandHandler for ColorBarsCommandHandler {
+    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
+        let intensity = args
+            .get_one::<f32>(ColorBarsIntensityArg::id())
+            .map_to_unknown_error()?;
+
+        let generator = ColorBarsGenerator::new(ColorBarsGeneratorOptions {
+            intensity: *intensity,
+        });
+
+        run_generator(&args, Box::new(generator))
+    }
+}
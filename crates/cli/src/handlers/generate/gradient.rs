@@ -0,0 +1,43 @@
+use clap::ArgMatches;
+
+use picturify_core::geometry::angle::Angle;
+use picturify_core::image::Rgba;
+use picturify_processing::generators::gradient::{
+    GradientGenerator, GradientGeneratorOptions, GradientKind,
+};
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::generate::gradient::{
+    GradientAngleArg, GradientEndArg, GradientKindArg, GradientStartArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::generate::run_generator;
+use crate::handlers::common::handler::CommandHandler;
+
+pub struct GradientCommandHandler;
+
+impl CommandHandler for GradientCommandHandler {
+    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
+        let kind = args
+            .get_one::<GradientKind>(GradientKindArg::id())
+            .map_to_unknown_error()?;
+        let start = args
+            .get_one::<Rgba<u8>>(GradientStartArg::id())
+            .map_to_unknown_error()?;
+        let end = args
+            .get_one::<Rgba<u8>>(GradientEndArg::id())
+            .map_to_unknown_error()?;
+        let angle = args
+            .get_one::<Angle>(GradientAngleArg::id())
+            .map_to_unknown_error()?;
+
+        let generator = GradientGenerator::new(GradientGeneratorOptions {
+            kind: *kind,
+            start: *start,
+            end: *end,
+            angle: *angle,
+        });
+
+        run_generator(&args, Box::new(generator))
+    }
+}
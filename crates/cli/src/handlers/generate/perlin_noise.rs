@@ -0,0 +1,39 @@
+use clap::ArgMatches;
+
+use picturify_processing::generators::perlin_noise::{
+    PerlinNoiseGenerator, PerlinNoiseGeneratorOptions,
+};
+
+use crate::commands::common::args::common::{PicturifyArg, SeedArg};
+use crate::commands::generate::perlin_noise::{
+    PerlinNoiseOctavesArg, PerlinNoisePersistenceArg, PerlinNoiseScaleArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::generate::run_generator;
+use crate::handlers::common::handler::CommandHandler;
+
+pub struct PerlinNoiseCommandHandler;
+
+impl CommandHandler for PerlinNoiseCommandHandler {
+    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
+        let scale = args
+            .get_one::<f32>(PerlinNoiseScaleArg::id())
+            .map_to_unknown_error()?;
+        let octaves = args
+            .get_one::<u32>(PerlinNoiseOctavesArg::id())
+            .map_to_unknown_error()?;
+        let persistence = args
+            .get_one::<f32>(PerlinNoisePersistenceArg::id())
+            .map_to_unknown_error()?;
+        let seed = args.get_one::<u64>(SeedArg::id()).copied();
+
+        let generator = PerlinNoiseGenerator::new(PerlinNoiseGeneratorOptions {
+            scale: *scale,
+            octaves: *octaves as usize,
+            persistence: *persistence,
+            seed,
+        });
+
+        run_generator(&args, Box::new(generator))
+    }
+}
@@ -0,0 +1,25 @@
+use clap::ArgMatches;
+
+use picturify_processing::generators::zone_plate::{ZonePlateGenerator, ZonePlateGeneratorOptions};
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::generate::zone_plate::ZonePlateFrequencyArg;
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::generate::run_generator;
+use crate::handlers::common::handler::CommandHandler;
+
+pub struct ZonePlateCommandHandler;
+
+impl CommandHandler for ZonePlateCommandHandler {
+    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
+        let frequency = args
+            .get_one::<f32>(ZonePlateFrequencyArg::id())
+            .map_to_unknown_error()?;
+
+        let generator = ZonePlateGenerator::new(ZonePlateGeneratorOptions {
+            frequency: *frequency,
+        });
+
+        run_generator(&args, Box::new(generator))
+    }
+}
@@ -1,20 +1,21 @@
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
 use crate::commands::image::blob::laplacian_of_gaussian::{
-    LaplacianOfGaussianRadiusArg, LaplacianOfGaussianSigmaArg,
+    LaplacianOfGaussianCommand, LaplacianOfGaussianRadiusArg, LaplacianOfGaussianSigmaArg,
 };
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 use clap::ArgMatches;
 use picturify_pipeline::blob::laplacian_of_gaussian::{
     LaplacianOfGaussianPipeline, LaplacianOfGaussianPipelineOptions,
 };
+use picturify_pipeline::pipeline::Pipeline;
 
 pub struct LaplacianOfGaussianCommandHandler;
 
-impl CommandHandler for LaplacianOfGaussianCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for LaplacianOfGaussianCommandHandler {
+    type Command = LaplacianOfGaussianCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
         let radius = args
             .get_one::<usize>(LaplacianOfGaussianRadiusArg::id())
@@ -29,10 +30,6 @@ impl CommandHandler for LaplacianOfGaussianCommandHandler {
             sigma: *sigma,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
@@ -1,28 +1,25 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::color::brightness::{BrightnessPipeline, BrightnessPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 use crate::commands::common::args::common::PicturifyArg;
-use crate::commands::image::color::brightness::BrightnessFactorArg;
+use crate::commands::image::color::brightness::{BrightnessCommand, BrightnessFactorArg};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct BrightnessCommandHandler;
 
-impl CommandHandler for BrightnessCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for BrightnessCommandHandler {
+    type Command = BrightnessCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let factor = args
             .get_one::<f32>(BrightnessFactorArg::id())
             .map_to_unknown_error()?;
 
         let pipeline = BrightnessPipeline::new(BrightnessPipelineOptions { factor: *factor });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
@@ -0,0 +1,36 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::color::channel_extraction::{
+    ChannelExtractionPipeline, ChannelExtractionPipelineOptions,
+};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::common::color_space::ColorSpace;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::channel_extraction::{
+    ChannelExtractionChannelArg, ChannelExtractionCommand, ChannelExtractionSpaceArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct ChannelExtractionCommandHandler;
+
+impl PipelineCommandHandler for ChannelExtractionCommandHandler {
+    type Command = ChannelExtractionCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let space = args
+            .get_one::<ColorSpace>(ChannelExtractionSpaceArg::id())
+            .map_to_unknown_error()?;
+        let channel = args
+            .get_one::<u8>(ChannelExtractionChannelArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = ChannelExtractionPipeline::new(ChannelExtractionPipelineOptions {
+            space: *space,
+            channel: *channel as usize,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+
+use picturify_pipeline::color::channel_merge::{ChannelMergePipeline, ChannelMergePipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::common::color_space::ColorSpace;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::channel_merge::{
+    ChannelMergeCommand, ChannelMergeFirstArg, ChannelMergeSecondArg, ChannelMergeSpaceArg,
+    ChannelMergeThirdArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+use crate::handlers::common::image_io::read_image_from_file;
+
+pub struct ChannelMergeCommandHandler;
+
+impl PipelineCommandHandler for ChannelMergeCommandHandler {
+    type Command = ChannelMergeCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let space = args
+            .get_one::<ColorSpace>(ChannelMergeSpaceArg::id())
+            .map_to_unknown_error()?;
+
+        let read_channel = |id: &str| match args.get_one::<PathBuf>(id) {
+            Some(path) => read_image_from_file(path).map(Some),
+            None => Ok(None),
+        };
+        let channels = [
+            read_channel(ChannelMergeFirstArg::id())?,
+            read_channel(ChannelMergeSecondArg::id())?,
+            read_channel(ChannelMergeThirdArg::id())?,
+        ];
+
+        let pipeline = ChannelMergePipeline::new(ChannelMergePipelineOptions {
+            space: *space,
+            channels,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -0,0 +1,35 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::color::curves::{CurvesPipeline, CurvesPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::processors::color::curves::{Curve, Curves};
+
+use crate::commands::common::args::common::{FastArg, PicturifyArg};
+use crate::commands::image::color::curves::{
+    CurvesBlueArg, CurvesCommand, CurvesGreenArg, CurvesMasterArg, CurvesRedArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct CurvesCommandHandler;
+
+impl PipelineCommandHandler for CurvesCommandHandler {
+    type Command = CurvesCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let get_curve = |id: &str| args.get_one::<Curve>(id).cloned().map_to_unknown_error();
+        let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
+
+        let pipeline = CurvesPipeline::new(CurvesPipelineOptions {
+            curves: Curves {
+                master: get_curve(CurvesMasterArg::id())?,
+                red: get_curve(CurvesRedArg::id())?,
+                green: get_curve(CurvesGreenArg::id())?,
+                blue: get_curve(CurvesBlueArg::id())?,
+            },
+            fast: *fast,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
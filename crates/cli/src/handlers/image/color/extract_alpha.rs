@@ -0,0 +1,18 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::color::extract_alpha::ExtractAlphaPipeline;
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::image::color::extract_alpha::ExtractAlphaCommand;
+use crate::error::CliPicturifyResult;
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct ExtractAlphaCommandHandler;
+
+impl PipelineCommandHandler for ExtractAlphaCommandHandler {
+    type Command = ExtractAlphaCommand;
+
+    fn create_pipeline(&self, _args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        Ok(Box::new(ExtractAlphaPipeline))
+    }
+}
@@ -0,0 +1,28 @@
+use clap::ArgMatches;
+
+use picturify_core::image::Rgba;
+use picturify_pipeline::color::flatten::{FlattenPipeline, FlattenPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::flatten::{FlattenBackgroundArg, FlattenCommand};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct FlattenCommandHandler;
+
+impl PipelineCommandHandler for FlattenCommandHandler {
+    type Command = FlattenCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let background = args
+            .get_one::<Rgba<u8>>(FlattenBackgroundArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = FlattenPipeline::new(FlattenPipelineOptions {
+            background: *background,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
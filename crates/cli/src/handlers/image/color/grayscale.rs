@@ -1,19 +1,20 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::color::grayscale::{GrayscalePipeline, GrayscalePipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 use picturify_processing::processors::color::grayscale::GrayscaleStrategy;
 
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
-use crate::commands::image::color::grayscale::GrayscaleStrategyArg;
+use crate::commands::image::color::grayscale::{GrayscaleCommand, GrayscaleStrategyArg};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct GrayscaleCommandHandler;
 
-impl CommandHandler for GrayscaleCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for GrayscaleCommandHandler {
+    type Command = GrayscaleCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let strategy = args
             .get_one::<GrayscaleStrategy>(GrayscaleStrategyArg::id())
             .map_to_unknown_error()?;
@@ -23,10 +24,6 @@ impl CommandHandler for GrayscaleCommandHandler {
             fast: *fast,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
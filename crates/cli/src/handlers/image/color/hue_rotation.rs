@@ -0,0 +1,26 @@
+use clap::ArgMatches;
+
+use picturify_core::geometry::angle::Angle;
+use picturify_pipeline::color::hue_rotation::{HueRotationPipeline, HueRotationPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::hue_rotation::{HueRotationAngleArg, HueRotationCommand};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct HueRotationCommandHandler;
+
+impl PipelineCommandHandler for HueRotationCommandHandler {
+    type Command = HueRotationCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let angle = args
+            .get_one::<Angle>(HueRotationAngleArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = HueRotationPipeline::new(HueRotationPipelineOptions { angle: *angle });
+
+        Ok(Box::new(pipeline))
+    }
+}
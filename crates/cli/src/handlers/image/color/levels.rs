@@ -0,0 +1,44 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::color::levels::{LevelsPipeline, LevelsPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::processors::color::levels::Levels;
+
+use crate::commands::common::args::common::{FastArg, PicturifyArg};
+use crate::commands::image::color::levels::{
+    LevelsCommand, LevelsGammaArg, LevelsInputBlackArg, LevelsInputWhiteArg, LevelsOutputBlackArg,
+    LevelsOutputWhiteArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct LevelsCommandHandler;
+
+impl PipelineCommandHandler for LevelsCommandHandler {
+    type Command = LevelsCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let get_value = |id: &str| {
+            args.get_one::<u8>(id)
+                .map(|value| *value as f32 / 255.0)
+                .map_to_unknown_error()
+        };
+        let gamma = args
+            .get_one::<f32>(LevelsGammaArg::id())
+            .map_to_unknown_error()?;
+        let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
+
+        let pipeline = LevelsPipeline::new(LevelsPipelineOptions {
+            levels: Levels {
+                input_black: get_value(LevelsInputBlackArg::id())?,
+                input_white: get_value(LevelsInputWhiteArg::id())?,
+                gamma: *gamma,
+                output_black: get_value(LevelsOutputBlackArg::id())?,
+                output_white: get_value(LevelsOutputWhiteArg::id())?,
+            },
+            fast: *fast,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -0,0 +1,34 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::color::lightness::{LightnessPipeline, LightnessPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::processors::color::lightness::LightnessSpace;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::lightness::{
+    LightnessCommand, LightnessFactorArg, LightnessSpaceArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct LightnessCommandHandler;
+
+impl PipelineCommandHandler for LightnessCommandHandler {
+    type Command = LightnessCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let factor = args
+            .get_one::<f32>(LightnessFactorArg::id())
+            .map_to_unknown_error()?;
+        let space = args
+            .get_one::<LightnessSpace>(LightnessSpaceArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = LightnessPipeline::new(LightnessPipelineOptions {
+            factor: *factor,
+            space: *space,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
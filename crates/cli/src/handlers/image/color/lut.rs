@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+
+use picturify_core::core::io::ReadFromFile;
+use picturify_pipeline::color::lut::{LutPipeline, LutPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::common::lut3d::{Lut3d, Lut3dInterpolation};
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::lut::{LutCommand, LutFileArg, LutInterpolationArg};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub fn create_lut_pipeline(args: &ArgMatches) -> CliPicturifyResult<LutPipeline> {
+    let file = args
+        .get_one::<PathBuf>(LutFileArg::id())
+        .map_to_unknown_error()?;
+    let interpolation = args
+        .get_one::<Lut3dInterpolation>(LutInterpolationArg::id())
+        .map_to_unknown_error()?;
+
+    Ok(LutPipeline::new(LutPipelineOptions {
+        lut: Lut3d::read_from_file(file)?,
+        interpolation: *interpolation,
+    }))
+}
+
+pub struct LutCommandHandler;
+
+impl PipelineCommandHandler for LutCommandHandler {
+    type Command = LutCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        Ok(Box::new(create_lut_pipeline(args)?))
+    }
+}
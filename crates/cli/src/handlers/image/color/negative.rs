@@ -1,25 +1,23 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::color::negative::{NegativePipeline, NegativePipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
+use crate::commands::image::color::negative::NegativeCommand;
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct NegativeCommandHandler;
 
-impl CommandHandler for NegativeCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for NegativeCommandHandler {
+    type Command = NegativeCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
 
         let pipeline = NegativePipeline::new(NegativePipelineOptions { fast: *fast });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
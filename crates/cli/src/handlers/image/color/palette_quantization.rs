@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+
+use picturify_core::core::color_palette::ColorPalette;
+use picturify_core::core::io::ReadFromFile;
+use picturify_pipeline::color::palette_quantization::{
+    PaletteQuantizationPipeline, PaletteQuantizationPipelineOptions,
+};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::common::palette_generation::PaletteMethod;
+use picturify_processing::processors::color::palette_quantization::Dithering;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::palette_quantization::{
+    DitheringArg, ExportPaletteArg, PaletteColorsArg, PaletteFileArg, PaletteMethodArg,
+    PaletteQuantizationCommand,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct PaletteQuantizationCommandHandler;
+
+impl PipelineCommandHandler for PaletteQuantizationCommandHandler {
+    type Command = PaletteQuantizationCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let colors = args
+            .get_one::<u16>(PaletteColorsArg::id())
+            .map_to_unknown_error()?;
+        let method = args
+            .get_one::<PaletteMethod>(PaletteMethodArg::id())
+            .map_to_unknown_error()?;
+        let dithering = args
+            .get_one::<Dithering>(DitheringArg::id())
+            .map_to_unknown_error()?;
+        let palette = args
+            .get_one::<PathBuf>(PaletteFileArg::id())
+            .map(ColorPalette::read_from_file)
+            .transpose()?;
+        let export_palette = args.get_one::<PathBuf>(ExportPaletteArg::id());
+
+        let pipeline = PaletteQuantizationPipeline::new(PaletteQuantizationPipelineOptions {
+            colors: *colors as usize,
+            method: *method,
+            palette,
+            dithering: *dithering,
+            export_palette: export_palette.cloned(),
+        });
+
+        Ok(Box::new(pipeline))
+    }
+
+    fn single_image_args(&self) -> Vec<&'static str> {
+        vec![ExportPaletteArg::id()]
+    }
+}
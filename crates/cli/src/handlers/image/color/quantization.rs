@@ -1,18 +1,19 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::color::quantization::{QuantizationPipeline, QuantizationPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
-use crate::commands::image::color::quantization::QuantizationLevelsArg;
+use crate::commands::image::color::quantization::{QuantizationCommand, QuantizationLevelsArg};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct QuantizationCommandHandler;
 
-impl CommandHandler for QuantizationCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for QuantizationCommandHandler {
+    type Command = QuantizationCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
         let levels = args
             .get_one::<u8>(QuantizationLevelsArg::id())
@@ -23,10 +24,6 @@ impl CommandHandler for QuantizationCommandHandler {
             levels: *levels,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
@@ -0,0 +1,33 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::color::saturation::{SaturationPipeline, SaturationPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::saturation::{
+    SaturationCommand, SaturationFactorArg, SaturationVibranceArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct SaturationCommandHandler;
+
+impl PipelineCommandHandler for SaturationCommandHandler {
+    type Command = SaturationCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let factor = args
+            .get_one::<f32>(SaturationFactorArg::id())
+            .map_to_unknown_error()?;
+        let vibrance = args
+            .get_one::<f32>(SaturationVibranceArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = SaturationPipeline::new(SaturationPipelineOptions {
+            factor: *factor,
+            vibrance: *vibrance,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -1,17 +1,19 @@
 #[cfg(feature = "gpu")]
 use crate::commands::common::args::common::GpuArg;
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
+use crate::commands::image::color::sepia::SepiaCommand;
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 use clap::ArgMatches;
 use picturify_pipeline::color::sepia::{SepiaPipeline, SepiaPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 pub struct SepiaCommandHandler;
 
-impl CommandHandler for SepiaCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for SepiaCommandHandler {
+    type Command = SepiaCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
 
         #[cfg(feature = "gpu")]
@@ -23,10 +25,6 @@ impl CommandHandler for SepiaCommandHandler {
             use_gpu: *gpu,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
@@ -0,0 +1,30 @@
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+
+use picturify_pipeline::color::set_alpha::{SetAlphaPipeline, SetAlphaPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::common::region::Mask;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::set_alpha::{SetAlphaCommand, SetAlphaImageArg};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+use crate::handlers::common::image_io::read_image_from_file;
+
+pub struct SetAlphaCommandHandler;
+
+impl PipelineCommandHandler for SetAlphaCommandHandler {
+    type Command = SetAlphaCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let path = args
+            .get_one::<PathBuf>(SetAlphaImageArg::id())
+            .map_to_unknown_error()?;
+        let alpha = Mask::from_image(&read_image_from_file(path)?);
+
+        let pipeline = SetAlphaPipeline::new(SetAlphaPipelineOptions { alpha });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -0,0 +1,33 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::color::temperature::{TemperaturePipeline, TemperaturePipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::temperature::{
+    TemperatureCommand, TemperatureKelvinArg, TemperatureTintArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct TemperatureCommandHandler;
+
+impl PipelineCommandHandler for TemperatureCommandHandler {
+    type Command = TemperatureCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let temperature = args
+            .get_one::<f32>(TemperatureKelvinArg::id())
+            .map_to_unknown_error()?;
+        let tint = args
+            .get_one::<f32>(TemperatureTintArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = TemperaturePipeline::new(TemperaturePipelineOptions {
+            temperature: *temperature,
+            tint: *tint,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
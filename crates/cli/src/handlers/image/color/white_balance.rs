@@ -0,0 +1,33 @@
+use clap::ArgMatches;
+
+use picturify_core::geometry::coord::Coord;
+use picturify_pipeline::color::white_balance::{WhiteBalancePipeline, WhiteBalancePipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::processors::color::white_balance::WhiteBalanceMethod;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::color::white_balance::{
+    WhiteBalanceCommand, WhiteBalanceMethodArg, WhiteBalancePointArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct WhiteBalanceCommandHandler;
+
+impl PipelineCommandHandler for WhiteBalanceCommandHandler {
+    type Command = WhiteBalanceCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let method = args
+            .get_one::<WhiteBalanceMethod>(WhiteBalanceMethodArg::id())
+            .map_to_unknown_error()?;
+        let point = args.get_one::<Coord>(WhiteBalancePointArg::id());
+
+        let pipeline = WhiteBalancePipeline::new(WhiteBalancePipelineOptions {
+            method: *method,
+            point: point.copied(),
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
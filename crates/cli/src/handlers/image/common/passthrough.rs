@@ -1,17 +1,18 @@
 use clap::ArgMatches;
 
+use picturify_pipeline::common::passthrough_pipeline::PassthroughPipeline;
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::image::common::passthrough::PassthroughCommand;
 use crate::error::CliPicturifyResult;
-use crate::handlers::common::handler::CommandHandler;
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct PassthroughCommandHandler;
 
-impl CommandHandler for PassthroughCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
-
-        write_image(image, args.clone())?;
+impl PipelineCommandHandler for PassthroughCommandHandler {
+    type Command = PassthroughCommand;
 
-        Ok(())
+    fn create_pipeline(&self, _args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        Ok(Box::new(PassthroughPipeline))
     }
 }
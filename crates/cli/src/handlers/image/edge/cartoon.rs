@@ -0,0 +1,58 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::edge::cartoon::{
+    CartoonPipeline, CartoonPipelineOptions, CartoonSmoothing,
+};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::edge::cartoon::{
+    CartoonCommand, CartoonLevelsArg, CartoonRadiusArg, CartoonSmoothingArg,
+};
+use crate::commands::image::edge::xdog::{
+    XDogEpsilonArg, XDogPhiArg, XDogSharpnessArg, XDogSigmaArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct CartoonCommandHandler;
+
+impl PipelineCommandHandler for CartoonCommandHandler {
+    type Command = CartoonCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let smoothing = args
+            .get_one::<CartoonSmoothing>(CartoonSmoothingArg::id())
+            .map_to_unknown_error()?;
+        let radius = args
+            .get_one::<usize>(CartoonRadiusArg::id())
+            .map_to_unknown_error()?;
+        let levels = args
+            .get_one::<u8>(CartoonLevelsArg::id())
+            .map_to_unknown_error()?;
+        let sigma = args
+            .get_one::<f32>(XDogSigmaArg::id())
+            .map_to_unknown_error()?;
+        let sharpness = args
+            .get_one::<f32>(XDogSharpnessArg::id())
+            .map_to_unknown_error()?;
+        let epsilon = args
+            .get_one::<f32>(XDogEpsilonArg::id())
+            .map_to_unknown_error()?;
+        let phi = args
+            .get_one::<f32>(XDogPhiArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = CartoonPipeline::new(CartoonPipelineOptions {
+            smoothing: *smoothing,
+            radius: *radius,
+            levels: *levels,
+            sigma: *sigma,
+            sharpness: *sharpness,
+            epsilon: *epsilon,
+            phi: *phi,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -0,0 +1,40 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::edge::difference_of_gaussians::{
+    DifferenceOfGaussiansPipeline, DifferenceOfGaussiansPipelineOptions,
+};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::edge::difference_of_gaussians::{
+    DifferenceOfGaussiansCommand, DifferenceOfGaussiansGainArg, DifferenceOfGaussiansKArg,
+    DifferenceOfGaussiansSigmaArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct DifferenceOfGaussiansCommandHandler;
+
+impl PipelineCommandHandler for DifferenceOfGaussiansCommandHandler {
+    type Command = DifferenceOfGaussiansCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let sigma = args
+            .get_one::<f32>(DifferenceOfGaussiansSigmaArg::id())
+            .map_to_unknown_error()?;
+        let k = args
+            .get_one::<f32>(DifferenceOfGaussiansKArg::id())
+            .map_to_unknown_error()?;
+        let gain = args
+            .get_one::<f32>(DifferenceOfGaussiansGainArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = DifferenceOfGaussiansPipeline::new(DifferenceOfGaussiansPipelineOptions {
+            sigma: *sigma,
+            k: *k,
+            gain: *gain,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
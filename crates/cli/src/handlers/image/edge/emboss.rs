@@ -1,23 +1,21 @@
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
+use crate::commands::image::edge::emboss::EmbossCommand;
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 use clap::ArgMatches;
 use picturify_pipeline::edge::emboss::{EmbossPipeline, EmbossPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 pub struct EmbossCommandHandler;
 
-impl CommandHandler for EmbossCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for EmbossCommandHandler {
+    type Command = EmbossCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
 
         let pipeline = EmbossPipeline::new(EmbossPipelineOptions { fast: *fast });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
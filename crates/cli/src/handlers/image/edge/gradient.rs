@@ -0,0 +1,40 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::edge::gradient::{GradientPipeline, GradientPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::processors::edge::gradient::{GradientMode, GradientOperator};
+
+use crate::commands::common::args::common::{FastArg, PicturifyArg};
+use crate::commands::image::edge::gradient::{
+    GradientCommand, GradientGainArg, GradientModeArg, GradientOperatorArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct GradientCommandHandler;
+
+impl PipelineCommandHandler for GradientCommandHandler {
+    type Command = GradientCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
+        let operator = args
+            .get_one::<GradientOperator>(GradientOperatorArg::id())
+            .map_to_unknown_error()?;
+        let mode = args
+            .get_one::<GradientMode>(GradientModeArg::id())
+            .map_to_unknown_error()?;
+        let gain = args
+            .get_one::<f32>(GradientGainArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = GradientPipeline::new(GradientPipelineOptions {
+            operator: *operator,
+            mode: *mode,
+            gain: *gain,
+            fast: *fast,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -1,15 +1,16 @@
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
-use crate::commands::image::edge::prewitt::PrewittRgbArg;
+use crate::commands::image::edge::prewitt::{PrewittCommand, PrewittRgbArg};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 use picturify_pipeline::edge::prewitt::{PrewittPipeline, PrewittPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 pub struct PrewittCommandHandler;
 
-impl CommandHandler for PrewittCommandHandler {
-    fn handle(&self, args: clap::ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for PrewittCommandHandler {
+    type Command = PrewittCommand;
+
+    fn create_pipeline(&self, args: &clap::ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
         let rgb = args
             .get_one::<bool>(PrewittRgbArg::id())
@@ -20,10 +21,6 @@ impl CommandHandler for PrewittCommandHandler {
             rgb: *rgb,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
@@ -1,18 +1,19 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::edge::sobel::{SobelPipeline, SobelPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
-use crate::commands::image::edge::sobel::SobelRgbArg;
+use crate::commands::image::edge::sobel::{SobelCommand, SobelRgbArg};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct SobelCommandHandler;
 
-impl CommandHandler for SobelCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for SobelCommandHandler {
+    type Command = SobelCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
         let rgb = args
             .get_one::<bool>(SobelRgbArg::id())
@@ -23,10 +24,6 @@ impl CommandHandler for SobelCommandHandler {
             rgb: *rgb,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
@@ -0,0 +1,43 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::edge::xdog::{XDogPipeline, XDogPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::edge::xdog::{
+    XDogCommand, XDogEpsilonArg, XDogKArg, XDogPhiArg, XDogSharpnessArg, XDogSigmaArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct XDogCommandHandler;
+
+impl PipelineCommandHandler for XDogCommandHandler {
+    type Command = XDogCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let sigma = args
+            .get_one::<f32>(XDogSigmaArg::id())
+            .map_to_unknown_error()?;
+        let k = args.get_one::<f32>(XDogKArg::id()).map_to_unknown_error()?;
+        let sharpness = args
+            .get_one::<f32>(XDogSharpnessArg::id())
+            .map_to_unknown_error()?;
+        let epsilon = args
+            .get_one::<f32>(XDogEpsilonArg::id())
+            .map_to_unknown_error()?;
+        let phi = args
+            .get_one::<f32>(XDogPhiArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = XDogPipeline::new(XDogPipelineOptions {
+            sigma: *sigma,
+            k: *k,
+            sharpness: *sharpness,
+            epsilon: *epsilon,
+            phi: *phi,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
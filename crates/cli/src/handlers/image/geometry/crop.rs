@@ -1,19 +1,20 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::geometry::crop::{CropPipeline, CropPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 use picturify_processing::processors::geometry::crop::CropBorder;
 
 use crate::commands::common::args::common::PicturifyArg;
-use crate::commands::image::geometry::crop::CropBorderArg;
+use crate::commands::image::geometry::crop::{CropBorderArg, CropCommand};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct CropCommandHandler;
 
-impl CommandHandler for CropCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for CropCommandHandler {
+    type Command = CropCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let crop_border = args
             .get_one::<CropBorder>(CropBorderArg::id())
             .map_to_unknown_error()?;
@@ -22,10 +23,6 @@ impl CommandHandler for CropCommandHandler {
             crop_border: *crop_border,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
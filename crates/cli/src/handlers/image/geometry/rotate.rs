@@ -2,28 +2,25 @@ use clap::ArgMatches;
 
 use picturify_core::geometry::angle::Angle;
 use picturify_pipeline::geometry::rotate::{RotatePipeline, RotatePipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 use crate::commands::common::args::common::PicturifyArg;
-use crate::commands::image::geometry::rotate::RotateAngleArg;
+use crate::commands::image::geometry::rotate::{RotateAngleArg, RotateCommand};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct RotateCommandHandler;
 
-impl CommandHandler for RotateCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for RotateCommandHandler {
+    type Command = RotateCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let angle = args
             .get_one::<Angle>(RotateAngleArg::id())
             .map_to_unknown_error()?;
 
         let pipeline = RotatePipeline::new(RotatePipelineOptions { angle: *angle });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
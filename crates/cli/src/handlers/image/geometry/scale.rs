@@ -1,18 +1,19 @@
 use crate::commands::common::args::common::PicturifyArg;
-use crate::commands::image::geometry::scale::{ScaleSizeArg, ScaleStrategyArg};
+use crate::commands::image::geometry::scale::{ScaleCommand, ScaleSizeArg, ScaleStrategyArg};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 use clap::ArgMatches;
 use picturify_core::geometry::size::Size;
 use picturify_pipeline::geometry::scale::{ScalePipeline, ScalePipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 use picturify_processing::processors::geometry::scale::ScaleStrategy;
 
 pub struct ScaleCommandHandler;
 
-impl CommandHandler for ScaleCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for ScaleCommandHandler {
+    type Command = ScaleCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let size = args
             .get_one::<Size>(ScaleSizeArg::id())
             .map_to_unknown_error()?;
@@ -25,10 +26,6 @@ impl CommandHandler for ScaleCommandHandler {
             strategy: *strategy,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
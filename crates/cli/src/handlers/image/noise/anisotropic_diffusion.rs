@@ -0,0 +1,45 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::noise::anisotropic_diffusion::{
+    AnisotropicDiffusionPipeline, AnisotropicDiffusionPipelineOptions,
+};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::processors::noise::anisotropic_diffusion::ConductionFunction;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::noise::anisotropic_diffusion::{
+    AnisotropicDiffusionCommand, AnisotropicDiffusionFunctionArg,
+    AnisotropicDiffusionIterationsArg, AnisotropicDiffusionKappaArg, AnisotropicDiffusionLambdaArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct AnisotropicDiffusionCommandHandler;
+
+impl PipelineCommandHandler for AnisotropicDiffusionCommandHandler {
+    type Command = AnisotropicDiffusionCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let iterations = args
+            .get_one::<usize>(AnisotropicDiffusionIterationsArg::id())
+            .map_to_unknown_error()?;
+        let kappa = args
+            .get_one::<f32>(AnisotropicDiffusionKappaArg::id())
+            .map_to_unknown_error()?;
+        let lambda = args
+            .get_one::<f32>(AnisotropicDiffusionLambdaArg::id())
+            .map_to_unknown_error()?;
+        let function = args
+            .get_one::<ConductionFunction>(AnisotropicDiffusionFunctionArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = AnisotropicDiffusionPipeline::new(AnisotropicDiffusionPipelineOptions {
+            iterations: *iterations,
+            kappa: *kappa,
+            lambda: *lambda,
+            function: *function,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -3,20 +3,22 @@ use clap::ArgMatches;
 use picturify_pipeline::noise::bilateral_blur::{
     BilateralBlurPipeline, BilateralBlurPipelineOptions,
 };
+use picturify_pipeline::pipeline::Pipeline;
 
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
 use crate::commands::image::noise::bilateral_blur::{
-    BilateralBlurIntensitySigmaArg, BilateralBlurRadiusArg, BilateralBlurSpatialSigmaArg,
+    BilateralBlurCommand, BilateralBlurIntensitySigmaArg, BilateralBlurRadiusArg,
+    BilateralBlurSpatialSigmaArg,
 };
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct BilateralBlurCommandHandler;
 
-impl CommandHandler for BilateralBlurCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for BilateralBlurCommandHandler {
+    type Command = BilateralBlurCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
         let radius = args
             .get_one::<usize>(BilateralBlurRadiusArg::id())
@@ -35,10 +37,6 @@ impl CommandHandler for BilateralBlurCommandHandler {
             sigma_intensity: *intensity_sigma,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
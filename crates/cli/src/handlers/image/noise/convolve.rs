@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+
+use picturify_core::core::io::ReadFromFile;
+use picturify_core::geometry::coord::Coord;
+use picturify_pipeline::noise::convolve::{ConvolvePipeline, ConvolvePipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::common::kernels::convolution::ConvolutionKernel;
+use picturify_processing::processors::noise::convolve::ConvolveChannels;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::noise::convolve::{
+    ConvolveAnchorArg, ConvolveBiasArg, ConvolveChannelsArg, ConvolveCommand, ConvolveDivisorArg,
+    ConvolveFileArg, ConvolveKernelArg, ConvolveNormalizeArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct ConvolveCommandHandler;
+
+impl PipelineCommandHandler for ConvolveCommandHandler {
+    type Command = ConvolveCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let kernel = match args.get_one::<PathBuf>(ConvolveFileArg::id()) {
+            Some(file) => ConvolutionKernel::read_from_file(file)?,
+            None => args
+                .get_one::<ConvolutionKernel>(ConvolveKernelArg::id())
+                .map_to_unknown_error()?
+                .clone(),
+        };
+        let normalize = args.get_flag(ConvolveNormalizeArg::id());
+        let divisor = args
+            .get_one::<f32>(ConvolveDivisorArg::id())
+            .map_to_unknown_error()?;
+        let bias = args
+            .get_one::<f32>(ConvolveBiasArg::id())
+            .map_to_unknown_error()?;
+        let anchor = args.get_one::<Coord>(ConvolveAnchorArg::id());
+        let channels = args
+            .get_one::<ConvolveChannels>(ConvolveChannelsArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = ConvolvePipeline::new(ConvolvePipelineOptions {
+            kernel,
+            normalize,
+            divisor: *divisor,
+            bias: *bias,
+            anchor: anchor.copied(),
+            channels: *channels,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
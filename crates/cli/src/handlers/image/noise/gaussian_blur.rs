@@ -1,18 +1,21 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::noise::gaussian_blur::{GaussianBlurPipeline, GaussianBlurPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
-use crate::commands::image::noise::gaussian_blur::{GaussianBlurRadiusArg, GaussianBlurSigmaArg};
+use crate::commands::image::noise::gaussian_blur::{
+    GaussianBlurCommand, GaussianBlurRadiusArg, GaussianBlurSigmaArg,
+};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct GaussianBlurCommandHandler;
 
-impl CommandHandler for GaussianBlurCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for GaussianBlurCommandHandler {
+    type Command = GaussianBlurCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
         let radius = args
             .get_one::<usize>(GaussianBlurRadiusArg::id())
@@ -27,10 +30,6 @@ impl CommandHandler for GaussianBlurCommandHandler {
             sigma: *sigma,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
@@ -0,0 +1,31 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::noise::gaussian_noise::{
+    GaussianNoisePipeline, GaussianNoisePipelineOptions,
+};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::{PicturifyArg, SeedArg};
+use crate::commands::image::noise::gaussian_noise::{GaussianNoiseCommand, GaussianNoiseSigmaArg};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct GaussianNoiseCommandHandler;
+
+impl PipelineCommandHandler for GaussianNoiseCommandHandler {
+    type Command = GaussianNoiseCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let sigma = args
+            .get_one::<f32>(GaussianNoiseSigmaArg::id())
+            .map_to_unknown_error()?;
+        let seed = args.get_one::<u64>(SeedArg::id()).copied();
+
+        let pipeline = GaussianNoisePipeline::new(GaussianNoisePipelineOptions {
+            sigma: *sigma,
+            seed,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
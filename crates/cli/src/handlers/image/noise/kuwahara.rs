@@ -1,32 +1,44 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::noise::kuwahara::{KuwaharaPipeline, KuwaharaPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::processors::noise::kuwahara::KuwaharaMethod;
 
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
-use crate::commands::image::noise::kuwahara::KuwaharaRadiusArg;
+use crate::commands::image::noise::kuwahara::{
+    KuwaharaCommand, KuwaharaMethodArg, KuwaharaRadiusArg, KuwaharaSectorsArg, KuwaharaSharpnessArg,
+};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct KuwaharaCommandHandler;
 
-impl CommandHandler for KuwaharaCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for KuwaharaCommandHandler {
+    type Command = KuwaharaCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
         let radius = args
             .get_one::<usize>(KuwaharaRadiusArg::id())
             .map_to_unknown_error()?;
+        let method = args
+            .get_one::<KuwaharaMethod>(KuwaharaMethodArg::id())
+            .map_to_unknown_error()?;
+        let sectors = args
+            .get_one::<u32>(KuwaharaSectorsArg::id())
+            .map_to_unknown_error()?;
+        let sharpness = args
+            .get_one::<f32>(KuwaharaSharpnessArg::id())
+            .map_to_unknown_error()?;
 
         let pipeline = KuwaharaPipeline::new(KuwaharaPipelineOptions {
             fast: *fast,
+            method: *method,
             radius: *radius,
+            sectors: *sectors as usize,
+            sharpness: *sharpness,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
@@ -1,18 +1,19 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::noise::mean_blur::{MeanBlurPipeline, MeanBlurPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
-use crate::commands::image::noise::mean_blur::MeanBlurRadiusArg;
+use crate::commands::image::noise::mean_blur::{MeanBlurCommand, MeanBlurRadiusArg};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct MeanBlurCommandHandler;
 
-impl CommandHandler for MeanBlurCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for MeanBlurCommandHandler {
+    type Command = MeanBlurCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let radius = args
             .get_one::<usize>(MeanBlurRadiusArg::id())
             .map_to_unknown_error()?;
@@ -22,10 +23,6 @@ impl CommandHandler for MeanBlurCommandHandler {
             fast: *fast,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
@@ -1,18 +1,19 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::noise::median_blur::{MedianBlurPipeline, MedianBlurPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
 
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
-use crate::commands::image::noise::median_blur::MedianBlurRadiusArg;
+use crate::commands::image::noise::median_blur::{MedianBlurCommand, MedianBlurRadiusArg};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct MedianBlurCommandHandler;
 
-impl CommandHandler for MedianBlurCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
+impl PipelineCommandHandler for MedianBlurCommandHandler {
+    type Command = MedianBlurCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
         let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
         let radius = args
             .get_one::<usize>(MedianBlurRadiusArg::id())
@@ -23,10 +24,6 @@ impl CommandHandler for MedianBlurCommandHandler {
             radius: *radius,
         });
 
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
-
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+        Ok(Box::new(pipeline))
     }
 }
@@ -0,0 +1,34 @@
+use clap::ArgMatches;
+
+use picturify_core::geometry::angle::Angle;
+use picturify_pipeline::noise::motion_blur::{MotionBlurPipeline, MotionBlurPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::noise::motion_blur::{
+    MotionBlurAngleArg, MotionBlurCommand, MotionBlurLengthArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct MotionBlurCommandHandler;
+
+impl PipelineCommandHandler for MotionBlurCommandHandler {
+    type Command = MotionBlurCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let angle = args
+            .get_one::<Angle>(MotionBlurAngleArg::id())
+            .map_to_unknown_error()?;
+        let length = args
+            .get_one::<f32>(MotionBlurLengthArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = MotionBlurPipeline::new(MotionBlurPipelineOptions {
+            angle: *angle,
+            length: *length,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -0,0 +1,40 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::noise::non_local_means::{
+    NonLocalMeansPipeline, NonLocalMeansPipelineOptions,
+};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::noise::non_local_means::{
+    NonLocalMeansCommand, NonLocalMeansPatchRadiusArg, NonLocalMeansSearchRadiusArg,
+    NonLocalMeansStrengthArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct NonLocalMeansCommandHandler;
+
+impl PipelineCommandHandler for NonLocalMeansCommandHandler {
+    type Command = NonLocalMeansCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let search_radius = args
+            .get_one::<usize>(NonLocalMeansSearchRadiusArg::id())
+            .map_to_unknown_error()?;
+        let patch_radius = args
+            .get_one::<usize>(NonLocalMeansPatchRadiusArg::id())
+            .map_to_unknown_error()?;
+        let strength = args
+            .get_one::<f32>(NonLocalMeansStrengthArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = NonLocalMeansPipeline::new(NonLocalMeansPipelineOptions {
+            search_radius: *search_radius,
+            patch_radius: *patch_radius,
+            strength: *strength,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
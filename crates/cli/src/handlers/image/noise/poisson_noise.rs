@@ -0,0 +1,29 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::noise::poisson_noise::{PoissonNoisePipeline, PoissonNoisePipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::{PicturifyArg, SeedArg};
+use crate::commands::image::noise::poisson_noise::{PoissonNoiseCommand, PoissonNoiseScaleArg};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct PoissonNoiseCommandHandler;
+
+impl PipelineCommandHandler for PoissonNoiseCommandHandler {
+    type Command = PoissonNoiseCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let scale = args
+            .get_one::<f32>(PoissonNoiseScaleArg::id())
+            .map_to_unknown_error()?;
+        let seed = args.get_one::<u64>(SeedArg::id()).copied();
+
+        let pipeline = PoissonNoisePipeline::new(PoissonNoisePipelineOptions {
+            scale: *scale,
+            seed,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -0,0 +1,33 @@
+use clap::ArgMatches;
+
+use picturify_core::geometry::angle::Angle;
+use picturify_core::geometry::coord::Coord;
+use picturify_pipeline::noise::radial_blur::{RadialBlurPipeline, RadialBlurPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::noise::radial_blur::{
+    RadialBlurAngleArg, RadialBlurCenterArg, RadialBlurCommand,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct RadialBlurCommandHandler;
+
+impl PipelineCommandHandler for RadialBlurCommandHandler {
+    type Command = RadialBlurCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let center = args.get_one::<Coord>(RadialBlurCenterArg::id());
+        let angle = args
+            .get_one::<Angle>(RadialBlurAngleArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = RadialBlurPipeline::new(RadialBlurPipelineOptions {
+            center: center.copied(),
+            angle: *angle,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -0,0 +1,37 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::noise::salt_and_pepper_noise::{
+    SaltAndPepperNoisePipeline, SaltAndPepperNoisePipelineOptions,
+};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::{PicturifyArg, SeedArg};
+use crate::commands::image::noise::salt_and_pepper_noise::{
+    SaltAndPepperNoiseAmountArg, SaltAndPepperNoiseCommand, SaltAndPepperNoiseSaltRatioArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct SaltAndPepperNoiseCommandHandler;
+
+impl PipelineCommandHandler for SaltAndPepperNoiseCommandHandler {
+    type Command = SaltAndPepperNoiseCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let amount = args
+            .get_one::<f32>(SaltAndPepperNoiseAmountArg::id())
+            .map_to_unknown_error()?;
+        let salt_ratio = args
+            .get_one::<f32>(SaltAndPepperNoiseSaltRatioArg::id())
+            .map_to_unknown_error()?;
+        let seed = args.get_one::<u64>(SeedArg::id()).copied();
+
+        let pipeline = SaltAndPepperNoisePipeline::new(SaltAndPepperNoisePipelineOptions {
+            amount: *amount,
+            salt_ratio: *salt_ratio,
+            seed,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
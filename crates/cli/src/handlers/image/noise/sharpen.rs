@@ -1,25 +1,51 @@
 use clap::ArgMatches;
 
 use picturify_pipeline::noise::sharpen::{SharpenPipeline, SharpenPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+use picturify_processing::processors::noise::sharpen::SharpenMethod;
 
 use crate::commands::common::args::common::{FastArg, PicturifyArg};
+use crate::commands::image::noise::sharpen::{
+    SharpenAmountArg, SharpenCommand, SharpenLuminanceOnlyArg, SharpenMethodArg, SharpenRadiusArg,
+    SharpenSigmaArg, SharpenThresholdArg,
+};
 use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
-use crate::handlers::common::handler::{run_pipeline, CommandHandler};
-use crate::handlers::common::image_io::{read_image, write_image};
+use crate::handlers::common::handler::PipelineCommandHandler;
 
 pub struct SharpenCommandHandler;
 
-impl CommandHandler for SharpenCommandHandler {
-    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
-        let image = read_image(args.clone())?;
-        let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
-
-        let pipeline = SharpenPipeline::new(SharpenPipelineOptions { fast: *fast });
-
-        let result_image = run_pipeline(image, Box::new(pipeline))?;
+impl PipelineCommandHandler for SharpenCommandHandler {
+    type Command = SharpenCommand;
 
-        write_image(result_image, args.clone())?;
-
-        Ok(())
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let fast = args.get_one::<bool>(FastArg::id()).map_to_unknown_error()?;
+        let method = args
+            .get_one::<SharpenMethod>(SharpenMethodArg::id())
+            .map_to_unknown_error()?;
+        let radius = args
+            .get_one::<usize>(SharpenRadiusArg::id())
+            .map_to_unknown_error()?;
+        let sigma = args
+            .get_one::<f32>(SharpenSigmaArg::id())
+            .map_to_unknown_error()?;
+        let amount = args
+            .get_one::<f32>(SharpenAmountArg::id())
+            .map_to_unknown_error()?;
+        let threshold = args
+            .get_one::<f32>(SharpenThresholdArg::id())
+            .map_to_unknown_error()?;
+        let luminance_only = args.get_flag(SharpenLuminanceOnlyArg::id());
+
+        let pipeline = SharpenPipeline::new(SharpenPipelineOptions {
+            method: *method,
+            radius: *radius,
+            sigma: *sigma,
+            amount: *amount,
+            threshold: *threshold,
+            luminance_only,
+            fast: *fast,
+        });
+
+        Ok(Box::new(pipeline))
     }
 }
@@ -0,0 +1,29 @@
+use clap::ArgMatches;
+
+use picturify_pipeline::noise::speckle_noise::{SpeckleNoisePipeline, SpeckleNoisePipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::{PicturifyArg, SeedArg};
+use crate::commands::image::noise::speckle_noise::{SpeckleNoiseCommand, SpeckleNoiseSigmaArg};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct SpeckleNoiseCommandHandler;
+
+impl PipelineCommandHandler for SpeckleNoiseCommandHandler {
+    type Command = SpeckleNoiseCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let sigma = args
+            .get_one::<f32>(SpeckleNoiseSigmaArg::id())
+            .map_to_unknown_error()?;
+        let seed = args.get_one::<u64>(SeedArg::id()).copied();
+
+        let pipeline = SpeckleNoisePipeline::new(SpeckleNoisePipelineOptions {
+            sigma: *sigma,
+            seed,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
@@ -0,0 +1,32 @@
+use clap::ArgMatches;
+
+use picturify_core::geometry::coord::Coord;
+use picturify_pipeline::noise::zoom_blur::{ZoomBlurPipeline, ZoomBlurPipelineOptions};
+use picturify_pipeline::pipeline::Pipeline;
+
+use crate::commands::common::args::common::PicturifyArg;
+use crate::commands::image::noise::zoom_blur::{
+    ZoomBlurCenterArg, ZoomBlurCommand, ZoomBlurStrengthArg,
+};
+use crate::error::{CliPicturifyResult, MapToCliPicturifyResult};
+use crate::handlers::common::handler::PipelineCommandHandler;
+
+pub struct ZoomBlurCommandHandler;
+
+impl PipelineCommandHandler for ZoomBlurCommandHandler {
+    type Command = ZoomBlurCommand;
+
+    fn create_pipeline(&self, args: &ArgMatches) -> CliPicturifyResult<Box<dyn Pipeline>> {
+        let center = args.get_one::<Coord>(ZoomBlurCenterArg::id());
+        let strength = args
+            .get_one::<f32>(ZoomBlurStrengthArg::id())
+            .map_to_unknown_error()?;
+
+        let pipeline = ZoomBlurPipeline::new(ZoomBlurPipelineOptions {
+            center: center.copied(),
+            strength: *strength,
+        });
+
+        Ok(Box::new(pipeline))
+    }
+}
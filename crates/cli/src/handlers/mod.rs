@@ -1,3 +1,4 @@
 pub mod common;
+pub mod generate;
 pub mod image;
 pub mod movie;
@@ -0,0 +1,16 @@
+use clap::ArgMatches;
+
+use crate::error::CliPicturifyResult;
+use crate::handlers::common::handler::CommandHandler;
+use crate::handlers::common::movie::run_movie_pipeline;
+use crate::handlers::image::color::lut::create_lut_pipeline;
+
+pub struct LutCommandHandler;
+
+impl CommandHandler for LutCommandHandler {
+    fn handle(&self, args: ArgMatches) -> CliPicturifyResult<()> {
+        let lut_pipeline = create_lut_pipeline(&args)?;
+
+        run_movie_pipeline(&args, Box::new(lut_pipeline))
+    }
+}
@@ -20,6 +20,8 @@ mod metadata;
 mod progress;
 
 const DEFAULT_VERBOSITY: LevelFilter = LevelFilter::Info;
+const STDOUT_OUTPUT: &str = "-";
+const STDOUT_OUTPUT_ARGS: [&str; 3] = ["--output=-", "-o=-", "-o-"];
 
 fn main() -> ExitCode {
     setup_logger();
@@ -57,10 +59,24 @@ fn setup_logger() {
         .unwrap_or(Ok(DEFAULT_VERBOSITY))
         .unwrap_or(DEFAULT_VERBOSITY);
 
+    // keep stdout clean for image data when streaming the output
+    let writes_to_stdout = args()
+        .skip(1)
+        .tuple_windows()
+        .any(|(arg, value)| (arg == "--output" || arg == "-o") && value == STDOUT_OUTPUT)
+        || args()
+            .skip(1)
+            .any(|arg| STDOUT_OUTPUT_ARGS.contains(&arg.as_str()));
+    let terminal_mode = if writes_to_stdout {
+        TerminalMode::Stderr
+    } else {
+        TerminalMode::Mixed
+    };
+
     TermLogger::init(
         verbosity,
         Config::default(),
-        TerminalMode::Mixed,
+        terminal_mode,
         ColorChoice::Auto,
     )
     .expect("Failed to initialize logger");
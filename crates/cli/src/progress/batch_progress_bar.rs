@@ -0,0 +1,11 @@
+use indicatif::{ProgressBar, ProgressStyle};
+
+pub fn create_progress_bar_for_batch(len: usize) -> ProgressBar {
+    let bar = ProgressBar::new(len as u64);
+    bar.set_style(
+        ProgressStyle::with_template("[FILES: {pos}/{len}] [{bar:40.green/cyan}] {percent}%")
+            .unwrap(),
+    );
+
+    bar
+}
@@ -1,2 +1,3 @@
+pub mod batch_progress_bar;
 pub mod movie_progress_bar;
 pub mod pipeline_progress_bar;
@@ -45,5 +45,5 @@ pub fn run_progress_bar_for_movie(movie_progress: Arc<RwLock<MovieProgress>>) {
     }
     bar.finish();
 
-    println!();
+    eprintln!();
 }
@@ -38,5 +38,5 @@ pub fn run_progress_bar_for_pipeline(pipeline_progress: PipelineProgress) {
 
     bar.finish();
 
-    println!();
+    eprintln!();
 }
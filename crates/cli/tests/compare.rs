@@ -0,0 +1,44 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::TestFiles;
+
+#[test]
+fn compare_same_image_with_diff_should_print_json() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("compare")
+        .arg(test_files.input())
+        .arg(test_files.input())
+        .arg("--json")
+        .arg("--diff")
+        .arg(test_files.output());
+
+    let assert = cmd.assert();
+
+    assert
+        .success()
+        .stdout(predicates::str::contains("\"ssim\": 1.0"));
+    assert!(test_files.output().exists());
+}
+
+#[test]
+fn compare_different_sizes_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut generate_cmd = get_picturify_cli_cmd();
+    generate_cmd
+        .arg("generate")
+        .arg("checkerboard")
+        .arg("--size")
+        .arg("64x48")
+        .arg("--output")
+        .arg(test_files.output());
+    generate_cmd.assert().success();
+
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("compare")
+        .arg(test_files.input())
+        .arg(test_files.output());
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
@@ -0,0 +1,36 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::TestFiles;
+
+#[test]
+fn checkerboard_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("generate")
+        .arg("checkerboard")
+        .arg("--size")
+        .arg("64x48")
+        .arg("--output")
+        .arg(test_files.output());
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn checkerboard_zero_cell_size_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("generate")
+        .arg("checkerboard")
+        .arg("--size")
+        .arg("64x48")
+        .arg("-c")
+        .arg("0")
+        .arg("--output")
+        .arg(test_files.output());
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
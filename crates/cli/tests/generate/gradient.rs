@@ -0,0 +1,40 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::TestFiles;
+
+#[test]
+fn gradient_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("generate")
+        .arg("gradient")
+        .arg("--size")
+        .arg("64x48")
+        .arg("--output")
+        .arg(test_files.output());
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn gradient_radial_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("generate")
+        .arg("gradient")
+        .arg("--size")
+        .arg("64x48")
+        .arg("-k")
+        .arg("radial")
+        .arg("--start")
+        .arg("#ff0000")
+        .arg("--end")
+        .arg("0,0,255")
+        .arg("--output")
+        .arg(test_files.output());
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
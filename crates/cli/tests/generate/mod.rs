@@ -0,0 +1,5 @@
+mod checkerboard;
+mod color_bars;
+mod gradient;
+mod perlin_noise;
+mod zone_plate;
@@ -0,0 +1,20 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::TestFiles;
+
+#[test]
+fn perlin_noise_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("generate")
+        .arg("perlin-noise")
+        .arg("--size")
+        .arg("64x48")
+        .arg("--seed")
+        .arg("7")
+        .arg("--output")
+        .arg(test_files.output());
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
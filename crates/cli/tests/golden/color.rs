@@ -0,0 +1,27 @@
+use picturify_testing::golden::GoldenTolerance;
+
+use crate::golden::{run_image_golden, FLOAT_TOLERANCE};
+
+#[test]
+fn negative_should_match_golden() {
+    run_image_golden("negative", &["negative"], GoldenTolerance::EXACT);
+}
+
+#[test]
+fn sepia_should_match_golden() {
+    run_image_golden("sepia", &["sepia"], FLOAT_TOLERANCE);
+}
+
+#[test]
+fn grayscale_should_match_golden() {
+    run_image_golden("grayscale", &["grayscale"], FLOAT_TOLERANCE);
+}
+
+#[test]
+fn hue_rotation_should_match_golden() {
+    run_image_golden(
+        "hue_rotation",
+        &["hue-rotation", "--angle", "-45deg"],
+        FLOAT_TOLERANCE,
+    );
+}
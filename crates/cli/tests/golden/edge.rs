@@ -0,0 +1,25 @@
+use crate::golden::{run_image_golden, FLOAT_TOLERANCE};
+
+#[test]
+fn sobel_should_match_golden() {
+    run_image_golden("sobel", &["sobel"], FLOAT_TOLERANCE);
+}
+
+#[test]
+fn prewitt_should_match_golden() {
+    run_image_golden("prewitt", &["prewitt"], FLOAT_TOLERANCE);
+}
+
+#[test]
+fn gradient_should_match_golden() {
+    run_image_golden("gradient", &["gradient"], FLOAT_TOLERANCE);
+}
+
+#[test]
+fn difference_of_gaussians_should_match_golden() {
+    run_image_golden(
+        "difference_of_gaussians",
+        &["difference-of-gaussians"],
+        FLOAT_TOLERANCE,
+    );
+}
@@ -0,0 +1,17 @@
+use picturify_testing::golden::GoldenTolerance;
+
+use crate::golden::{run_image_golden, FLOAT_TOLERANCE};
+
+#[test]
+fn crop_should_match_golden() {
+    run_image_golden(
+        "crop",
+        &["crop", "--border", "32x24+8+16"],
+        GoldenTolerance::EXACT,
+    );
+}
+
+#[test]
+fn rotate_should_match_golden() {
+    run_image_golden("rotate", &["rotate"], FLOAT_TOLERANCE);
+}
@@ -0,0 +1,24 @@
+use picturify_testing::golden::{assert_golden, GoldenTolerance};
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+mod color;
+mod edge;
+mod geometry;
+mod noise;
+
+// floating point filters may round a channel differently between platforms
+const FLOAT_TOLERANCE: GoldenTolerance = GoldenTolerance {
+    min_psnr: 50.0,
+    max_delta: 2,
+};
+
+fn run_image_golden(name: &str, args: &[&str], tolerance: GoldenTolerance) {
+    let test_files = TestFiles::new_golden_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image").args(args).with_io(&test_files);
+
+    cmd.assert().success();
+
+    assert_golden(name, test_files.output(), tolerance);
+}
@@ -0,0 +1,32 @@
+use picturify_testing::golden::GoldenTolerance;
+
+use crate::golden::{run_image_golden, FLOAT_TOLERANCE};
+
+#[test]
+fn gaussian_blur_should_match_golden() {
+    run_image_golden("gaussian_blur", &["gaussian-blur"], FLOAT_TOLERANCE);
+}
+
+#[test]
+fn median_blur_should_match_golden() {
+    run_image_golden("median_blur", &["median-blur"], GoldenTolerance::EXACT);
+}
+
+#[test]
+fn bilateral_blur_should_match_golden() {
+    run_image_golden("bilateral_blur", &["bilateral-blur"], FLOAT_TOLERANCE);
+}
+
+#[test]
+fn kuwahara_should_match_golden() {
+    run_image_golden("kuwahara", &["kuwahara"], FLOAT_TOLERANCE);
+}
+
+#[test]
+fn sharpen_should_match_golden() {
+    run_image_golden(
+        "sharpen",
+        &["sharpen", "--method", "unsharp-mask"],
+        FLOAT_TOLERANCE,
+    );
+}
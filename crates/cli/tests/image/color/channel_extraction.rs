@@ -0,0 +1,34 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn channel_extraction_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("channel-extraction")
+        .arg("--space")
+        .arg("ycbcr")
+        .arg("--channel")
+        .arg("1")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn channel_extraction_invalid_channel_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("channel-extraction")
+        .arg("--channel")
+        .arg("3")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
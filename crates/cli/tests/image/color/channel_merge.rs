@@ -0,0 +1,19 @@
+use picturify_testing::helpers::{get_picturify_cli_cmd, get_sample_100x100_png_path};
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn channel_merge_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("channel-merge")
+        .arg("--space")
+        .arg("hsv")
+        .arg("--third")
+        .arg(get_sample_100x100_png_path())
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
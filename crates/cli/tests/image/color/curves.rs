@@ -0,0 +1,50 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn curves_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("curves")
+        .arg("-m")
+        .arg("0:0,64:48,192:208,255:255")
+        .arg("-b")
+        .arg("0:16,255:240")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn curves_fast_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("curves")
+        .arg("-r")
+        .arg("0:0,128:150,255:255")
+        .arg("--fast")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn curves_single_point_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("curves")
+        .arg("-m")
+        .arg("128:128")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
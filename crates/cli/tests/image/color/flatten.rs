@@ -0,0 +1,32 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn flatten_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("flatten")
+        .arg("--background")
+        .arg("#336699")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn flatten_invalid_background_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("flatten")
+        .arg("--background")
+        .arg("#12345")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
@@ -0,0 +1,38 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn levels_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("levels")
+        .arg("--input-black")
+        .arg("20")
+        .arg("--input-white")
+        .arg("230")
+        .arg("-g")
+        .arg("1.2")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn levels_inverted_input_range_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("levels")
+        .arg("--input-black")
+        .arg("200")
+        .arg("--input-white")
+        .arg("100")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
@@ -0,0 +1,34 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn lightness_oklab_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("lightness")
+        .arg("-f")
+        .arg("1.1")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn lightness_lch_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("lightness")
+        .arg("-f")
+        .arg("0.9")
+        .arg("-s")
+        .arg("lch")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
@@ -0,0 +1,104 @@
+use std::fs::write;
+use std::path::Path;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::{ReadFromFile, WriteToFile};
+use picturify_core::image::Rgba;
+use picturify_testing::helpers::{
+    get_null_path, get_picturify_cli_cmd, get_sample_100x100_png_path, get_sample_lut_path,
+};
+use picturify_testing::test_files::{TestFiles, WithIo};
+use uuid::Uuid;
+
+fn assert_lut_keeps_pixels(lut_path: &Path) {
+    for interpolation in ["trilinear", "tetrahedral"] {
+        let test_files = TestFiles::new_golden_png();
+        let mut cmd = get_picturify_cli_cmd();
+        cmd.arg("image")
+            .arg("lut")
+            .arg("--file")
+            .arg(lut_path)
+            .arg("-m")
+            .arg(interpolation)
+            .with_io(&test_files);
+        cmd.assert().success();
+
+        let input = FastImage::read_from_file(test_files.input()).unwrap();
+        let output = FastImage::read_from_file(test_files.output()).unwrap();
+        assert_eq!(input.to_rgba_vec(), output.to_rgba_vec());
+    }
+}
+
+#[test]
+fn lut_cube_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("lut")
+        .arg("--file")
+        .arg(get_sample_lut_path())
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn lut_trilinear_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("lut")
+        .arg("--file")
+        .arg(get_sample_lut_path())
+        .arg("-m")
+        .arg("trilinear")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn lut_identity_cube_should_keep_pixels() {
+    let mut cube = String::from("LUT_3D_SIZE 2\n");
+    for index in 0..8 {
+        let [red, green, blue] = [index & 1, (index >> 1) & 1, (index >> 2) & 1];
+        cube.push_str(&format!("{} {} {}\n", red, green, blue));
+    }
+    let lut_path = get_null_path().join(format!("{}.cube", Uuid::new_v4()));
+    write(&lut_path, cube).unwrap();
+
+    assert_lut_keeps_pixels(&lut_path);
+}
+
+#[test]
+fn lut_identity_hald_should_keep_pixels() {
+    // level 2 is an 8x8 image holding a lattice of 4 steps per channel
+    let mut hald = FastImage::empty((8, 8).into());
+    for (index, pixel) in hald.pixels_mut().enumerate() {
+        let [red, green, blue] = [index % 4, (index / 4) % 4, index / 16].map(|v| (v * 85) as u8);
+        *pixel = Rgba([red, green, blue, 255]);
+    }
+    let lut_path = get_null_path().join(format!("{}.png", Uuid::new_v4()));
+    hald.write_to_file(&lut_path).unwrap();
+
+    assert_lut_keeps_pixels(&lut_path);
+}
+
+#[test]
+fn lut_image_that_is_not_hald_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("lut")
+        .arg("--file")
+        .arg(get_sample_100x100_png_path())
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
@@ -1,5 +1,19 @@
 mod brightness;
+mod channel_extraction;
+mod channel_merge;
+mod curves;
+mod extract_alpha;
+mod flatten;
 mod grayscale;
+mod hue_rotation;
+mod levels;
+mod lightness;
+mod lut;
 mod negative;
+mod palette_quantization;
 mod quantization;
+mod saturation;
 mod sepia;
+mod set_alpha;
+mod temperature;
+mod white_balance;
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+use std::fs::remove_file;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::ReadFromFile;
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn palette_quantization_median_cut_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("palette-quantization")
+        .arg("-c")
+        .arg("8")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn palette_quantization_k_means_with_floyd_steinberg_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("palette-quantization")
+        .arg("-m")
+        .arg("k-means")
+        .arg("-d")
+        .arg("floyd-steinberg")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn palette_quantization_octree_with_bayer_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("palette-quantization")
+        .arg("-m")
+        .arg("octree")
+        .arg("-d")
+        .arg("bayer")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn palette_quantization_should_use_at_most_requested_colors() {
+    for method in ["median-cut", "k-means", "octree"] {
+        let test_files = TestFiles::new_golden_png();
+        let mut cmd = get_picturify_cli_cmd();
+        cmd.arg("image")
+            .arg("palette-quantization")
+            .arg("-c")
+            .arg("8")
+            .arg("-m")
+            .arg(method)
+            .arg("-d")
+            .arg("floyd-steinberg")
+            .with_io(&test_files);
+        cmd.assert().success();
+
+        let image = FastImage::read_from_file(test_files.output()).unwrap();
+        let colors: HashSet<[u8; 3]> = image
+            .pixels()
+            .map(|pixel| [pixel.0[0], pixel.0[1], pixel.0[2]])
+            .collect();
+
+        assert!(colors.len() <= 8, "{} used {} colors", method, colors.len());
+    }
+}
+
+#[test]
+fn palette_quantization_exported_palette_should_be_reusable() {
+    let test_files = TestFiles::new_100x100_png();
+    let palette_path = test_files.output().with_extension("gpl");
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("palette-quantization")
+        .arg("-c")
+        .arg("4")
+        .arg("--export-palette")
+        .arg(&palette_path)
+        .with_io(&test_files);
+    cmd.assert().success();
+
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("palette-quantization")
+        .arg("-p")
+        .arg(&palette_path)
+        .arg("-d")
+        .arg("atkinson")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+    remove_file(&palette_path).unwrap();
+
+    assert.success();
+}
+
+#[test]
+fn palette_quantization_zero_colors_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("palette-quantization")
+        .arg("-c")
+        .arg("0")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
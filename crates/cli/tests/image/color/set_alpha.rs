@@ -0,0 +1,28 @@
+use picturify_testing::helpers::{get_picturify_cli_cmd, get_sample_100x100_png_path};
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn set_alpha_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("set-alpha")
+        .arg("--alpha")
+        .arg(get_sample_100x100_png_path())
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn set_alpha_missing_alpha_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image").arg("set-alpha").with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
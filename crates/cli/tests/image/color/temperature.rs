@@ -0,0 +1,19 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn temperature_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("temperature")
+        .arg("-t")
+        .arg("3200")
+        .arg("--tint")
+        .arg("-10")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
@@ -0,0 +1,87 @@
+use std::fs::remove_file;
+use std::path::Path;
+
+use picturify_core::core::animation::AnimatedImage;
+use picturify_core::core::io::ReadFromFile;
+use picturify_testing::helpers::{get_picturify_cli_cmd, get_sample_animated_gif_path};
+use picturify_testing::test_files::TestFiles;
+
+fn assert_same_timing(output: impl AsRef<Path>) {
+    let input = AnimatedImage::read_from_file(get_sample_animated_gif_path()).unwrap();
+    let output = AnimatedImage::read_from_file(output).unwrap();
+
+    assert_eq!(output.frames().len(), input.frames().len());
+    assert_eq!(output.loop_count(), input.loop_count());
+    for (output_frame, input_frame) in output.frames().iter().zip(input.frames()) {
+        assert_eq!(output_frame.delay_ms(), input_frame.delay_ms());
+        assert_eq!(output_frame.disposal, input_frame.disposal);
+    }
+}
+
+#[test]
+fn animated_gif_to_gif_should_keep_frames() {
+    let test_files = TestFiles::new_100x100_png();
+    let output = test_files.output().with_extension("gif");
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("negative")
+        .arg("--input")
+        .arg(get_sample_animated_gif_path())
+        .arg("--output")
+        .arg(&output);
+
+    cmd.assert().success();
+    assert_same_timing(&output);
+    remove_file(&output).unwrap();
+}
+
+#[test]
+fn animated_gif_to_apng_should_keep_frames() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("gaussian-blur")
+        .arg("--input")
+        .arg(get_sample_animated_gif_path())
+        .arg("--output")
+        .arg(test_files.output());
+
+    cmd.assert().success();
+    assert_same_timing(test_files.output());
+}
+
+#[test]
+fn animated_gif_to_webp_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let output = test_files.output().with_extension("webp");
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("negative")
+        .arg("--input")
+        .arg(get_sample_animated_gif_path())
+        .arg("--output")
+        .arg(&output);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+    assert!(!output.exists());
+}
+
+#[test]
+fn animated_gif_to_still_format_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let output = test_files.output().with_extension("jpg");
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("sepia")
+        .arg("--input")
+        .arg(get_sample_animated_gif_path())
+        .arg("--output")
+        .arg(&output);
+
+    let assert = cmd.assert();
+    remove_file(&output).unwrap();
+
+    assert.success();
+}
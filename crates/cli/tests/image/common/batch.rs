@@ -0,0 +1,124 @@
+use std::fs::{copy, create_dir_all, remove_dir_all};
+use std::path::PathBuf;
+
+use uuid::Uuid;
+
+use picturify_testing::helpers::{
+    get_null_path, get_picturify_cli_cmd, get_sample_100x100_png_path,
+};
+
+struct BatchDirs {
+    root: PathBuf,
+}
+
+impl BatchDirs {
+    fn new(file_count: usize) -> Self {
+        let root = get_null_path().join(Uuid::new_v4().to_string());
+        create_dir_all(root.join("input")).unwrap();
+        for i in 0..file_count {
+            copy(
+                get_sample_100x100_png_path(),
+                root.join("input").join(format!("sample_{}.png", i)),
+            )
+            .unwrap();
+        }
+        Self { root }
+    }
+
+    fn input(&self) -> PathBuf {
+        self.root.join("input")
+    }
+
+    fn output(&self) -> PathBuf {
+        self.root.join("output")
+    }
+}
+
+impl Drop for BatchDirs {
+    fn drop(&mut self) {
+        if self.root.exists() {
+            remove_dir_all(&self.root).unwrap();
+        }
+    }
+}
+
+#[test]
+fn batch_directory_should_succeed() {
+    let dirs = BatchDirs::new(2);
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("passthrough")
+        .arg("--input")
+        .arg(dirs.input())
+        .arg("--output")
+        .arg(dirs.output());
+
+    let assert = cmd.assert();
+
+    assert.success();
+    assert!(dirs.output().join("sample_0_passthrough.png").exists());
+    assert!(dirs.output().join("sample_1_passthrough.png").exists());
+}
+
+#[test]
+fn batch_glob_with_name_template_should_succeed() {
+    let dirs = BatchDirs::new(2);
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("passthrough")
+        .arg("--input")
+        .arg(dirs.input().join("*_1.png"))
+        .arg("--output")
+        .arg(dirs.output())
+        .arg("--format")
+        .arg("jpg")
+        .arg("--name-template")
+        .arg("{filter}-{stem}.{ext}");
+
+    let assert = cmd.assert();
+
+    assert.success();
+    assert!(!dirs.output().join("passthrough-sample_0.jpg").exists());
+    assert!(dirs.output().join("passthrough-sample_1.jpg").exists());
+}
+
+#[test]
+fn existing_file_with_glob_characters_should_not_be_batched() {
+    let dirs = BatchDirs::new(0);
+    let input = dirs.input().join("photo[1].png");
+    copy(get_sample_100x100_png_path(), &input).unwrap();
+    let output = dirs.root.join("output.png");
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("passthrough")
+        .arg("--input")
+        .arg(&input)
+        .arg("--output")
+        .arg(&output);
+
+    let assert = cmd.assert();
+
+    assert.success();
+    assert!(output.is_file());
+}
+
+#[test]
+fn batch_with_export_palette_should_fail() {
+    let dirs = BatchDirs::new(2);
+    let palette_path = dirs.root.join("palette.gpl");
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("palette-quantization")
+        .arg("--export-palette")
+        .arg(&palette_path)
+        .arg("--input")
+        .arg(dirs.input())
+        .arg("--output")
+        .arg(dirs.output());
+
+    let assert = cmd.assert();
+
+    assert.failure();
+    assert!(!palette_path.exists());
+    assert!(!dirs.output().exists());
+}
@@ -0,0 +1,72 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::ReadFromFile;
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn linear_working_space_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("gaussian-blur")
+        .arg("--working-space")
+        .arg("linear-srgb")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn linear_working_space_passthrough_should_round_trip() {
+    let test_files = TestFiles::new_golden_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("passthrough")
+        .arg("--working-space")
+        .arg("linear-srgb")
+        .with_io(&test_files);
+    cmd.assert().success();
+
+    let input = FastImage::read_from_file(test_files.input()).unwrap();
+    let output = FastImage::read_from_file(test_files.output()).unwrap();
+    let max_delta = input
+        .to_rgba_vec()
+        .iter()
+        .zip(output.to_rgba_vec())
+        .map(|(input, output)| input.abs_diff(output))
+        .max()
+        .unwrap();
+    assert!(max_delta <= 1, "max delta {}", max_delta);
+}
+
+#[test]
+fn wide_gamut_working_space_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("passthrough")
+        .arg("--working-space")
+        .arg("display-p3")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn unknown_working_space_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("passthrough")
+        .arg("--working-space")
+        .arg("adobe-rgb")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
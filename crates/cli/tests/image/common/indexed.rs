@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+use std::fs::remove_file;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::ReadFromFile;
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn indexed_png_after_quantization_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("palette-quantization")
+        .arg("-c")
+        .arg("16")
+        .arg("--indexed")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn indexed_gif_after_quantization_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let gif_path = test_files.output().with_extension("gif");
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("palette-quantization")
+        .arg("-c")
+        .arg("32")
+        .arg("--indexed")
+        .arg("--input")
+        .arg(test_files.input())
+        .arg("--output")
+        .arg(&gif_path);
+
+    let assert = cmd.assert();
+    remove_file(&gif_path).unwrap();
+
+    assert.success();
+}
+
+#[test]
+fn indexed_with_too_many_colors_should_quantize() {
+    let test_files = TestFiles::new_golden_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("passthrough")
+        .arg("--indexed")
+        .with_io(&test_files);
+
+    cmd.assert().success();
+
+    let input = FastImage::read_from_file(test_files.input()).unwrap();
+    let output = FastImage::read_from_file(test_files.output()).unwrap();
+    let colors =
+        |image: &FastImage| -> HashSet<[u8; 4]> { image.pixels().map(|pixel| pixel.0).collect() };
+    assert!(colors(&input).len() > 256);
+    assert!(colors(&output).len() <= 256);
+}
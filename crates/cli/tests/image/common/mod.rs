@@ -1 +1,8 @@
+mod animation;
+mod batch;
+mod color_management;
+mod indexed;
 mod passthrough;
+mod region;
+mod stdio;
+mod tiled;
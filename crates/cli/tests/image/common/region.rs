@@ -0,0 +1,127 @@
+use picturify_testing::golden::get_golden_input_png_path;
+use picturify_testing::helpers::{get_picturify_cli_cmd, get_sample_100x100_png_path};
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn region_roi_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("gaussian-blur")
+        .arg("--roi")
+        .arg("40x40+10+10")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn region_mask_with_feather_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("negative")
+        .arg("--mask")
+        .arg(get_sample_100x100_png_path())
+        .arg("--feather")
+        .arg("3")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn region_roi_outside_image_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("sepia")
+        .arg("--roi")
+        .arg("200x20+0+0")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
+
+#[test]
+fn region_roi_with_overflowing_offset_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("sepia")
+        .arg("--roi")
+        .arg("1x18446744073709551615+0+1")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert
+        .failure()
+        .stderr(predicates::str::contains("outside the image"));
+}
+
+#[test]
+fn region_roi_outside_image_tiled_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("sepia")
+        .arg("--roi")
+        .arg("10x10+0+500")
+        .arg("--tile-height")
+        .arg("30")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert
+        .failure()
+        .stderr(predicates::str::contains("outside the image"));
+}
+
+#[test]
+fn region_roi_with_overflowing_offset_tiled_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("sepia")
+        .arg("--roi")
+        .arg("1x18446744073709551615+0+1")
+        .arg("--tile-height")
+        .arg("30")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert
+        .failure()
+        .stderr(predicates::str::contains("outside the image"));
+}
+
+#[test]
+fn region_mask_of_other_size_should_fail() {
+    for tile_height in [None, Some("30")] {
+        let test_files = TestFiles::new_100x100_png();
+        let mut cmd = get_picturify_cli_cmd();
+        cmd.arg("image")
+            .arg("sepia")
+            .arg("--mask")
+            .arg(get_golden_input_png_path())
+            .with_io(&test_files);
+        if let Some(tile_height) = tile_height {
+            cmd.arg("--tile-height").arg(tile_height);
+        }
+
+        let assert = cmd.assert();
+
+        assert
+            .failure()
+            .stderr(predicates::str::contains("Mask size does not match"));
+    }
+}
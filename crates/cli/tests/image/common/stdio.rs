@@ -0,0 +1,65 @@
+use std::fs::read;
+
+use picturify_testing::helpers::{get_picturify_cli_cmd, get_sample_100x100_png_path};
+
+const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+#[test]
+fn stdio_stdin_to_stdout_should_succeed() {
+    let input = read(get_sample_100x100_png_path()).unwrap();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("passthrough")
+        .arg("--input")
+        .arg("-")
+        .arg("--output")
+        .arg("-")
+        .arg("--format")
+        .arg("png")
+        .write_stdin(input);
+
+    let assert = cmd.assert();
+
+    assert
+        .success()
+        .stdout(predicates::function::function(|stdout: &[u8]| {
+            stdout.starts_with(PNG_SIGNATURE)
+        }));
+}
+
+#[test]
+fn stdio_stdout_with_equals_form_should_succeed() {
+    let input = read(get_sample_100x100_png_path()).unwrap();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("passthrough")
+        .arg("--input=-")
+        .arg("--output=-")
+        .arg("--format=png")
+        .write_stdin(input);
+
+    let assert = cmd.assert();
+
+    assert
+        .success()
+        .stdout(predicates::function::function(|stdout: &[u8]| {
+            stdout.starts_with(PNG_SIGNATURE)
+        }));
+}
+
+#[test]
+fn stdio_stdout_without_format_should_fail() {
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("passthrough")
+        .arg("--input")
+        .arg(get_sample_100x100_png_path())
+        .arg("--output")
+        .arg("-");
+
+    let assert = cmd.assert();
+
+    assert
+        .failure()
+        .stderr(predicates::str::contains("--format is required"));
+}
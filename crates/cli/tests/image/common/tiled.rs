@@ -0,0 +1,57 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::ReadFromFile;
+use picturify_testing::golden::get_golden_input_png_path;
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+fn run_gaussian_blur(test_files: TestFiles, args: &[&str]) -> FastImage {
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("gaussian-blur")
+        .arg("--radius")
+        .arg("3")
+        .args(args)
+        .with_io(&test_files);
+    cmd.assert().success();
+
+    FastImage::read_from_file(test_files.output()).unwrap()
+}
+
+#[test]
+fn tiled_gaussian_blur_should_match_untiled() {
+    let untiled = run_gaussian_blur(TestFiles::new_100x100_png(), &[]);
+    let tiled = run_gaussian_blur(TestFiles::new_100x100_png(), &["--tile-height", "7"]);
+
+    assert_eq!(untiled.to_rgba_vec(), tiled.to_rgba_vec());
+}
+
+#[test]
+fn tiled_region_should_match_untiled() {
+    // the region straddles tile borders and the mask is cut to every tile
+    let mask = get_golden_input_png_path();
+    let region = ["--roi", "40x40+10+13", "--mask", mask.to_str().unwrap()];
+    let untiled = run_gaussian_blur(TestFiles::new_golden_png(), &region);
+    let tiled = run_gaussian_blur(
+        TestFiles::new_golden_png(),
+        &[&region[..], &["--tile-height", "7"]].concat(),
+    );
+
+    assert_eq!(untiled.to_rgba_vec(), tiled.to_rgba_vec());
+}
+
+#[test]
+fn tiled_unsupported_pipeline_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("rotate")
+        .arg("--tile-height")
+        .arg("8")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure().stderr(predicates::str::contains(
+        "does not support tiled processing",
+    ));
+}
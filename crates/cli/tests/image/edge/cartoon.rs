@@ -0,0 +1,32 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn cartoon_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image").arg("cartoon").with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn cartoon_kuwahara_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("cartoon")
+        .arg("--smoothing")
+        .arg("kuwahara")
+        .arg("--radius")
+        .arg("2")
+        .arg("--levels")
+        .arg("3")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
@@ -0,0 +1,32 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn difference_of_gaussians_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image").arg("difference-of-gaussians").with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn difference_of_gaussians_custom_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("difference-of-gaussians")
+        .arg("--sigma")
+        .arg("2.0")
+        .arg("-k")
+        .arg("2.5")
+        .arg("--gain")
+        .arg("8")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
@@ -0,0 +1,32 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn gradient_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image").arg("gradient").with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn gradient_signed_derivative_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("gradient")
+        .arg("--operator")
+        .arg("prewitt")
+        .arg("--mode")
+        .arg("dy")
+        .arg("--gain")
+        .arg("2.0")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
@@ -1,2 +1,6 @@
+mod cartoon;
+mod difference_of_gaussians;
+mod gradient;
 mod prewitt;
 mod sobel;
+mod xdog;
@@ -0,0 +1,32 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn xdog_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image").arg("xdog").with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn xdog_hard_threshold_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("xdog")
+        .arg("--sharpness")
+        .arg("40")
+        .arg("--epsilon")
+        .arg("0.5")
+        .arg("--phi")
+        .arg("200")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
@@ -15,3 +15,39 @@ fn crop_standard_args_should_succeed() {
 
     assert.success();
 }
+
+#[test]
+fn crop_outside_image_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("crop")
+        .arg("--border")
+        .arg("50x50+75+25")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert
+        .failure()
+        .stderr(predicates::str::contains("outside the image"));
+}
+
+#[test]
+fn crop_with_overflowing_border_should_fail() {
+    for border in ["18446744073709551615x1+1+0", "1x18446744073709551615+0+1"] {
+        let test_files = TestFiles::new_100x100_png();
+        let mut cmd = get_picturify_cli_cmd();
+        cmd.arg("image")
+            .arg("crop")
+            .arg("--border")
+            .arg(border)
+            .with_io(&test_files);
+
+        let assert = cmd.assert();
+
+        assert
+            .failure()
+            .stderr(predicates::str::contains("outside the image"));
+    }
+}
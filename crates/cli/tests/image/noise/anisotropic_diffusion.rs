@@ -0,0 +1,32 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn anisotropic_diffusion_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("anisotropic-diffusion")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn anisotropic_diffusion_quadratic_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("anisotropic-diffusion")
+        .arg("-n")
+        .arg("5")
+        .arg("--function")
+        .arg("quadratic")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
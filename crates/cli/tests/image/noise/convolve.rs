@@ -0,0 +1,148 @@
+use std::fs::remove_file;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::{ReadFromFile, WriteToFile};
+use picturify_testing::helpers::{
+    get_picturify_cli_cmd, get_sample_kernel_csv_path, get_sample_kernel_json_path,
+};
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn convolve_inline_kernel_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("convolve")
+        .arg("--kernel")
+        .arg("0,-1,0;-1,5,-1;0,-1,0")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn convolve_json_file_normalized_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("convolve")
+        .arg("--file")
+        .arg(get_sample_kernel_json_path())
+        .arg("--normalize")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn convolve_csv_file_with_bias_anchor_and_channels_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("convolve")
+        .arg("--file")
+        .arg(get_sample_kernel_csv_path())
+        .arg("--divisor")
+        .arg("2")
+        .arg("--bias")
+        .arg("128")
+        .arg("--anchor")
+        .arg("0,0")
+        .arg("--channels")
+        .arg("rg")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn convolve_ragged_kernel_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("convolve")
+        .arg("--kernel")
+        .arg("1,1;1")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
+
+#[test]
+fn convolve_emboss_with_bias_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("convolve")
+        .arg("--kernel")
+        .arg("-2,-1,0;-1,1,1;0,1,2")
+        .arg("--bias")
+        .arg("128")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn convolve_anchor_outside_kernel_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("convolve")
+        .arg("--kernel")
+        .arg("-1,0,1")
+        .arg("--anchor")
+        .arg("3,0")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure();
+}
+
+#[test]
+fn convolve_should_not_bleed_transparent_colors() {
+    let test_files = TestFiles::new_100x100_png();
+    let input = test_files.output().with_extension("input.png");
+    // transparent red on the left half, opaque blue on the right half
+    let rgba_vec = (0..64)
+        .flat_map(|index| match index % 8 < 4 {
+            true => [255, 0, 0, 0],
+            false => [0, 0, 255, 255],
+        })
+        .collect();
+    FastImage::from_rgba_vec((8, 8).into(), rgba_vec)
+        .write_to_file(&input)
+        .unwrap();
+
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("convolve")
+        .arg("--kernel")
+        .arg("1,1,1;1,1,1;1,1,1")
+        .arg("--normalize")
+        .arg("--input")
+        .arg(&input)
+        .arg("--output")
+        .arg(test_files.output());
+
+    let assert = cmd.assert();
+    remove_file(&input).unwrap();
+
+    assert.success();
+    let output = FastImage::read_from_file(test_files.output()).unwrap();
+    for y in 0..8 {
+        let pixel = output.get_image_pixel((4, y).into());
+        assert_eq!(&pixel.0[..3], &[0, 0, 255]);
+    }
+}
@@ -0,0 +1,35 @@
+use std::fs::read;
+
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn gaussian_noise_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image").arg("gaussian-noise").with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn gaussian_noise_same_seed_should_give_same_output() {
+    let first_files = TestFiles::new_100x100_png();
+    let second_files = TestFiles::new_100x100_png();
+    for test_files in [&first_files, &second_files] {
+        let mut cmd = get_picturify_cli_cmd();
+        cmd.arg("image")
+            .arg("gaussian-noise")
+            .arg("--seed")
+            .arg("42")
+            .with_io(test_files);
+        cmd.assert().success();
+    }
+
+    let first = read(first_files.output()).unwrap();
+    let second = read(second_files.output()).unwrap();
+
+    assert_eq!(first, second);
+}
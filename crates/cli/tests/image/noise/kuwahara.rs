@@ -11,3 +11,41 @@ fn kuwahara_standard_args_should_succeed() {
 
     assert.success();
 }
+
+#[test]
+fn kuwahara_generalized_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("kuwahara")
+        .arg("--method")
+        .arg("generalized")
+        .arg("--radius")
+        .arg("3")
+        .arg("--sectors")
+        .arg("6")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn kuwahara_anisotropic_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("kuwahara")
+        .arg("-m")
+        .arg("anisotropic")
+        .arg("-r")
+        .arg("4")
+        .arg("-q")
+        .arg("12")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
@@ -1,6 +1,16 @@
+mod anisotropic_diffusion;
 mod bilateral_blur;
+mod convolve;
 mod gaussian_blur;
+mod gaussian_noise;
 mod kuwahara;
 mod mean_blur;
 mod median_blur;
+mod motion_blur;
+mod non_local_means;
+mod poisson_noise;
+mod radial_blur;
+mod salt_and_pepper_noise;
 mod sharpen;
+mod speckle_noise;
+mod zoom_blur;
@@ -0,0 +1,30 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn motion_blur_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image").arg("motion-blur").with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn motion_blur_diagonal_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("motion-blur")
+        .arg("--angle")
+        .arg("45deg")
+        .arg("--length")
+        .arg("25")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
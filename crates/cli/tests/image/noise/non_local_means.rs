@@ -0,0 +1,32 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn non_local_means_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image").arg("non-local-means").with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn non_local_means_custom_radii_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("non-local-means")
+        .arg("-r")
+        .arg("3")
+        .arg("-p")
+        .arg("1")
+        .arg("-s")
+        .arg("25")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
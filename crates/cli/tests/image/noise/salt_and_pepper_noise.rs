@@ -0,0 +1,32 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn salt_and_pepper_noise_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("salt-and-pepper-noise")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn salt_and_pepper_noise_custom_amount_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("salt-and-pepper-noise")
+        .arg("-a")
+        .arg("0.2")
+        .arg("--salt-ratio")
+        .arg("0.8")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
@@ -11,3 +11,57 @@ fn sharpen_standard_args_should_succeed() {
 
     assert.success();
 }
+
+#[test]
+fn sharpen_kernel_method_with_amount_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("sharpen")
+        .arg("--method")
+        .arg("kernel")
+        .arg("--amount")
+        .arg("0.5")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn sharpen_unsharp_mask_with_threshold_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("sharpen")
+        .arg("--method")
+        .arg("unsharp-mask")
+        .arg("--radius")
+        .arg("3")
+        .arg("--sigma")
+        .arg("1.5")
+        .arg("--threshold")
+        .arg("4")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn sharpen_high_pass_luminance_only_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("sharpen")
+        .arg("--method")
+        .arg("high-pass")
+        .arg("--luminance-only")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
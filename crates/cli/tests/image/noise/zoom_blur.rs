@@ -0,0 +1,30 @@
+use picturify_testing::helpers::get_picturify_cli_cmd;
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+#[test]
+fn zoom_blur_standard_args_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image").arg("zoom-blur").with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
+
+#[test]
+fn zoom_blur_custom_center_should_succeed() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("image")
+        .arg("zoom-blur")
+        .arg("--center")
+        .arg("0,0")
+        .arg("--strength")
+        .arg("0.6")
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.success();
+}
@@ -1,2 +1,6 @@
+mod compare;
 mod empty;
+mod generate;
+mod golden;
 mod image;
+mod movie;
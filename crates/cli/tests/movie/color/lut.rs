@@ -0,0 +1,32 @@
+use picturify_testing::helpers::{get_picturify_cli_cmd, get_sample_100x100_png_path};
+use picturify_testing::test_files::{TestFiles, WithIo};
+
+// both fail before any video is decoded, so ffmpeg is not needed
+
+#[test]
+fn movie_lut_without_file_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("movie").arg("lut").with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert.failure().stderr(predicates::str::contains("--file"));
+}
+
+#[test]
+fn movie_lut_with_invalid_lut_should_fail() {
+    let test_files = TestFiles::new_100x100_png();
+    let mut cmd = get_picturify_cli_cmd();
+    cmd.arg("movie")
+        .arg("lut")
+        .arg("--file")
+        .arg(get_sample_100x100_png_path())
+        .with_io(&test_files);
+
+    let assert = cmd.assert();
+
+    assert
+        .failure()
+        .stderr(predicates::str::contains("Invalid 3D LUT"));
+}
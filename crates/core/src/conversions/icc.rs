@@ -0,0 +1,375 @@
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::Arc;
+
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+use image::io::Reader;
+use image::ImageDecoder;
+use moxcms::{DataColorSpace, Layout, ToneReprCurve, TransformF32Executor, TransformOptions};
+use rayon::prelude::*;
+
+use crate::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use crate::core::fast_image::FastImage;
+use crate::error::{PicturifyError, PicturifyResult};
+
+pub type Matrix = [[f32; 3]; 3];
+
+// pixels handed to the color management module at once
+const TRANSFORM_CHUNK_SIZE: usize = 4096;
+// profiles of the same space store their colorants with different rounding
+const IDENTITY_TOLERANCE: f64 = 1e-3;
+
+pub const D65_WHITE: (f32, f32) = (0.3127, 0.3290);
+
+pub const BRADFORD: Matrix = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+// ICC color profile of an RGB or gray image, parsed and applied by moxcms, so both
+// matrix/TRC and LUT based profiles are supported
+#[derive(Debug, Clone)]
+pub struct ColorProfile {
+    profile: moxcms::ColorProfile,
+}
+
+impl ColorProfile {
+    pub fn from_icc(data: &[u8]) -> PicturifyResult<Self> {
+        let profile = moxcms::ColorProfile::new_from_slice(data)
+            .map_err(|e| invalid_profile(&e.to_string()))?;
+
+        match profile.color_space {
+            DataColorSpace::Rgb | DataColorSpace::Gray => Ok(Self { profile }),
+            _ => Err(invalid_profile("only RGB and gray profiles are supported")),
+        }
+    }
+
+    fn is_gray(&self) -> bool {
+        self.profile.color_space == DataColorSpace::Gray
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WorkingSpace {
+    Srgb,
+    LinearSrgb,
+    DisplayP3,
+    Rec2020,
+}
+
+impl ValueEnum for WorkingSpace {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            WorkingSpace::Srgb,
+            WorkingSpace::LinearSrgb,
+            WorkingSpace::DisplayP3,
+            WorkingSpace::Rec2020,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            WorkingSpace::Srgb => Some(PossibleValue::new("srgb")),
+            WorkingSpace::LinearSrgb => Some(PossibleValue::new("linear-srgb")),
+            WorkingSpace::DisplayP3 => Some(PossibleValue::new("display-p3")),
+            WorkingSpace::Rec2020 => Some(PossibleValue::new("rec2020")),
+        }
+    }
+}
+
+impl WorkingSpace {
+    pub fn profile(&self) -> ColorProfile {
+        let profile = match self {
+            WorkingSpace::Srgb => moxcms::ColorProfile::new_srgb(),
+            WorkingSpace::LinearSrgb => {
+                let mut profile = moxcms::ColorProfile::new_srgb();
+                let linear = ToneReprCurve::Parametric(vec![1.0]);
+                profile.red_trc = Some(linear.clone());
+                profile.green_trc = Some(linear.clone());
+                profile.blue_trc = Some(linear);
+                // the code points would describe the sRGB curve replaced above
+                profile.cicp = None;
+                profile
+            }
+            WorkingSpace::DisplayP3 => moxcms::ColorProfile::new_display_p3(),
+            WorkingSpace::Rec2020 => moxcms::ColorProfile::new_bt2020(),
+        };
+
+        ColorProfile { profile }
+    }
+}
+
+// Converts 8-bit pixels between two profiles, alpha is left untouched. Gray sources are read
+// from the red channel, decoded gray images hold the same value in every color channel.
+#[derive(Clone)]
+pub struct ColorTransform {
+    executor: Arc<TransformF32Executor>,
+    gray_source: bool,
+    identity: bool,
+}
+
+impl ColorTransform {
+    pub fn new(source: &ColorProfile, target: &ColorProfile) -> PicturifyResult<Self> {
+        let gray_source = source.is_gray();
+        let source_layout = match gray_source {
+            true => Layout::Gray,
+            false => Layout::Rgb,
+        };
+        let options = TransformOptions {
+            prefer_fixed_point: false,
+            ..TransformOptions::default()
+        };
+        let executor = source
+            .profile
+            .create_transform_f32(source_layout, &target.profile, Layout::Rgb, options)
+            .map_err(|e| invalid_profile(&e.to_string()))?;
+
+        let mut transform = Self {
+            executor,
+            gray_source,
+            identity: false,
+        };
+        transform.identity = transform.changes_no_value(source, target)?;
+
+        Ok(transform)
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.identity
+    }
+
+    // The colorants have to match within the tolerance, after that every level of each
+    // channel and of the gray axis has to convert back to itself. Gray sources only hold
+    // values on the gray axis.
+    fn changes_no_value(
+        &self,
+        source: &ColorProfile,
+        target: &ColorProfile,
+    ) -> PicturifyResult<bool> {
+        if !self.gray_source && !same_colorants(source, target) {
+            return Ok(false);
+        }
+
+        let samples: Vec<[f32; 3]> = (0..=255)
+            .map(|level| level as f32)
+            .flat_map(|level| {
+                [
+                    [level; 3],
+                    [level, 0.0, 0.0],
+                    [0.0, level, 0.0],
+                    [0.0, 0.0, level],
+                ]
+            })
+            .filter(|sample| !self.gray_source || sample.iter().all(|value| *value == sample[0]))
+            .collect();
+
+        let converted = self.convert(&samples)?;
+        Ok(samples.iter().zip(converted).all(|(sample, converted)| {
+            (0..3).all(|channel| round_channel(converted[channel]) == sample[channel] as u8)
+        }))
+    }
+
+    // values and results are in 0-255 units, results before rounding
+    fn convert(&self, values: &[[f32; 3]]) -> PicturifyResult<Vec<[f32; 3]>> {
+        let converted = values
+            .par_chunks(TRANSFORM_CHUNK_SIZE)
+            .map(|chunk| {
+                let source: Vec<f32> = match self.gray_source {
+                    true => chunk.iter().map(|value| value[0] / 255.0).collect(),
+                    false => chunk.iter().flatten().map(|value| value / 255.0).collect(),
+                };
+                let mut target = vec![0.0; chunk.len() * 3];
+                self.executor
+                    .transform(&source, &mut target)
+                    .map_err(|e| invalid_profile(&e.to_string()))?;
+
+                Ok(target
+                    .chunks_exact(3)
+                    .map(|value| [value[0], value[1], value[2]].map(|value| value * 255.0))
+                    .collect::<Vec<_>>())
+            })
+            .collect::<PicturifyResult<Vec<_>>>()?;
+
+        Ok(converted.concat())
+    }
+
+    fn write_values(image: &mut FastImage, values: &[[f32; 3]]) {
+        let (width, _): (usize, usize) = image.size().into();
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let values = values[coord.array_index(width)];
+                for (channel, value) in pixel.0.iter_mut().zip(values) {
+                    *channel = round_channel(value);
+                }
+            },
+            None,
+        );
+    }
+
+    pub fn apply(&self, image: FastImage) -> PicturifyResult<FastImage> {
+        if self.identity {
+            return Ok(image);
+        }
+
+        let (image, _) = self.apply_precise(image)?;
+        Ok(image)
+    }
+
+    // Like apply, also returns the converted values of every pixel before they were rounded
+    pub fn apply_precise(
+        &self,
+        mut image: FastImage,
+    ) -> PicturifyResult<(FastImage, Vec<[f32; 3]>)> {
+        let values = color_values(&image);
+        if self.identity {
+            return Ok((image, values));
+        }
+
+        let precise = self.convert(&values)?;
+        Self::write_values(&mut image, &precise);
+
+        Ok((image, precise))
+    }
+
+    // Converts an image made by apply_precise back. Channels that still hold their rounded
+    // value start from the precise one instead, so a round trip through a space with coarse
+    // 8-bit steps, like linear light, stays within a step of the original.
+    pub fn apply_restoring(
+        &self,
+        mut image: FastImage,
+        precise: &[[f32; 3]],
+    ) -> PicturifyResult<FastImage> {
+        let (width, height): (usize, usize) = image.size().into();
+        if self.identity || precise.len() != width * height {
+            return self.apply(image);
+        }
+
+        let values: Vec<[f32; 3]> = image
+            .to_rgba_vec()
+            .par_chunks(4)
+            .zip(precise)
+            .map(|(pixel, precise)| {
+                [0, 1, 2].map(
+                    |channel| match pixel[channel] == round_channel(precise[channel]) {
+                        true => precise[channel],
+                        false => pixel[channel] as f32,
+                    },
+                )
+            })
+            .collect();
+        let converted = self.convert(&values)?;
+        Self::write_values(&mut image, &converted);
+
+        Ok(image)
+    }
+}
+
+// both are matrix/TRC profiles and converting between their colorants changes nothing
+fn same_colorants(source: &ColorProfile, target: &ColorProfile) -> bool {
+    let (source, target) = (&source.profile, &target.profile);
+    let has_lut = |profile: &moxcms::ColorProfile| {
+        profile.lut_a_to_b_perceptual.is_some()
+            || profile.lut_a_to_b_colorimetric.is_some()
+            || profile.lut_a_to_b_saturation.is_some()
+    };
+    if !source.is_matrix_shaper() || !target.is_matrix_shaper() || has_lut(source) {
+        return false;
+    }
+
+    let matrix = source.transform_matrix(target);
+    (0..3).all(|i| {
+        (0..3).all(|j| {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            (matrix.v[i][j] - expected).abs() < IDENTITY_TOLERANCE
+        })
+    })
+}
+
+fn color_values(image: &FastImage) -> Vec<[f32; 3]> {
+    image
+        .to_rgba_vec()
+        .par_chunks(4)
+        .map(|pixel| [0, 1, 2].map(|channel| pixel[channel] as f32))
+        .collect()
+}
+
+#[inline(always)]
+fn round_channel(value: f32) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+pub fn read_icc_profile_from_file<P>(path: P) -> PicturifyResult<Option<Vec<u8>>>
+where
+    P: AsRef<Path>,
+{
+    let reader = Reader::open(path)?.with_guessed_format()?;
+    Ok(reader.into_decoder()?.icc_profile()?)
+}
+
+pub fn read_icc_profile_from_bytes(bytes: &[u8]) -> PicturifyResult<Option<Vec<u8>>> {
+    let reader = Reader::new(Cursor::new(bytes)).with_guessed_format()?;
+    Ok(reader.into_decoder()?.icc_profile()?)
+}
+
+fn invalid_profile(reason: &str) -> PicturifyError {
+    PicturifyError::InvalidIccProfile(reason.to_string())
+}
+
+pub fn xy_to_xyz((x, y): (f32, f32)) -> [f32; 3] {
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+pub fn bradford_adaptation(source_white: [f32; 3], target_white: [f32; 3]) -> Matrix {
+    let source = apply(&BRADFORD, source_white);
+    let target = apply(&BRADFORD, target_white);
+    let scale = [
+        [target[0] / source[0], 0.0, 0.0],
+        [0.0, target[1] / source[1], 0.0],
+        [0.0, 0.0, target[2] / source[2]],
+    ];
+
+    multiply(&invert(&BRADFORD), &multiply(&scale, &BRADFORD))
+}
+
+pub fn apply(matrix: &Matrix, vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+pub fn multiply(left: &Matrix, right: &Matrix) -> Matrix {
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| left[i][k] * right[k][j]).sum();
+        }
+    }
+
+    result
+}
+
+pub fn invert(m: &Matrix) -> Matrix {
+    let cofactor =
+        |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let adjugate = [
+        [
+            cofactor(1, 2, 1, 2),
+            -cofactor(0, 2, 1, 2),
+            cofactor(0, 1, 1, 2),
+        ],
+        [
+            -cofactor(1, 2, 0, 2),
+            cofactor(0, 2, 0, 2),
+            -cofactor(0, 1, 0, 2),
+        ],
+        [
+            cofactor(1, 2, 0, 1),
+            -cofactor(0, 2, 0, 1),
+            cofactor(0, 1, 0, 1),
+        ],
+    ];
+    let determinant =
+        m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+
+    adjugate.map(|row| row.map(|value| value / determinant))
+}
@@ -1 +1,2 @@
+pub mod icc;
 pub mod image_palette_bridge;
@@ -0,0 +1,358 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+use image::{AnimationDecoder, Delay, Frames, ImageFormat};
+
+use crate::core::fast_image::FastImage;
+use crate::core::indexed_image::{gif_size, write_gif, IndexedImage};
+use crate::core::io::{ReadFromFile, WriteToFile};
+use crate::error::{PicturifyError, PicturifyResult};
+use crate::geometry::size::Size;
+
+// NeuQuant sampling used for gif frames that do not fit a palette, 1 is best and 30 fastest
+const GIF_QUANTIZATION_SPEED: i32 = 10;
+
+// What happens to the canvas once a frame has been shown
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FrameDisposal {
+    Keep,
+    #[default]
+    Background,
+    Previous,
+}
+
+// Number of times the whole animation is played
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LoopCount {
+    #[default]
+    Infinite,
+    Finite(u32),
+}
+
+#[derive(Debug, Clone)]
+pub struct AnimationFrame {
+    pub image: FastImage,
+    pub delay: Delay,
+    pub disposal: FrameDisposal,
+}
+
+impl AnimationFrame {
+    pub fn delay_ms(&self) -> u32 {
+        let (numerator, denominator) = self.delay.numer_denom_ms();
+        (numerator as f64 / denominator.max(1) as f64).round() as u32
+    }
+}
+
+// Frames are stored composited onto the full canvas, so disposal of the source is already
+// applied and every frame can be processed like a still image. The disposal is still kept
+// per frame so it can be written back.
+#[derive(Debug, Clone)]
+pub struct AnimatedImage {
+    frames: Vec<AnimationFrame>,
+    loop_count: LoopCount,
+}
+
+impl AnimatedImage {
+    pub fn new(frames: Vec<AnimationFrame>, loop_count: LoopCount) -> PicturifyResult<Self> {
+        let size = frames
+            .first()
+            .map(|frame| frame.image.size())
+            .ok_or(PicturifyError::InvalidImageFormat)?;
+        if frames.iter().any(|frame| frame.image.size() != size) {
+            return Err(PicturifyError::InvalidImageFormat);
+        }
+
+        Ok(Self { frames, loop_count })
+    }
+
+    pub fn size(&self) -> Size {
+        self.frames[0].image.size()
+    }
+
+    pub fn frames(&self) -> &[AnimationFrame] {
+        &self.frames
+    }
+
+    pub fn loop_count(&self) -> LoopCount {
+        self.loop_count
+    }
+
+    pub fn into_frames(self) -> Vec<AnimationFrame> {
+        self.frames
+    }
+
+    // same frames in the same palette where possible, otherwise every frame is quantized alone
+    pub fn write_gif<W: Write>(&self, writer: W) -> PicturifyResult<()> {
+        let delays: Vec<u16> = self
+            .frames
+            .iter()
+            .map(|frame| (frame.delay_ms().div_ceil(10)).min(u16::MAX as u32) as u16)
+            .collect();
+        let disposals: Vec<gif::DisposalMethod> = self
+            .frames
+            .iter()
+            .map(|frame| gif_disposal(frame.disposal))
+            .collect();
+        let repeat = gif_repeat(self.loop_count);
+        let images: Vec<FastImage> = self
+            .frames
+            .iter()
+            .map(|frame| frame.image.clone())
+            .collect();
+
+        let indexed = IndexedImage::from_frames_exact(&images).or_else(|_| {
+            images
+                .iter()
+                .map(IndexedImage::from_fast_image_exact)
+                .collect()
+        });
+        if let Ok(indexed) = indexed {
+            return write_gif(writer, &indexed, &delays, &disposals, repeat);
+        }
+
+        let (width, height) = gif_size(self.size())?;
+        let mut encoder = gif::Encoder::new(writer, width, height, &[])?;
+        encoder.set_repeat(repeat)?;
+        for ((image, delay), disposal) in images.iter().zip(delays).zip(disposals) {
+            let mut rgba = image.to_rgba_vec();
+            let mut frame =
+                gif::Frame::from_rgba_speed(width, height, &mut rgba, GIF_QUANTIZATION_SPEED);
+            frame.delay = delay;
+            frame.dispose = disposal;
+            encoder.write_frame(&frame)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_apng<W: Write>(&self, writer: W) -> PicturifyResult<()> {
+        let (width, height): (usize, usize) = self.size().into();
+        let mut encoder = png::Encoder::new(writer, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        // zero plays loops forever
+        let plays = match self.loop_count {
+            LoopCount::Infinite => 0,
+            LoopCount::Finite(plays) => plays.max(1),
+        };
+        encoder.set_animated(self.frames.len() as u32, plays)?;
+
+        let mut writer = encoder.write_header()?;
+        for frame in &self.frames {
+            writer.set_frame_delay(frame.delay_ms().min(u16::MAX as u32) as u16, 1000)?;
+            writer.set_dispose_op(match frame.disposal {
+                FrameDisposal::Keep => png::DisposeOp::None,
+                FrameDisposal::Background => png::DisposeOp::Background,
+                FrameDisposal::Previous => png::DisposeOp::Previous,
+            })?;
+            writer.set_blend_op(png::BlendOp::Source)?;
+            writer.write_image_data(&frame.image.to_rgba_vec())?;
+        }
+        writer.finish()?;
+
+        Ok(())
+    }
+}
+
+impl From<FastImage> for AnimatedImage {
+    fn from(image: FastImage) -> Self {
+        Self {
+            frames: vec![AnimationFrame {
+                image,
+                delay: Delay::from_numer_denom_ms(0, 1),
+                disposal: FrameDisposal::default(),
+            }],
+            loop_count: LoopCount::default(),
+        }
+    }
+}
+
+// true for gif, apng and webp files holding more than a single frame
+pub fn is_animated<P: AsRef<Path>>(path: P) -> PicturifyResult<bool> {
+    let path = path.as_ref();
+    let reader = || File::open(path).map(BufReader::new);
+    let is_animated = match ImageFormat::from_path(path) {
+        Ok(ImageFormat::Gif) => GifDecoder::new(reader()?)?.into_frames().take(2).count() > 1,
+        Ok(ImageFormat::Png) => PngDecoder::new(reader()?)?.is_apng()?,
+        Ok(ImageFormat::WebP) => WebPDecoder::new(reader()?)?.has_animation(),
+        _ => false,
+    };
+
+    Ok(is_animated)
+}
+
+// animated webp can be read but not written, there is no animated webp encoder available
+pub fn supports_animated_output<P: AsRef<Path>>(path: P) -> bool {
+    matches!(
+        ImageFormat::from_path(path),
+        Ok(ImageFormat::Gif) | Ok(ImageFormat::Png)
+    )
+}
+
+impl ReadFromFile for AnimatedImage {
+    fn read_from_file<P>(path: P) -> PicturifyResult<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let reader = || File::open(path).map(BufReader::new);
+        // the image decoders composite the frames but drop disposal and loop count, those are
+        // read from the gif and apng chunks, webp keeps the defaults
+        let frames = match ImageFormat::from_path(path) {
+            Ok(ImageFormat::Gif) => Some((
+                GifDecoder::new(reader()?)?.into_frames(),
+                read_gif_metadata(reader()?)?,
+            )),
+            Ok(ImageFormat::Png) => {
+                let decoder = PngDecoder::new(reader()?)?;
+                match decoder.is_apng()? {
+                    true => Some((
+                        decoder.apng()?.into_frames(),
+                        read_apng_metadata(reader()?)?,
+                    )),
+                    false => None,
+                }
+            }
+            Ok(ImageFormat::WebP) => {
+                let decoder = WebPDecoder::new(reader()?)?;
+                match decoder.has_animation() {
+                    true => Some((decoder.into_frames(), Metadata::default())),
+                    false => None,
+                }
+            }
+            _ => None,
+        };
+
+        match frames {
+            Some((frames, metadata)) => {
+                Self::new(collect_frames(frames, &metadata)?, metadata.loop_count)
+            }
+            None => Ok(FastImage::read_from_file(path)?.into()),
+        }
+    }
+}
+
+impl WriteToFile for AnimatedImage {
+    fn write_to_file<P>(&self, path: P) -> PicturifyResult<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let writer = || File::create(path).map(BufWriter::new);
+        match ImageFormat::from_path(path)? {
+            ImageFormat::Gif => self.write_gif(writer()?),
+            ImageFormat::Png => self.write_apng(writer()?),
+            _ => Err(PicturifyError::InvalidImageFormat),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Metadata {
+    disposals: Vec<FrameDisposal>,
+    loop_count: LoopCount,
+}
+
+fn read_gif_metadata<R: Read>(reader: R) -> PicturifyResult<Metadata> {
+    let mut options = gif::DecodeOptions::new();
+    options.skip_frame_decoding(true);
+    let mut decoder = options.read_info(reader)?;
+
+    let mut disposals = Vec::new();
+    while let Some(frame) = decoder.next_frame_info()? {
+        disposals.push(match frame.dispose {
+            gif::DisposalMethod::Any | gif::DisposalMethod::Keep => FrameDisposal::Keep,
+            gif::DisposalMethod::Background => FrameDisposal::Background,
+            gif::DisposalMethod::Previous => FrameDisposal::Previous,
+        });
+    }
+
+    // the netscape extension counts repeats after the first play, without it a gif plays once
+    let loop_count = match decoder.repeat() {
+        gif::Repeat::Infinite => LoopCount::Infinite,
+        gif::Repeat::Finite(repeats) => LoopCount::Finite(repeats as u32 + 1),
+    };
+
+    Ok(Metadata {
+        disposals,
+        loop_count,
+    })
+}
+
+fn read_apng_metadata<R: Read>(reader: R) -> PicturifyResult<Metadata> {
+    let mut reader = png::Decoder::new(reader).read_info()?;
+    let (frame_count, plays) = reader
+        .info()
+        .animation_control
+        .map(|control| (control.num_frames, control.num_plays))
+        .unwrap_or_default();
+
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    // a default image without a frame control is not part of the animation
+    if reader.info().frame_control.is_none() {
+        reader.next_frame(&mut buffer)?;
+    }
+    let mut disposals = Vec::new();
+    for _ in 0..frame_count {
+        reader.next_frame(&mut buffer)?;
+        disposals.push(
+            match reader
+                .info()
+                .frame_control
+                .map(|control| control.dispose_op)
+            {
+                Some(png::DisposeOp::None) => FrameDisposal::Keep,
+                Some(png::DisposeOp::Previous) => FrameDisposal::Previous,
+                _ => FrameDisposal::Background,
+            },
+        );
+    }
+
+    let loop_count = match plays {
+        0 => LoopCount::Infinite,
+        plays => LoopCount::Finite(plays),
+    };
+
+    Ok(Metadata {
+        disposals,
+        loop_count,
+    })
+}
+
+fn gif_disposal(disposal: FrameDisposal) -> gif::DisposalMethod {
+    match disposal {
+        FrameDisposal::Keep => gif::DisposalMethod::Keep,
+        FrameDisposal::Background => gif::DisposalMethod::Background,
+        FrameDisposal::Previous => gif::DisposalMethod::Previous,
+    }
+}
+
+fn gif_repeat(loop_count: LoopCount) -> gif::Repeat {
+    match loop_count {
+        LoopCount::Infinite => gif::Repeat::Infinite,
+        LoopCount::Finite(plays) => {
+            gif::Repeat::Finite(plays.saturating_sub(1).min(u16::MAX as u32) as u16)
+        }
+    }
+}
+
+fn collect_frames(frames: Frames, metadata: &Metadata) -> PicturifyResult<Vec<AnimationFrame>> {
+    frames
+        .enumerate()
+        .map(|(index, frame)| {
+            let frame = frame?;
+            let delay = frame.delay();
+            let buffer = frame.into_buffer();
+            let size = (buffer.width(), buffer.height()).into();
+            Ok(AnimationFrame {
+                image: FastImage::from_rgba_vec(size, buffer.into_raw()),
+                delay,
+                disposal: metadata.disposals.get(index).copied().unwrap_or_default(),
+            })
+        })
+        .collect()
+}
@@ -201,3 +201,29 @@ pub trait ApplyFnToImagePixels {
     ) where
         F: Fn(&mut Rgba<u8>, Coord) + Send + Sync;
 }
+
+pub trait ApplyFnToPlanePixels<T> {
+    fn apply_fn_to_plane_pixel<F>(&mut self, f: F, progress: Option<Progress>)
+    where
+        F: Fn(&mut T, Coord);
+
+    fn par_apply_fn_to_plane_pixel<F>(&mut self, f: F, progress: Option<Progress>)
+    where
+        F: Fn(&mut T, Coord) + Send + Sync;
+
+    fn apply_fn_to_plane_pixel_with_offset<F>(
+        &mut self,
+        f: F,
+        progress: Option<Progress>,
+        offset: Offset,
+    ) where
+        F: Fn(&mut T, Coord);
+
+    fn par_apply_fn_to_plane_pixel_with_offset<F>(
+        &mut self,
+        f: F,
+        progress: Option<Progress>,
+        offset: Offset,
+    ) where
+        F: Fn(&mut T, Coord) + Send + Sync;
+}
@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs::{read_to_string, write};
+use std::path::Path;
+
+use palette::{FromColor, Lab, Srgb};
+use serde::{Deserialize, Serialize};
+
+use crate::core::fast_image::FastImage;
+use crate::core::io::{ReadFromFile, WriteToFile};
+use crate::error::{PicturifyError, PicturifyResult};
+
+pub const MAX_PALETTE_SIZE: usize = 256;
+
+const GIMP_PALETTE_HEADER: &str = "GIMP Palette";
+
+// Up to 256 opaque sRGB colors
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColorPalette {
+    colors: Vec<[u8; 3]>,
+}
+
+impl ColorPalette {
+    pub fn new(colors: Vec<[u8; 3]>) -> PicturifyResult<Self> {
+        if colors.is_empty() || colors.len() > MAX_PALETTE_SIZE {
+            return Err(invalid_palette(&format!(
+                "expected 1 to {} colors, got {}",
+                MAX_PALETTE_SIZE,
+                colors.len()
+            )));
+        }
+
+        Ok(Self { colors })
+    }
+
+    pub fn colors(&self) -> &[[u8; 3]] {
+        &self.colors
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    pub fn lab_colors(&self) -> Vec<[f32; 3]> {
+        self.colors
+            .iter()
+            .map(|color| srgb_to_lab(color.map(|channel| channel as f32)))
+            .collect()
+    }
+
+    // GIMP .gpl palette, color lines are "<r> <g> <b> [name]"
+    pub fn from_gpl(text: &str) -> PicturifyResult<Self> {
+        let mut lines = text.lines();
+        if lines.next().map(str::trim) != Some(GIMP_PALETTE_HEADER) {
+            return Err(invalid_palette("missing GIMP Palette header"));
+        }
+
+        let mut colors = Vec::new();
+        for line in lines {
+            let line = line.trim();
+            if line.is_empty()
+                || line.starts_with('#')
+                || line.starts_with("Name:")
+                || line.starts_with("Columns:")
+            {
+                continue;
+            }
+
+            let mut color = [0; 3];
+            let mut words = line.split_whitespace();
+            for channel in color.iter_mut() {
+                *channel = words
+                    .next()
+                    .and_then(|word| word.parse::<u8>().ok())
+                    .ok_or_else(|| invalid_palette(&format!("invalid color line: {}", line)))?;
+            }
+            colors.push(color);
+        }
+
+        Self::new(colors)
+    }
+
+    // one #rrggbb color per line
+    pub fn from_hex(text: &str) -> PicturifyResult<Self> {
+        let colors = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let hex = line.strip_prefix('#').unwrap_or(line);
+                if hex.len() != 6 || !hex.is_ascii() {
+                    return Err(invalid_palette(&format!("invalid hex color: {}", line)));
+                }
+                let mut color = [0; 3];
+                for (i, channel) in color.iter_mut().enumerate() {
+                    *channel = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16)
+                        .map_err(|_| invalid_palette(&format!("invalid hex color: {}", line)))?;
+                }
+                Ok(color)
+            })
+            .collect::<PicturifyResult<Vec<_>>>()?;
+
+        Self::new(colors)
+    }
+
+    // every distinct color of the image, in order of appearance
+    pub fn from_image(image: &FastImage) -> PicturifyResult<Self> {
+        let mut seen = HashSet::new();
+        let mut colors = Vec::new();
+        for pixel in image.pixels() {
+            let color = [pixel.0[0], pixel.0[1], pixel.0[2]];
+            if seen.insert(color) {
+                colors.push(color);
+                if colors.len() > MAX_PALETTE_SIZE {
+                    break;
+                }
+            }
+        }
+
+        Self::new(colors)
+    }
+
+    pub fn to_gpl(&self, name: &str) -> String {
+        let mut text = format!("{}\nName: {}\nColumns: 16\n#\n", GIMP_PALETTE_HEADER, name);
+        for [red, green, blue] in &self.colors {
+            let _ = writeln!(text, "{:3} {:3} {:3}", red, green, blue);
+        }
+        text
+    }
+
+    pub fn to_hex(&self) -> String {
+        let mut text = String::new();
+        for [red, green, blue] in &self.colors {
+            let _ = writeln!(text, "#{:02x}{:02x}{:02x}", red, green, blue);
+        }
+        text
+    }
+}
+
+impl ReadFromFile for ColorPalette {
+    fn read_from_file<P>(path: P) -> PicturifyResult<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        match extension(path).as_deref() {
+            Some("gpl") => Self::from_gpl(&read_to_string(path)?),
+            Some("hex") | Some("txt") => Self::from_hex(&read_to_string(path)?),
+            _ => Self::from_image(&FastImage::read_from_file(path)?),
+        }
+    }
+}
+
+impl WriteToFile for ColorPalette {
+    fn write_to_file<P>(&self, path: P) -> PicturifyResult<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let text = match extension(path).as_deref() {
+            Some("gpl") => {
+                let name = path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                self.to_gpl(&name)
+            }
+            _ => self.to_hex(),
+        };
+
+        Ok(write(path, text)?)
+    }
+}
+
+// palette colors are compared in Lab, so nearest means perceptually nearest
+pub fn srgb_to_lab(color: [f32; 3]) -> [f32; 3] {
+    let lab = Lab::from_color(Srgb::new(color[0], color[1], color[2]) / 255.0);
+    [lab.l, lab.a, lab.b]
+}
+
+pub fn distance_squared(a: [f32; 3], b: [f32; 3]) -> f32 {
+    (0..3)
+        .map(|channel| (a[channel] - b[channel]).powi(2))
+        .sum()
+}
+
+pub fn nearest(centers: &[[f32; 3]], color: [f32; 3]) -> usize {
+    let mut best = (0, f32::MAX);
+    for (index, center) in centers.iter().enumerate() {
+        let distance = distance_squared(*center, color);
+        if distance < best.1 {
+            best = (index, distance);
+        }
+    }
+    best.0
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+}
+
+fn invalid_palette(reason: &str) -> PicturifyError {
+    PicturifyError::InvalidPalette(reason.to_string())
+}
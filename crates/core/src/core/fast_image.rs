@@ -1,19 +1,23 @@
 use image::buffer::{EnumeratePixels, Pixels, PixelsMut, Rows, RowsMut};
-use image::io::Reader;
+use image::io::{Limits, Reader};
 use image::{DynamicImage, ImageFormat, Rgba, RgbaImage};
 use palette::{LinSrgba, Srgba};
 use rayon::prelude::*;
+use std::io::Cursor;
 use std::path::Path;
 
 use crate::conversions::image_palette_bridge::{rgba_to_srgba, srgba_to_rgba};
 use crate::core::apply_fn_to_pixels::{ApplyFnToImagePixels, ApplyFnToPalettePixels, Offset};
-use crate::core::io::{ReadFromFile, WriteToFile};
+use crate::core::io::{ReadFromBytes, ReadFromFile, WriteToBytes, WriteToFile};
 use crate::core::read_pixels::ReadPixels;
-use crate::error::PicturifyResult;
+use crate::error::{PicturifyError, PicturifyResult};
 use crate::geometry::coord::Coord;
 use crate::geometry::size::Size;
 use crate::threading::progress::{Progress, ProgressIteratorExt};
 
+// large enough for any real picture, a corrupt header asking for more fails instead of aborting
+const MAX_DECODE_ALLOCATION: u64 = 4 * 1024 * 1024 * 1024;
+
 #[derive(Debug, Clone)]
 pub struct FastImage {
     inner: RgbaImage,
@@ -457,7 +461,21 @@ impl ReadFromFile for FastImage {
         P: AsRef<Path>,
     {
         let mut reader = Reader::open(path)?;
-        reader.no_limits();
+        reader.limits(decode_limits());
+        let dynamic_image = reader.decode()?;
+        Ok(FastImage {
+            inner: dynamic_image.into_rgba8(),
+        })
+    }
+}
+
+impl ReadFromBytes for FastImage {
+    fn read_from_bytes(bytes: &[u8]) -> PicturifyResult<Self> {
+        let mut reader = Reader::new(Cursor::new(bytes)).with_guessed_format()?;
+        if reader.format().is_none() {
+            return Err(PicturifyError::InvalidImageFormat);
+        }
+        reader.limits(decode_limits());
         let dynamic_image = reader.decode()?;
         Ok(FastImage {
             inner: dynamic_image.into_rgba8(),
@@ -472,26 +490,8 @@ impl WriteToFile for FastImage {
     {
         let path = path.as_ref();
         let format = ImageFormat::from_path(path)?;
-        let supports_alpha = match format {
-            ImageFormat::Png => true,
-            ImageFormat::Jpeg => false,
-            ImageFormat::Gif => true,
-            ImageFormat::WebP => true,
-            ImageFormat::Pnm => false,
-            ImageFormat::Tiff => true,
-            ImageFormat::Tga => true,
-            ImageFormat::Dds => true,
-            ImageFormat::Bmp => true,
-            ImageFormat::Ico => true,
-            ImageFormat::Hdr => true,
-            ImageFormat::OpenExr => true,
-            ImageFormat::Farbfeld => true,
-            ImageFormat::Avif => true,
-            ImageFormat::Qoi => true,
-            _ => unreachable!("Unsupported image format"),
-        };
 
-        if supports_alpha {
+        if supports_alpha(format)? {
             self.inner.save(path)?
         } else {
             DynamicImage::ImageRgba8(self.inner.clone())
@@ -502,3 +502,46 @@ impl WriteToFile for FastImage {
         Ok(())
     }
 }
+
+impl WriteToBytes for FastImage {
+    fn write_to_bytes(&self, format: ImageFormat) -> PicturifyResult<Vec<u8>> {
+        let mut cursor = Cursor::new(Vec::new());
+
+        if supports_alpha(format)? {
+            self.inner.write_to(&mut cursor, format)?
+        } else {
+            DynamicImage::ImageRgba8(self.inner.clone())
+                .to_rgb8()
+                .write_to(&mut cursor, format)?
+        };
+
+        Ok(cursor.into_inner())
+    }
+}
+
+fn decode_limits() -> Limits {
+    let mut limits = Limits::no_limits();
+    limits.max_alloc = Some(MAX_DECODE_ALLOCATION);
+    limits
+}
+
+fn supports_alpha(format: ImageFormat) -> PicturifyResult<bool> {
+    match format {
+        ImageFormat::Png => Ok(true),
+        ImageFormat::Jpeg => Ok(false),
+        ImageFormat::Gif => Ok(true),
+        ImageFormat::WebP => Ok(true),
+        ImageFormat::Pnm => Ok(false),
+        ImageFormat::Tiff => Ok(true),
+        ImageFormat::Tga => Ok(true),
+        ImageFormat::Dds => Ok(true),
+        ImageFormat::Bmp => Ok(true),
+        ImageFormat::Ico => Ok(true),
+        ImageFormat::Hdr => Ok(true),
+        ImageFormat::OpenExr => Ok(true),
+        ImageFormat::Farbfeld => Ok(true),
+        ImageFormat::Avif => Ok(true),
+        ImageFormat::Qoi => Ok(true),
+        _ => Err(PicturifyError::InvalidImageFormat),
+    }
+}
@@ -0,0 +1,288 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::core::color_palette::{nearest, srgb_to_lab, ColorPalette, MAX_PALETTE_SIZE};
+use crate::core::fast_image::FastImage;
+use crate::core::io::{WriteToBytes, WriteToFile};
+use crate::error::{PicturifyError, PicturifyResult};
+use crate::geometry::size::Size;
+use image::ImageFormat;
+
+// pixels with lower alpha are written as the transparent entry
+const ALPHA_THRESHOLD: u8 = 128;
+
+// Palette plus one palette index per pixel, fully transparent pixels use the entry right after
+// the palette colors
+#[derive(Debug, Clone)]
+pub struct IndexedImage {
+    size: Size,
+    palette: ColorPalette,
+    indices: Vec<u8>,
+    transparent_index: Option<u8>,
+}
+
+impl IndexedImage {
+    // maps every pixel to the nearest palette color, so one palette can be shared between frames
+    pub fn from_fast_image(image: &FastImage, palette: &ColorPalette) -> PicturifyResult<Self> {
+        let has_transparency = image.pixels().any(|pixel| pixel.0[3] < ALPHA_THRESHOLD);
+        let transparent_index = if has_transparency {
+            if palette.len() >= MAX_PALETTE_SIZE {
+                return Err(PicturifyError::InvalidPalette(
+                    "no free palette entry left for transparency".to_string(),
+                ));
+            }
+            Some(palette.len() as u8)
+        } else {
+            None
+        };
+
+        let palette_lab = palette.lab_colors();
+        let mut cache: HashMap<[u8; 3], u8> = HashMap::new();
+        let indices = image
+            .pixels()
+            .map(|pixel| match transparent_index {
+                Some(index) if pixel.0[3] < ALPHA_THRESHOLD => index,
+                _ => {
+                    let color = [pixel.0[0], pixel.0[1], pixel.0[2]];
+                    *cache
+                        .entry(color)
+                        .or_insert_with(|| nearest_index(&palette_lab, color))
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            size: image.size(),
+            palette: palette.clone(),
+            indices,
+            transparent_index,
+        })
+    }
+
+    // lossless conversion, fails when the image has too many colors for a palette
+    pub fn from_fast_image_exact(image: &FastImage) -> PicturifyResult<Self> {
+        Self::from_fast_image(image, &exact_palette(std::slice::from_ref(image))?)
+    }
+
+    // lossless conversion of several frames sharing a single palette
+    pub fn from_frames_exact(images: &[FastImage]) -> PicturifyResult<Vec<Self>> {
+        let palette = exact_palette(images)?;
+        images
+            .iter()
+            .map(|image| Self::from_fast_image(image, &palette))
+            .collect()
+    }
+
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    pub fn palette(&self) -> &ColorPalette {
+        &self.palette
+    }
+
+    pub fn indices(&self) -> &[u8] {
+        &self.indices
+    }
+
+    pub fn transparent_index(&self) -> Option<u8> {
+        self.transparent_index
+    }
+
+    pub fn to_fast_image(&self) -> FastImage {
+        let colors = self.palette.colors();
+        let rgba_vec = self
+            .indices
+            .iter()
+            .flat_map(|&index| match colors.get(index as usize) {
+                Some([red, green, blue]) => [*red, *green, *blue, 255],
+                None => [0, 0, 0, 0],
+            })
+            .collect();
+
+        FastImage::from_rgba_vec(self.size, rgba_vec)
+    }
+
+    // palette as r, g, b triples including the transparent entry
+    fn palette_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self.palette.colors().concat();
+        if self.transparent_index.is_some() {
+            bytes.extend_from_slice(&[0, 0, 0]);
+        }
+        bytes
+    }
+
+    // paletted PNG with the smallest bit depth that fits the palette
+    pub fn write_png<W: Write>(&self, writer: W) -> PicturifyResult<()> {
+        let (width, height): (usize, usize) = self.size.into();
+        let palette = self.palette_bytes();
+        let entries = palette.len() / 3;
+        let bits: usize = match entries {
+            0..=2 => 1,
+            3..=4 => 2,
+            5..=16 => 4,
+            _ => 8,
+        };
+
+        let mut encoder = png::Encoder::new(writer, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(match bits {
+            1 => png::BitDepth::One,
+            2 => png::BitDepth::Two,
+            4 => png::BitDepth::Four,
+            _ => png::BitDepth::Eight,
+        });
+        encoder.set_palette(palette);
+        if let Some(index) = self.transparent_index {
+            // entries after the last tRNS value are opaque
+            let mut trns = vec![255; index as usize + 1];
+            trns[index as usize] = 0;
+            encoder.set_trns(trns);
+        }
+
+        let mut data = Vec::with_capacity((width * bits).div_ceil(8) * height);
+        for row in self.indices.chunks(width.max(1)) {
+            let mut byte = 0u8;
+            let mut filled = 0;
+            for &index in row {
+                byte |= index << (8 - bits - filled);
+                filled += bits;
+                if filled == 8 {
+                    data.push(byte);
+                    byte = 0;
+                    filled = 0;
+                }
+            }
+            if filled > 0 {
+                data.push(byte);
+            }
+        }
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&data)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    pub fn write_gif<W: Write>(&self, writer: W) -> PicturifyResult<()> {
+        write_gif(
+            writer,
+            std::slice::from_ref(self),
+            &[0],
+            &[gif::DisposalMethod::Keep],
+            gif::Repeat::Finite(0),
+        )
+    }
+}
+
+// Frames sharing the first frame's palette use the global color table, any other frame
+// carries its own local table. Delays are in hundredths of a second.
+pub fn write_gif<W: Write>(
+    writer: W,
+    frames: &[IndexedImage],
+    delays: &[u16],
+    disposals: &[gif::DisposalMethod],
+    repeat: gif::Repeat,
+) -> PicturifyResult<()> {
+    let first = frames.first().ok_or(PicturifyError::InvalidImageFormat)?;
+    let (width, height) = gif_size(first.size)?;
+    let global_palette = first.palette_bytes();
+
+    let mut encoder = gif::Encoder::new(writer, width, height, &global_palette)?;
+    encoder.set_repeat(repeat)?;
+    for (index, frame) in frames.iter().enumerate() {
+        let (frame_width, frame_height) = gif_size(frame.size)?;
+        let palette = frame.palette_bytes();
+        encoder.write_frame(&gif::Frame {
+            width: frame_width,
+            height: frame_height,
+            delay: delays.get(index).copied().unwrap_or_default(),
+            dispose: disposals
+                .get(index)
+                .copied()
+                .unwrap_or(gif::DisposalMethod::Keep),
+            transparent: frame.transparent_index,
+            palette: (palette != global_palette).then_some(palette),
+            buffer: Cow::Borrowed(&frame.indices),
+            ..gif::Frame::default()
+        })?;
+    }
+
+    Ok(())
+}
+
+impl WriteToFile for IndexedImage {
+    fn write_to_file<P>(&self, path: P) -> PicturifyResult<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        let writer = || File::create(path).map(BufWriter::new);
+
+        match extension.as_deref() {
+            Some("png") => self.write_png(writer()?),
+            Some("gif") => self.write_gif(writer()?),
+            _ => Err(PicturifyError::InvalidImageFormat),
+        }
+    }
+}
+
+impl WriteToBytes for IndexedImage {
+    fn write_to_bytes(&self, format: ImageFormat) -> PicturifyResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        match format {
+            ImageFormat::Png => self.write_png(&mut bytes)?,
+            ImageFormat::Gif => self.write_gif(&mut bytes)?,
+            _ => return Err(PicturifyError::InvalidImageFormat),
+        }
+        Ok(bytes)
+    }
+}
+
+// every visible color of the images, fails when they do not fit a palette
+fn exact_palette(images: &[FastImage]) -> PicturifyResult<ColorPalette> {
+    let mut seen = HashSet::new();
+    let mut colors = Vec::new();
+    let visible_pixels = images
+        .iter()
+        .flat_map(|image| image.pixels())
+        .filter(|pixel| pixel.0[3] >= ALPHA_THRESHOLD);
+    for pixel in visible_pixels {
+        let color = [pixel.0[0], pixel.0[1], pixel.0[2]];
+        if seen.insert(color) {
+            colors.push(color);
+            if colors.len() > MAX_PALETTE_SIZE {
+                return Err(PicturifyError::InvalidPalette(format!(
+                    "image has more than {} colors, quantize it first",
+                    MAX_PALETTE_SIZE
+                )));
+            }
+        }
+    }
+    if colors.is_empty() {
+        colors.push([0, 0, 0]);
+    }
+
+    ColorPalette::new(colors)
+}
+
+pub(crate) fn gif_size(size: Size) -> PicturifyResult<(u16, u16)> {
+    let (width, height): (usize, usize) = size.into();
+    match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(width), Ok(height)) => Ok((width, height)),
+        _ => Err(PicturifyError::InvalidImageFormat),
+    }
+}
+
+fn nearest_index(palette_lab: &[[f32; 3]], color: [u8; 3]) -> u8 {
+    nearest(
+        palette_lab,
+        srgb_to_lab(color.map(|channel| channel as f32)),
+    ) as u8
+}
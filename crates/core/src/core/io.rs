@@ -1,4 +1,5 @@
 use crate::error::PicturifyResult;
+use image::ImageFormat;
 use std::path::Path;
 
 pub trait ReadFromFile
@@ -15,3 +16,14 @@ pub trait WriteToFile {
     where
         P: AsRef<Path>;
 }
+
+pub trait ReadFromBytes
+where
+    Self: Sized,
+{
+    fn read_from_bytes(bytes: &[u8]) -> PicturifyResult<Self>;
+}
+
+pub trait WriteToBytes {
+    fn write_to_bytes(&self, format: ImageFormat) -> PicturifyResult<Vec<u8>>;
+}
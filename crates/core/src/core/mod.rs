@@ -1,5 +1,10 @@
+pub mod animation;
 pub mod apply_fn_to_pixels;
+pub mod color_palette;
 pub mod fast_image;
+pub mod indexed_image;
 pub mod io;
+pub mod plane_image;
 pub mod read_pixels;
+pub mod streaming;
 pub mod util;
@@ -0,0 +1,316 @@
+use image::Rgba;
+use rayon::prelude::*;
+
+use crate::core::apply_fn_to_pixels::{ApplyFnToPlanePixels, Offset};
+use crate::core::fast_image::FastImage;
+use crate::error::{PicturifyError, PicturifyResult};
+use crate::geometry::coord::Coord;
+use crate::geometry::size::Size;
+use crate::threading::progress::{Progress, ProgressIteratorExt};
+
+// Value stored in a plane, converted to and from 8 bit channels so every type covers the same
+// range. Floats use 0-1 and are not clamped until converted back.
+pub trait PlaneValue: Copy + Default + PartialOrd + Send + Sync {
+    fn from_u8(value: u8) -> Self;
+    fn to_u8(self) -> u8;
+}
+
+impl PlaneValue for u8 {
+    fn from_u8(value: u8) -> Self {
+        value
+    }
+
+    fn to_u8(self) -> u8 {
+        self
+    }
+}
+
+impl PlaneValue for u16 {
+    fn from_u8(value: u8) -> Self {
+        value as u16 * 257
+    }
+
+    fn to_u8(self) -> u8 {
+        ((self as u32 + 128) / 257) as u8
+    }
+}
+
+impl PlaneValue for f32 {
+    fn from_u8(value: u8) -> Self {
+        value as f32 / 255.0
+    }
+
+    fn to_u8(self) -> u8 {
+        (self * 255.0).round().clamp(0.0, 255.0) as u8
+    }
+}
+
+// Single channel image, rows are stored one after another
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaneImage<T> {
+    size: Size,
+    values: Vec<T>,
+}
+
+pub type PlaneImageU8 = PlaneImage<u8>;
+pub type PlaneImageU16 = PlaneImage<u16>;
+pub type PlaneImageF32 = PlaneImage<f32>;
+
+impl<T: PlaneValue> PlaneImage<T> {
+    pub fn empty(size: Size) -> Self {
+        Self::filled(size, T::default())
+    }
+
+    pub fn filled(size: Size, value: T) -> Self {
+        let (width, height): (usize, usize) = size.into();
+        Self {
+            size,
+            values: vec![value; width * height],
+        }
+    }
+
+    pub fn from_vec(size: Size, values: Vec<T>) -> PicturifyResult<Self> {
+        let (width, height): (usize, usize) = size.into();
+        if values.len() != width * height {
+            return Err(PicturifyError::InvalidPlane(format!(
+                "{} values do not fill a {}x{} plane",
+                values.len(),
+                width,
+                height
+            )));
+        }
+        Ok(Self { size, values })
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
+    }
+
+    // one channel of the image, 0 to 3 for red, green, blue and alpha
+    pub fn from_channel(image: &FastImage, channel: usize) -> PicturifyResult<Self> {
+        if channel > 3 {
+            return Err(PicturifyError::InvalidPlane(format!(
+                "channel {} does not exist, expected 0 to 3",
+                channel
+            )));
+        }
+        Ok(Self::channel_of(image, channel))
+    }
+
+    pub fn split(image: &FastImage) -> [Self; 4] {
+        [0, 1, 2, 3].map(|channel| Self::channel_of(image, channel))
+    }
+
+    fn channel_of(image: &FastImage, channel: usize) -> Self {
+        Self {
+            size: image.size(),
+            values: image
+                .pixels()
+                .map(|pixel| T::from_u8(pixel.0[channel]))
+                .collect(),
+        }
+    }
+
+    pub fn merge(planes: &[Self; 4]) -> PicturifyResult<FastImage> {
+        let size = planes[0].size;
+        if planes.iter().any(|plane| plane.size != size) {
+            return Err(PicturifyError::InvalidPlane(
+                "planes differ in size".to_string(),
+            ));
+        }
+
+        let mut image = FastImage::empty(size);
+        image.pixels_mut().enumerate().for_each(|(index, pixel)| {
+            *pixel = Rgba([0, 1, 2, 3].map(|channel| planes[channel].values[index].to_u8()));
+        });
+        Ok(image)
+    }
+
+    // opaque image with the plane in all three color channels
+    pub fn to_gray_image(&self) -> FastImage {
+        let mut image = FastImage::empty(self.size);
+        image
+            .pixels_mut()
+            .zip(&self.values)
+            .for_each(|(pixel, value)| {
+                let value = value.to_u8();
+                *pixel = Rgba([value, value, value, u8::MAX]);
+            });
+        image
+    }
+
+    // writes the plane into the color channels of the image, alpha is kept
+    pub fn write_gray_to(&self, image: &mut FastImage) -> PicturifyResult<()> {
+        if image.size() != self.size {
+            return Err(PicturifyError::InvalidPlane(
+                "plane and image differ in size".to_string(),
+            ));
+        }
+
+        image
+            .pixels_mut()
+            .zip(&self.values)
+            .for_each(|(pixel, value)| {
+                let value = value.to_u8();
+                for channel in pixel.0.iter_mut().take(3) {
+                    *channel = value;
+                }
+            });
+        Ok(())
+    }
+
+    pub fn map<U, F>(&self, f: F) -> PlaneImage<U>
+    where
+        U: PlaneValue,
+        F: Fn(T) -> U + Send + Sync,
+    {
+        PlaneImage {
+            size: self.size,
+            values: self.values.par_iter().map(|value| f(*value)).collect(),
+        }
+    }
+}
+
+impl<T> PlaneImage<T> {
+    pub fn from_fn<F>(size: Size, f: F) -> Self
+    where
+        T: Send,
+        F: Fn(Coord) -> T + Send + Sync,
+    {
+        let (width, height): (usize, usize) = size.into();
+        Self {
+            size,
+            values: (0..width * height)
+                .into_par_iter()
+                .map(|index| f(Coord::from_1d_index(index, width)))
+                .collect(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    #[inline(always)]
+    pub fn width(&self) -> usize {
+        let (width, _): (usize, usize) = self.size.into();
+        width
+    }
+
+    #[inline(always)]
+    pub fn height(&self) -> usize {
+        let (_, height): (usize, usize) = self.size.into();
+        height
+    }
+
+    #[inline(always)]
+    pub fn get(&self, coord: Coord) -> &T {
+        &self.values[coord.array_index(self.width())]
+    }
+
+    #[inline(always)]
+    pub fn set(&mut self, coord: Coord, value: T) {
+        let index = coord.array_index(self.width());
+        self.values[index] = value;
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.values.chunks(self.width().max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> std::slice::ChunksMut<'_, T> {
+        let width = self.width().max(1);
+        self.values.chunks_mut(width)
+    }
+
+    fn full_offset(&self) -> Offset {
+        Offset {
+            skip_rows: 0,
+            take_rows: self.height(),
+            skip_columns: 0,
+            take_columns: self.width(),
+        }
+    }
+}
+
+impl<T: Send + Sync> ApplyFnToPlanePixels<T> for PlaneImage<T> {
+    fn apply_fn_to_plane_pixel<F>(&mut self, f: F, progress: Option<Progress>)
+    where
+        F: Fn(&mut T, Coord),
+    {
+        let offset = self.full_offset();
+        self.apply_fn_to_plane_pixel_with_offset(f, progress, offset);
+    }
+
+    fn par_apply_fn_to_plane_pixel<F>(&mut self, f: F, progress: Option<Progress>)
+    where
+        F: Fn(&mut T, Coord) + Send + Sync,
+    {
+        let offset = self.full_offset();
+        self.par_apply_fn_to_plane_pixel_with_offset(f, progress, offset);
+    }
+
+    fn apply_fn_to_plane_pixel_with_offset<F>(
+        &mut self,
+        f: F,
+        progress: Option<Progress>,
+        offset: Offset,
+    ) where
+        F: Fn(&mut T, Coord),
+    {
+        let mut progress = progress.unwrap_or_default();
+        progress.setup(offset.take_rows);
+        self.rows_mut()
+            .enumerate()
+            .skip(offset.skip_rows)
+            .take(offset.take_rows)
+            .progress(progress)
+            .for_each(|(y, row)| {
+                process_plane_row(row, y, &offset, &f);
+            });
+    }
+
+    fn par_apply_fn_to_plane_pixel_with_offset<F>(
+        &mut self,
+        f: F,
+        progress: Option<Progress>,
+        offset: Offset,
+    ) where
+        F: Fn(&mut T, Coord) + Send + Sync,
+    {
+        let mut progress = progress.unwrap_or_default();
+        progress.setup(offset.take_rows);
+        self.rows_mut()
+            .enumerate()
+            .skip(offset.skip_rows)
+            .take(offset.take_rows)
+            .progress(progress)
+            .par_bridge()
+            .for_each(|(y, row)| {
+                process_plane_row(row, y, &offset, &f);
+            });
+    }
+}
+
+#[inline(always)]
+fn process_plane_row<T, F>(row: &mut [T], y: usize, offset: &Offset, f: &F)
+where
+    F: Fn(&mut T, Coord),
+{
+    row.iter_mut()
+        .enumerate()
+        .skip(offset.skip_columns)
+        .take(offset.take_columns)
+        .for_each(|(x, value)| {
+            f(value, (x, y).into());
+        });
+}
@@ -0,0 +1,289 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+use image::ImageFormat;
+use tiff::decoder::{Decoder as TiffDecoder, DecodingResult};
+use tiff::encoder::{colortype, TiffEncoder};
+use tiff::ColorType as TiffColorType;
+
+use crate::core::fast_image::FastImage;
+use crate::error::{PicturifyError, PicturifyResult};
+use crate::geometry::size::Size;
+
+const RGBA_CHANNELS: usize = 4;
+const TIFF_ROWS_PER_STRIP: usize = 64;
+
+// Reads an image sequentially, a band of rows at a time, without decoding the whole image
+pub trait RowReader {
+    fn size(&self) -> Size;
+    fn read_rows(&mut self, count: usize) -> PicturifyResult<FastImage>;
+}
+
+pub fn open_row_reader<P>(path: P) -> PicturifyResult<Box<dyn RowReader>>
+where
+    P: AsRef<Path>,
+{
+    match ImageFormat::from_path(&path)? {
+        ImageFormat::Png => Ok(Box::new(PngRowReader::open(path)?)),
+        ImageFormat::Tiff => Ok(Box::new(TiffRowReader::open(path)?)),
+        _ => Err(PicturifyError::InvalidImageFormat),
+    }
+}
+
+// Writes an image sequentially, pulling bands of rows from `next_rows` until it returns None
+pub fn write_rows_to_file<P, E, F>(path: P, size: Size, next_rows: F) -> Result<(), E>
+where
+    P: AsRef<Path>,
+    E: From<PicturifyError>,
+    F: FnMut() -> Result<Option<FastImage>, E>,
+{
+    match ImageFormat::from_path(&path).map_err(PicturifyError::from)? {
+        ImageFormat::Png => write_png_rows(path, size, next_rows),
+        ImageFormat::Tiff => write_tiff_rows(path, size, next_rows),
+        _ => Err(PicturifyError::InvalidImageFormat.into()),
+    }
+}
+
+pub struct PngRowReader {
+    reader: png::Reader<BufReader<File>>,
+    size: Size,
+    channels: usize,
+    next_row: usize,
+}
+
+impl PngRowReader {
+    pub fn open<P>(path: P) -> PicturifyResult<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let mut decoder = png::Decoder::new(BufReader::new(File::open(path)?));
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+        let reader = decoder.read_info()?;
+
+        // interlaced images spread every row over multiple passes
+        if reader.info().interlaced {
+            return Err(PicturifyError::InvalidImageFormat);
+        }
+
+        let size = (reader.info().width, reader.info().height).into();
+        let (color_type, _) = reader.output_color_type();
+
+        Ok(Self {
+            reader,
+            size,
+            channels: color_type.samples(),
+            next_row: 0,
+        })
+    }
+}
+
+impl RowReader for PngRowReader {
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn read_rows(&mut self, count: usize) -> PicturifyResult<FastImage> {
+        let (width, height): (usize, usize) = self.size.into();
+        let count = count.min(height - self.next_row);
+        let mut rgba_vec = Vec::with_capacity(width * count * RGBA_CHANNELS);
+
+        for _ in 0..count {
+            let row = self
+                .reader
+                .next_row()?
+                .ok_or(PicturifyError::InvalidImageFormat)?;
+            extend_with_rgba(&mut rgba_vec, row.data(), self.channels)?;
+        }
+        self.next_row += count;
+
+        Ok(FastImage::from_rgba_vec((width, count).into(), rgba_vec))
+    }
+}
+
+pub struct TiffRowReader {
+    decoder: TiffDecoder<BufReader<File>>,
+    size: Size,
+    channels: usize,
+    chunk_size: (usize, usize),
+    chunks_across: usize,
+    band: Vec<u8>,
+    band_start: usize,
+    band_rows: usize,
+    next_row: usize,
+}
+
+impl TiffRowReader {
+    pub fn open<P>(path: P) -> PicturifyResult<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let mut decoder = TiffDecoder::new(BufReader::new(File::open(path)?))?;
+        let size: Size = decoder.dimensions()?.into();
+        let channels = match decoder.colortype()? {
+            TiffColorType::Gray(8 | 16) => 1,
+            TiffColorType::GrayA(8 | 16) => 2,
+            TiffColorType::RGB(8 | 16) => 3,
+            TiffColorType::RGBA(8 | 16) => 4,
+            _ => return Err(PicturifyError::InvalidImageFormat),
+        };
+        let (chunk_width, chunk_height) = decoder.chunk_dimensions();
+        let chunk_size = (chunk_width as usize, chunk_height as usize);
+        let (width, _): (usize, usize) = size.into();
+
+        Ok(Self {
+            decoder,
+            size,
+            channels,
+            chunk_size,
+            chunks_across: width.div_ceil(chunk_size.0),
+            band: Vec::new(),
+            band_start: 0,
+            band_rows: 0,
+            next_row: 0,
+        })
+    }
+
+    // decodes one row of chunks (a strip, or a row of tiles) into the rgba band
+    fn load_band(&mut self, band_index: usize) -> PicturifyResult<()> {
+        let (width, _): (usize, usize) = self.size.into();
+        let (chunk_width, chunk_height) = self.chunk_size;
+        let mut band: Vec<u8> = Vec::new();
+        let mut band_rows = 0;
+
+        for chunk_x in 0..self.chunks_across {
+            let chunk_index = (band_index * self.chunks_across + chunk_x) as u32;
+            let (data_width, data_height) = self.decoder.chunk_data_dimensions(chunk_index);
+            let (data_width, data_height) = (data_width as usize, data_height as usize);
+            let data = match self.decoder.read_chunk(chunk_index)? {
+                DecodingResult::U8(data) => data,
+                DecodingResult::U16(data) => data.into_iter().map(|v| (v >> 8) as u8).collect(),
+                _ => return Err(PicturifyError::InvalidImageFormat),
+            };
+
+            if band_rows == 0 {
+                band_rows = data_height;
+                band = vec![0; width * band_rows * RGBA_CHANNELS];
+            }
+
+            for y in 0..data_height.min(band_rows) {
+                let source =
+                    &data[y * data_width * self.channels..(y + 1) * data_width * self.channels];
+                let mut rgba_row = Vec::with_capacity(data_width * RGBA_CHANNELS);
+                extend_with_rgba(&mut rgba_row, source, self.channels)?;
+
+                let offset = (y * width + chunk_x * chunk_width) * RGBA_CHANNELS;
+                band[offset..offset + rgba_row.len()].copy_from_slice(&rgba_row);
+            }
+        }
+
+        self.band = band;
+        self.band_start = band_index * chunk_height;
+        self.band_rows = band_rows;
+
+        Ok(())
+    }
+}
+
+impl RowReader for TiffRowReader {
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn read_rows(&mut self, count: usize) -> PicturifyResult<FastImage> {
+        let (width, height): (usize, usize) = self.size.into();
+        let count = count.min(height - self.next_row);
+        let row_len = width * RGBA_CHANNELS;
+        let mut rgba_vec = Vec::with_capacity(row_len * count);
+
+        for row in self.next_row..self.next_row + count {
+            if row < self.band_start || row >= self.band_start + self.band_rows {
+                self.load_band(row / self.chunk_size.1)?;
+            }
+            let offset = (row - self.band_start) * row_len;
+            rgba_vec.extend_from_slice(&self.band[offset..offset + row_len]);
+        }
+        self.next_row += count;
+
+        Ok(FastImage::from_rgba_vec((width, count).into(), rgba_vec))
+    }
+}
+
+fn extend_with_rgba(rgba_vec: &mut Vec<u8>, data: &[u8], channels: usize) -> PicturifyResult<()> {
+    match channels {
+        1 => data
+            .iter()
+            .for_each(|&g| rgba_vec.extend_from_slice(&[g, g, g, u8::MAX])),
+        2 => data
+            .chunks_exact(2)
+            .for_each(|p| rgba_vec.extend_from_slice(&[p[0], p[0], p[0], p[1]])),
+        3 => data
+            .chunks_exact(3)
+            .for_each(|p| rgba_vec.extend_from_slice(&[p[0], p[1], p[2], u8::MAX])),
+        4 => rgba_vec.extend_from_slice(data),
+        _ => return Err(PicturifyError::InvalidImageFormat),
+    }
+
+    Ok(())
+}
+
+fn write_png_rows<P, E, F>(path: P, size: Size, mut next_rows: F) -> Result<(), E>
+where
+    P: AsRef<Path>,
+    E: From<PicturifyError>,
+    F: FnMut() -> Result<Option<FastImage>, E>,
+{
+    let (width, height) = size.into();
+    let file = File::create(path).map_err(PicturifyError::from)?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut stream_writer = encoder
+        .write_header()
+        .and_then(|writer| writer.into_stream_writer())
+        .map_err(PicturifyError::from)?;
+
+    while let Some(rows) = next_rows()? {
+        stream_writer
+            .write_all(&rows.to_rgba_vec())
+            .map_err(PicturifyError::from)?;
+    }
+    stream_writer.finish().map_err(PicturifyError::from)?;
+
+    Ok(())
+}
+
+fn write_tiff_rows<P, E, F>(path: P, size: Size, mut next_rows: F) -> Result<(), E>
+where
+    P: AsRef<Path>,
+    E: From<PicturifyError>,
+    F: FnMut() -> Result<Option<FastImage>, E>,
+{
+    let (width, height): (u32, u32) = size.into();
+    let file = File::create(path).map_err(PicturifyError::from)?;
+    let mut encoder = TiffEncoder::new(BufWriter::new(file)).map_err(PicturifyError::from)?;
+    let mut image = encoder
+        .new_image::<colortype::RGBA8>(width, height)
+        .map_err(PicturifyError::from)?;
+    image
+        .rows_per_strip(TIFF_ROWS_PER_STRIP as u32)
+        .map_err(PicturifyError::from)?;
+
+    let strip_len = width as usize * TIFF_ROWS_PER_STRIP * RGBA_CHANNELS;
+    let mut pending = Vec::new();
+    while let Some(rows) = next_rows()? {
+        pending.extend_from_slice(&rows.to_rgba_vec());
+        while pending.len() >= strip_len {
+            image
+                .write_strip(&pending[..strip_len])
+                .map_err(PicturifyError::from)?;
+            pending.drain(..strip_len);
+        }
+    }
+    if !pending.is_empty() {
+        image.write_strip(&pending).map_err(PicturifyError::from)?;
+    }
+    image.finish().map_err(PicturifyError::from)?;
+
+    Ok(())
+}
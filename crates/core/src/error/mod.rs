@@ -12,8 +12,28 @@ pub enum PicturifyError {
     IoError(#[from] std::io::Error),
     #[error("Image error: {0}")]
     ImageError(#[from] image::ImageError),
+    #[error("PNG decoding error: {0}")]
+    PngDecodingError(#[from] png::DecodingError),
+    #[error("PNG encoding error: {0}")]
+    PngEncodingError(#[from] png::EncodingError),
+    #[error("GIF encoding error: {0}")]
+    GifEncodingError(#[from] gif::EncodingError),
+    #[error("GIF decoding error: {0}")]
+    GifDecodingError(#[from] gif::DecodingError),
+    #[error("TIFF error: {0}")]
+    TiffError(#[from] tiff::TiffError),
     #[error("Invalid image format")]
     InvalidImageFormat,
+    #[error("Unsupported ICC profile: {0}")]
+    InvalidIccProfile(String),
+    #[error("Invalid 3D LUT: {0}")]
+    InvalidLut(String),
+    #[error("Invalid palette: {0}")]
+    InvalidPalette(String),
+    #[error("Invalid convolution kernel: {0}")]
+    InvalidKernel(String),
+    #[error("Invalid plane: {0}")]
+    InvalidPlane(String),
     #[error("Processing error: {0}")]
     ProcessingError(#[from] processing::ProcessingPicturifyError),
     #[error("Parse error: {0}")]
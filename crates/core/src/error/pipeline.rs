@@ -1,4 +1,5 @@
 use crate::error::processing::ProcessingPicturifyError;
+use crate::error::PicturifyError;
 use thiserror::Error;
 
 pub type PipelinePicturifyResult<T> = Result<T, PipelinePicturifyError>;
@@ -7,4 +8,10 @@ pub type PipelinePicturifyResult<T> = Result<T, PipelinePicturifyError>;
 pub enum PipelinePicturifyError {
     #[error("Processing error: {0}")]
     ProcessingError(#[from] ProcessingPicturifyError),
+    #[error("Core error: {0}")]
+    PicturifyError(#[from] PicturifyError),
+    #[error("Pipeline does not support tiled processing")]
+    TilingNotSupported,
+    #[error("Pipeline changed the tile size")]
+    TileSizeMismatch,
 }
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::error::PicturifyError;
+
 pub type ProcessingPicturifyResult<T> = Result<T, ProcessingPicturifyError>;
 
 #[derive(Debug, Error)]
@@ -12,4 +14,34 @@ pub enum ProcessingPicturifyError {
     InvalidAngle,
     #[error("Invalid kernel")]
     InvalidKernel,
+    #[error("Convolution divisor must not be zero")]
+    InvalidDivisor,
+    #[error("Region of interest is outside the image")]
+    InvalidRegion,
+    #[error("Mask size does not match the image")]
+    InvalidMask,
+    #[error("Processor changed the size of the region")]
+    RegionSizeChanged,
+    #[error("Channel image size does not match the image")]
+    InvalidChannelImage,
+    #[error("Invalid plane: {0}")]
+    InvalidPlane(String),
+    #[error("Images have different sizes")]
+    ImageSizeMismatch,
+    #[error("Point is outside the image")]
+    InvalidPoint,
+    #[error("Invalid levels, white point must be above the black point and gamma positive")]
+    InvalidLevels,
+    #[error("Curve needs at least two points with distinct input values")]
+    InvalidCurve,
+}
+
+impl ProcessingPicturifyError {
+    // keeps the reason given by the core plane functions
+    pub fn from_plane_error(error: PicturifyError) -> Self {
+        match error {
+            PicturifyError::InvalidPlane(reason) => Self::InvalidPlane(reason),
+            error => Self::InvalidPlane(error.to_string()),
+        }
+    }
 }
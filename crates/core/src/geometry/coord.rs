@@ -1,9 +1,11 @@
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
+use serde::{Deserialize, Serialize};
+
 use crate::geometry::angle::Angle;
 use crate::geometry::size::Size;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
 pub struct Coord {
     x: i32,
     y: i32,
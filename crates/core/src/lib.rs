@@ -1,4 +1,5 @@
 pub use image;
+pub use moxcms;
 pub use palette;
 pub use rayon;
 pub use thiserror;
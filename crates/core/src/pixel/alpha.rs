@@ -0,0 +1,57 @@
+use image::Rgba;
+use palette::LinSrgba;
+
+// Conversions between straight and premultiplied alpha, so that filters mixing
+// neighbouring pixels do not pull colour out of fully transparent ones
+pub trait PremultipliedAlpha {
+    fn premultiply(&self) -> Self;
+    fn unpremultiply(&self) -> Self;
+}
+
+impl PremultipliedAlpha for Rgba<u8> {
+    #[inline(always)]
+    fn premultiply(&self) -> Self {
+        let [red, green, blue, alpha] = self.0;
+        let multiply = |value: u8| ((value as u16 * alpha as u16 + 127) / 255) as u8;
+
+        Rgba([multiply(red), multiply(green), multiply(blue), alpha])
+    }
+
+    #[inline(always)]
+    fn unpremultiply(&self) -> Self {
+        let [red, green, blue, alpha] = self.0;
+        if alpha == 0 {
+            return Rgba([0, 0, 0, 0]);
+        }
+        let divide =
+            |value: u8| ((value as u16 * 255 + alpha as u16 / 2) / alpha as u16).min(255) as u8;
+
+        Rgba([divide(red), divide(green), divide(blue), alpha])
+    }
+}
+
+impl PremultipliedAlpha for LinSrgba {
+    #[inline(always)]
+    fn premultiply(&self) -> Self {
+        LinSrgba::new(
+            self.red * self.alpha,
+            self.green * self.alpha,
+            self.blue * self.alpha,
+            self.alpha,
+        )
+    }
+
+    #[inline(always)]
+    fn unpremultiply(&self) -> Self {
+        if self.alpha <= 0.0 {
+            return LinSrgba::new(0.0, 0.0, 0.0, 0.0);
+        }
+
+        LinSrgba::new(
+            self.red / self.alpha,
+            self.green / self.alpha,
+            self.blue / self.alpha,
+            self.alpha,
+        )
+    }
+}
@@ -1,3 +1,4 @@
+pub mod alpha;
 pub mod colors;
 pub mod lin_srgba;
 pub mod math;
@@ -0,0 +1,176 @@
+use picturify_core::conversions::icc::{ColorProfile, ColorTransform, WorkingSpace};
+
+const ICC_HEADER_SIZE: usize = 128;
+const ICC_TAG_ENTRY_SIZE: usize = 12;
+const ICC_VERSION: u32 = 0x0430_0000;
+const D50_XYZ: [f32; 3] = [0.9642, 1.0, 0.8249];
+// sRGB colorants adapted to the D50 connection space, as stored in common sRGB profiles
+const SRGB_COLORANTS: [[f32; 3]; 3] = [
+    [0.4361, 0.2225, 0.0139],
+    [0.3851, 0.7169, 0.0971],
+    [0.1431, 0.0606, 0.7141],
+];
+
+fn s15_fixed16(value: f32) -> [u8; 4] {
+    ((value * 65536.0).round() as i32).to_be_bytes()
+}
+
+fn xyz_tag(xyz: [f32; 3]) -> Vec<u8> {
+    let mut tag = b"XYZ \0\0\0\0".to_vec();
+    for value in xyz {
+        tag.extend(s15_fixed16(value));
+    }
+    tag
+}
+
+fn curv_tag(count: u32, entries: &[u16]) -> Vec<u8> {
+    let mut tag = b"curv\0\0\0\0".to_vec();
+    tag.extend(count.to_be_bytes());
+    for entry in entries {
+        tag.extend(entry.to_be_bytes());
+    }
+    tag
+}
+
+fn para_tag(function_type: u16, parameters: &[f32]) -> Vec<u8> {
+    let mut tag = b"para\0\0\0\0".to_vec();
+    tag.extend(function_type.to_be_bytes());
+    tag.extend([0, 0]);
+    for parameter in parameters {
+        tag.extend(s15_fixed16(*parameter));
+    }
+    tag
+}
+
+fn rgb_profile(colorants: [[f32; 3]; 3], curve: Vec<u8>) -> Vec<u8> {
+    let tags: Vec<(&[u8; 4], Vec<u8>)> = vec![
+        (b"rXYZ", xyz_tag(colorants[0])),
+        (b"gXYZ", xyz_tag(colorants[1])),
+        (b"bXYZ", xyz_tag(colorants[2])),
+        (b"rTRC", curve.clone()),
+        (b"gTRC", curve.clone()),
+        (b"bTRC", curve),
+    ];
+    icc_profile(b"RGB ", tags)
+}
+
+fn gray_profile(curve: Vec<u8>) -> Vec<u8> {
+    icc_profile(b"GRAY", vec![(b"wtpt", xyz_tag(D50_XYZ)), (b"kTRC", curve)])
+}
+
+fn icc_profile(color_space: &[u8; 4], tags: Vec<(&[u8; 4], Vec<u8>)>) -> Vec<u8> {
+    let mut header = vec![0u8; ICC_HEADER_SIZE];
+    header[8..12].copy_from_slice(&ICC_VERSION.to_be_bytes());
+    header[12..16].copy_from_slice(b"mntr");
+    header[16..20].copy_from_slice(color_space);
+    header[20..24].copy_from_slice(b"XYZ ");
+    header[36..40].copy_from_slice(b"acsp");
+    for (i, value) in D50_XYZ.iter().enumerate() {
+        header[68 + 4 * i..72 + 4 * i].copy_from_slice(&s15_fixed16(*value));
+    }
+    let mut table = (tags.len() as u32).to_be_bytes().to_vec();
+    let mut data = Vec::new();
+    let data_start = ICC_HEADER_SIZE + 4 + tags.len() * ICC_TAG_ENTRY_SIZE;
+    for (signature, tag) in tags {
+        table.extend(signature);
+        table.extend(((data_start + data.len()) as u32).to_be_bytes());
+        table.extend((tag.len() as u32).to_be_bytes());
+        data.extend(tag);
+    }
+
+    let mut profile = [header, table, data].concat();
+    let size = (profile.len() as u32).to_be_bytes();
+    profile[0..4].copy_from_slice(&size);
+    profile
+}
+
+fn is_identity_to(profile: &ColorProfile, working_space: WorkingSpace) -> bool {
+    ColorTransform::new(profile, &working_space.profile())
+        .unwrap()
+        .is_identity()
+}
+
+#[test]
+fn srgb_profile_should_round_trip() {
+    let curve = para_tag(3, &[2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045]);
+    let profile = ColorProfile::from_icc(&rgb_profile(SRGB_COLORANTS, curve)).unwrap();
+
+    assert!(is_identity_to(&profile, WorkingSpace::Srgb));
+    assert!(!is_identity_to(&profile, WorkingSpace::LinearSrgb));
+}
+
+#[test]
+fn curv_gamma_should_parse() {
+    // a single entry is a gamma in 8.8 fixed point, none means identity
+    for curve in [curv_tag(1, &[256]), curv_tag(0, &[])] {
+        let profile = ColorProfile::from_icc(&rgb_profile(SRGB_COLORANTS, curve)).unwrap();
+        assert!(is_identity_to(&profile, WorkingSpace::LinearSrgb));
+    }
+
+    let curve = curv_tag(1, &[563]);
+    let profile = ColorProfile::from_icc(&rgb_profile(SRGB_COLORANTS, curve)).unwrap();
+    assert!(!is_identity_to(&profile, WorkingSpace::LinearSrgb));
+}
+
+#[test]
+fn curv_table_should_parse() {
+    let entries: Vec<u16> = (0..=16).map(|i| (i * 65535 / 16) as u16).collect();
+    let curve = curv_tag(entries.len() as u32, &entries);
+    let profile = ColorProfile::from_icc(&rgb_profile(SRGB_COLORANTS, curve)).unwrap();
+
+    assert!(is_identity_to(&profile, WorkingSpace::LinearSrgb));
+}
+
+#[test]
+fn para_gamma_should_parse() {
+    let curve = para_tag(0, &[1.0]);
+    let profile = ColorProfile::from_icc(&rgb_profile(SRGB_COLORANTS, curve)).unwrap();
+
+    assert!(is_identity_to(&profile, WorkingSpace::LinearSrgb));
+}
+
+#[test]
+fn para_zero_slope_should_fail() {
+    for (function_type, parameters) in [(1, vec![2.2, 0.0, 0.1]), (2, vec![2.2, 0.0, 0.1, 0.0])] {
+        let curve = para_tag(function_type, &parameters);
+        let result = ColorProfile::from_icc(&rgb_profile(SRGB_COLORANTS, curve));
+
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn malformed_profile_should_fail() {
+    // the table claims more entries than the tag holds
+    let truncated = rgb_profile(SRGB_COLORANTS, curv_tag(8, &[0, 65535]));
+    assert!(ColorProfile::from_icc(&truncated).is_err());
+
+    let unknown = rgb_profile(SRGB_COLORANTS, para_tag(5, &[1.0]));
+    assert!(ColorProfile::from_icc(&unknown).is_err());
+
+    let mut outside = rgb_profile(SRGB_COLORANTS, para_tag(0, &[1.0]));
+    let first_offset = ICC_HEADER_SIZE + 4 + 4;
+    outside[first_offset..first_offset + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert!(ColorProfile::from_icc(&outside).is_err());
+
+    let mut headerless = rgb_profile(SRGB_COLORANTS, para_tag(0, &[1.0]));
+    headerless[36..40].copy_from_slice(b"\0\0\0\0");
+    assert!(ColorProfile::from_icc(&headerless).is_err());
+}
+
+#[test]
+fn gray_profile_should_parse() {
+    let profile = ColorProfile::from_icc(&gray_profile(para_tag(0, &[1.0]))).unwrap();
+    assert!(is_identity_to(&profile, WorkingSpace::LinearSrgb));
+
+    let profile = ColorProfile::from_icc(&gray_profile(para_tag(0, &[2.2]))).unwrap();
+    assert!(!is_identity_to(&profile, WorkingSpace::LinearSrgb));
+}
+
+#[test]
+fn unsupported_color_space_should_fail() {
+    let curve = para_tag(0, &[1.0]);
+    let cmyk = icc_profile(b"CMYK", vec![(b"kTRC", curve)]);
+
+    assert!(ColorProfile::from_icc(&cmyk).is_err());
+}
@@ -0,0 +1,55 @@
+use std::env::temp_dir;
+use std::fs::remove_file;
+use std::process::id;
+
+use image::Delay;
+
+use picturify_core::core::animation::{AnimatedImage, AnimationFrame, FrameDisposal, LoopCount};
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::{ReadFromFile, WriteToFile};
+
+const DISPOSALS: [FrameDisposal; 3] = [
+    FrameDisposal::Keep,
+    FrameDisposal::Previous,
+    FrameDisposal::Background,
+];
+
+fn create_animation(loop_count: LoopCount) -> AnimatedImage {
+    let frames = DISPOSALS
+        .iter()
+        .enumerate()
+        .map(|(index, disposal)| AnimationFrame {
+            image: FastImage::from_rgba_vec((4, 4).into(), vec![index as u8 * 80; 64]),
+            delay: Delay::from_numer_denom_ms(100 * (index as u32 + 1), 1),
+            disposal: *disposal,
+        })
+        .collect();
+    AnimatedImage::new(frames, loop_count).unwrap()
+}
+
+fn assert_round_trip(extension: &str, loop_count: LoopCount) {
+    let path = temp_dir().join(format!("picturify_animation_{}.{}", id(), extension));
+    create_animation(loop_count).write_to_file(&path).unwrap();
+    let animation = AnimatedImage::read_from_file(&path).unwrap();
+    remove_file(&path).unwrap();
+
+    assert_eq!(animation.loop_count(), loop_count);
+    assert_eq!(animation.frames().len(), DISPOSALS.len());
+    for (index, frame) in animation.frames().iter().enumerate() {
+        assert_eq!(frame.delay_ms(), 100 * (index as u32 + 1));
+        assert_eq!(frame.disposal, DISPOSALS[index]);
+    }
+}
+
+#[test]
+fn gif_should_keep_timing() {
+    assert_round_trip("gif", LoopCount::Finite(3));
+    assert_round_trip("gif", LoopCount::Finite(1));
+    assert_round_trip("gif", LoopCount::Infinite);
+}
+
+#[test]
+fn apng_should_keep_timing() {
+    assert_round_trip("png", LoopCount::Finite(3));
+    assert_round_trip("png", LoopCount::Infinite);
+}
@@ -0,0 +1,2 @@
+mod animation;
+mod plane_image;
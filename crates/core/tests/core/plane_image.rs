@@ -0,0 +1,101 @@
+use picturify_core::core::apply_fn_to_pixels::{ApplyFnToPlanePixels, Offset};
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::plane_image::{PlaneImageF32, PlaneImageU16, PlaneImageU8, PlaneValue};
+use picturify_core::error::PicturifyError;
+
+fn create_image() -> FastImage {
+    let values = (0..5 * 3 * 4)
+        .map(|value| (value * 17 % 256) as u8)
+        .collect();
+    FastImage::from_rgba_vec((5, 3).into(), values)
+}
+
+fn offset() -> Offset {
+    Offset {
+        skip_rows: 1,
+        take_rows: 2,
+        skip_columns: 2,
+        take_columns: 2,
+    }
+}
+
+fn assert_offset_marked(plane: &PlaneImageU8) {
+    for y in 0..plane.height() {
+        for x in 0..plane.width() {
+            let inside = (1..3).contains(&y) && (2..4).contains(&x);
+            let expected = if inside { (y * 10 + x) as u8 } else { 0 };
+            assert_eq!(*plane.get((x, y).into()), expected, "at {}x{}", x, y);
+        }
+    }
+}
+
+#[test]
+fn split_and_merge_should_round_trip() {
+    let image = create_image();
+
+    let merged = PlaneImageU8::merge(&PlaneImageU8::split(&image)).unwrap();
+    assert_eq!(merged.to_rgba_vec(), image.to_rgba_vec());
+    let merged = PlaneImageU16::merge(&PlaneImageU16::split(&image)).unwrap();
+    assert_eq!(merged.to_rgba_vec(), image.to_rgba_vec());
+    let merged = PlaneImageF32::merge(&PlaneImageF32::split(&image)).unwrap();
+    assert_eq!(merged.to_rgba_vec(), image.to_rgba_vec());
+}
+
+#[test]
+fn merge_should_reject_planes_of_different_sizes() {
+    let mut planes = PlaneImageU8::split(&create_image());
+    planes[2] = PlaneImageU8::empty((2, 2).into());
+    assert!(matches!(
+        PlaneImageU8::merge(&planes),
+        Err(PicturifyError::InvalidPlane(_))
+    ));
+}
+
+#[test]
+fn plane_values_should_round_trip_u8() {
+    for value in 0..=u8::MAX {
+        assert_eq!(u16::from_u8(value).to_u8(), value);
+        assert_eq!(f32::from_u8(value).to_u8(), value);
+    }
+    assert_eq!(u16::from_u8(u8::MAX), u16::MAX);
+    assert_eq!(f32::from_u8(u8::MAX), 1.0);
+}
+
+#[test]
+fn from_vec_should_reject_wrong_length() {
+    assert!(PlaneImageU8::from_vec((3, 2).into(), vec![0; 6]).is_ok());
+    assert!(matches!(
+        PlaneImageU8::from_vec((3, 2).into(), vec![0; 5]),
+        Err(PicturifyError::InvalidPlane(_))
+    ));
+}
+
+#[test]
+fn from_channel_should_reject_missing_channel() {
+    let image = create_image();
+    let alpha = PlaneImageU8::from_channel(&image, 3).unwrap();
+    assert_eq!(alpha, PlaneImageU8::split(&image)[3]);
+    assert!(matches!(
+        PlaneImageU8::from_channel(&image, 4),
+        Err(PicturifyError::InvalidPlane(_))
+    ));
+}
+
+#[test]
+fn apply_with_offset_should_only_visit_offset() {
+    let mut plane = PlaneImageU8::empty((5, 4).into());
+    plane.apply_fn_to_plane_pixel_with_offset(
+        |value, coord| *value = (coord.y() * 10 + coord.x()) as u8,
+        None,
+        offset(),
+    );
+    assert_offset_marked(&plane);
+
+    let mut plane = PlaneImageU8::empty((5, 4).into());
+    plane.par_apply_fn_to_plane_pixel_with_offset(
+        |value, coord| *value = (coord.y() * 10 + coord.x()) as u8,
+        None,
+        offset(),
+    );
+    assert_offset_marked(&plane);
+}
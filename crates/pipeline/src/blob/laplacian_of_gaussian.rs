@@ -6,6 +6,7 @@ use crate::pipeline::Pipeline;
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::pipeline::PipelinePicturifyResult;
 use picturify_core::palette::Srgba;
+use picturify_processing::common::processors::CpuProcessor;
 use picturify_processing::processors::blob::laplacian_of_gaussian::{
     LaplacianOfGaussianProcessor, LaplacianOfGaussianProcessorOptions,
 };
@@ -28,6 +29,14 @@ impl LaplacianOfGaussianPipeline {
     pub fn new(options: LaplacianOfGaussianPipelineOptions) -> Self {
         Self { options }
     }
+
+    fn create_processor(&self) -> LaplacianOfGaussianProcessor {
+        LaplacianOfGaussianProcessor::new(LaplacianOfGaussianProcessorOptions {
+            radius: self.options.radius,
+            sigma: self.options.sigma,
+            use_fast_approximation: self.options.fast,
+        })
+    }
 }
 
 const LAPLACIAN_OF_GAUSSIAN_PROCESSOR_NAME: &str = "LaplacianOfGaussian";
@@ -38,11 +47,7 @@ impl Pipeline for LaplacianOfGaussianPipeline {
         image: FastImage,
         pipeline_progress: Option<PipelineProgress>,
     ) -> PipelinePicturifyResult<FastImage> {
-        let processor = LaplacianOfGaussianProcessor::new(LaplacianOfGaussianProcessorOptions {
-            radius: self.options.radius,
-            sigma: self.options.sigma,
-            use_fast_approximation: self.options.fast,
-        });
+        let processor = self.create_processor();
         let (width, height) = image.size().into();
         let pipeline = EnlargementCropPipeline::new(EnlargementCropPipelineOptions {
             fast: self.options.fast,
@@ -66,4 +71,8 @@ impl Pipeline for LaplacianOfGaussianPipeline {
 
         Ok(image)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
 }
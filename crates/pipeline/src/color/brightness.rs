@@ -44,4 +44,8 @@ impl Pipeline for BrightnessPipeline {
         pipeline_progress.increment_combined();
         Ok(final_image)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
 }
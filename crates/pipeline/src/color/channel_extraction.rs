@@ -0,0 +1,54 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::color_space::ColorSpace;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::channel_extraction::{
+    ChannelExtractionProcessor, ChannelExtractionProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct ChannelExtractionPipelineOptions {
+    pub space: ColorSpace,
+    pub channel: usize,
+}
+
+pub struct ChannelExtractionPipeline {
+    options: ChannelExtractionPipelineOptions,
+}
+
+impl ChannelExtractionPipeline {
+    pub fn new(options: ChannelExtractionPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const CHANNEL_EXTRACTION_PROCESSOR_NAME: &str = "ChannelExtraction";
+
+impl Pipeline for ChannelExtractionPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(CHANNEL_EXTRACTION_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = ChannelExtractionProcessor::new(ChannelExtractionProcessorOptions {
+            space: self.options.space,
+            channel: self.options.channel,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
@@ -0,0 +1,50 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::color_space::ColorSpace;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::channel_merge::{
+    ChannelMergeProcessor, ChannelMergeProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct ChannelMergePipelineOptions {
+    pub space: ColorSpace,
+    pub channels: [Option<FastImage>; 3],
+}
+
+pub struct ChannelMergePipeline {
+    options: ChannelMergePipelineOptions,
+}
+
+impl ChannelMergePipeline {
+    pub fn new(options: ChannelMergePipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const CHANNEL_MERGE_PROCESSOR_NAME: &str = "ChannelMerge";
+
+impl Pipeline for ChannelMergePipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(CHANNEL_MERGE_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = ChannelMergeProcessor::new(ChannelMergeProcessorOptions {
+            space: self.options.space,
+            channels: self.options.channels.clone(),
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+}
@@ -0,0 +1,53 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::curves::{
+    Curves, CurvesProcessor, CurvesProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct CurvesPipelineOptions {
+    pub curves: Curves,
+    pub fast: bool,
+}
+
+pub struct CurvesPipeline {
+    options: CurvesPipelineOptions,
+}
+
+impl CurvesPipeline {
+    pub fn new(options: CurvesPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const CURVES_PROCESSOR_NAME: &str = "Curves";
+
+impl Pipeline for CurvesPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(CURVES_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = CurvesProcessor::new(CurvesProcessorOptions {
+            curves: self.options.curves.clone(),
+            use_fast_approximation: self.options.fast,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
@@ -0,0 +1,34 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::extract_alpha::ExtractAlphaProcessor;
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct ExtractAlphaPipeline;
+
+const EXTRACT_ALPHA_PROCESSOR_NAME: &str = "ExtractAlpha";
+
+impl Pipeline for ExtractAlphaPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(EXTRACT_ALPHA_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let final_image = ExtractAlphaProcessor
+            .process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
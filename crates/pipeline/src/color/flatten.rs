@@ -0,0 +1,51 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::image::Rgba;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::flatten::{FlattenProcessor, FlattenProcessorOptions};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct FlattenPipelineOptions {
+    pub background: Rgba<u8>,
+}
+
+pub struct FlattenPipeline {
+    options: FlattenPipelineOptions,
+}
+
+impl FlattenPipeline {
+    pub fn new(options: FlattenPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const FLATTEN_PROCESSOR_NAME: &str = "Flatten";
+
+impl Pipeline for FlattenPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(FLATTEN_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = FlattenProcessor::new(FlattenProcessorOptions {
+            background: self.options.background,
+        });
+
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
@@ -46,4 +46,8 @@ impl Pipeline for GrayscalePipeline {
 
         Ok(final_image)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
 }
@@ -0,0 +1,52 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::geometry::angle::Angle;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::hue_rotation::{
+    HueRotationProcessor, HueRotationProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct HueRotationPipelineOptions {
+    pub angle: Angle,
+}
+
+pub struct HueRotationPipeline {
+    options: HueRotationPipelineOptions,
+}
+
+impl HueRotationPipeline {
+    pub fn new(options: HueRotationPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const HUE_ROTATION_PROCESSOR_NAME: &str = "HueRotation";
+
+impl Pipeline for HueRotationPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(HUE_ROTATION_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = HueRotationProcessor::new(HueRotationProcessorOptions {
+            angle: self.options.angle,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
@@ -0,0 +1,53 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::levels::{
+    Levels, LevelsProcessor, LevelsProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct LevelsPipelineOptions {
+    pub levels: Levels,
+    pub fast: bool,
+}
+
+pub struct LevelsPipeline {
+    options: LevelsPipelineOptions,
+}
+
+impl LevelsPipeline {
+    pub fn new(options: LevelsPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const LEVELS_PROCESSOR_NAME: &str = "Levels";
+
+impl Pipeline for LevelsPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(LEVELS_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = LevelsProcessor::new(LevelsProcessorOptions {
+            levels: self.options.levels,
+            use_fast_approximation: self.options.fast,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
@@ -0,0 +1,53 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::lightness::{
+    LightnessProcessor, LightnessProcessorOptions, LightnessSpace,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct LightnessPipelineOptions {
+    pub factor: f32,
+    pub space: LightnessSpace,
+}
+
+pub struct LightnessPipeline {
+    options: LightnessPipelineOptions,
+}
+
+impl LightnessPipeline {
+    pub fn new(options: LightnessPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const LIGHTNESS_PROCESSOR_NAME: &str = "Lightness";
+
+impl Pipeline for LightnessPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(LIGHTNESS_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = LightnessProcessor::new(LightnessProcessorOptions {
+            factor: self.options.factor,
+            space: self.options.space,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
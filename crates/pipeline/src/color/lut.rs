@@ -0,0 +1,52 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::lut3d::{Lut3d, Lut3dInterpolation};
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::lut::{LutProcessor, LutProcessorOptions};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct LutPipelineOptions {
+    pub lut: Lut3d,
+    pub interpolation: Lut3dInterpolation,
+}
+
+pub struct LutPipeline {
+    options: LutPipelineOptions,
+}
+
+impl LutPipeline {
+    pub fn new(options: LutPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const LUT_PROCESSOR_NAME: &str = "Lut";
+
+impl Pipeline for LutPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(LUT_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = LutProcessor::new(LutProcessorOptions {
+            lut: self.options.lut.clone(),
+            interpolation: self.options.interpolation,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
@@ -46,4 +46,8 @@ impl Pipeline for NegativePipeline {
 
         Ok(final_image)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
 }
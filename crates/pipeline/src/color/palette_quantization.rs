@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+use picturify_core::core::color_palette::ColorPalette;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::WriteToFile;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::palette_generation::{generate_palette, PaletteMethod};
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::palette_quantization::{
+    Dithering, PaletteQuantizationProcessor, PaletteQuantizationProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct PaletteQuantizationPipelineOptions {
+    pub colors: usize,
+    pub method: PaletteMethod,
+    // fixed palette, when set the palette is not computed from the image
+    pub palette: Option<ColorPalette>,
+    pub dithering: Dithering,
+    pub export_palette: Option<PathBuf>,
+}
+
+pub struct PaletteQuantizationPipeline {
+    options: PaletteQuantizationPipelineOptions,
+}
+
+impl PaletteQuantizationPipeline {
+    pub fn new(options: PaletteQuantizationPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const PALETTE_QUANTIZATION_PROCESSOR_NAME: &str = "PaletteQuantization";
+
+impl Pipeline for PaletteQuantizationPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(PALETTE_QUANTIZATION_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let palette = match &self.options.palette {
+            Some(palette) => palette.clone(),
+            None => generate_palette(&image, self.options.colors, self.options.method)?,
+        };
+        if let Some(path) = &self.options.export_palette {
+            palette.write_to_file(path)?;
+        }
+
+        let processor = PaletteQuantizationProcessor::new(PaletteQuantizationProcessorOptions {
+            palette,
+            dithering: self.options.dithering,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+}
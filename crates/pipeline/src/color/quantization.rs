@@ -47,4 +47,8 @@ impl Pipeline for QuantizationPipeline {
 
         Ok(final_image)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
 }
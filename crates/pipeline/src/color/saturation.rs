@@ -0,0 +1,53 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::saturation::{
+    SaturationProcessor, SaturationProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct SaturationPipelineOptions {
+    pub factor: f32,
+    pub vibrance: f32,
+}
+
+pub struct SaturationPipeline {
+    options: SaturationPipelineOptions,
+}
+
+impl SaturationPipeline {
+    pub fn new(options: SaturationPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const SATURATION_PROCESSOR_NAME: &str = "Saturation";
+
+impl Pipeline for SaturationPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(SATURATION_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = SaturationProcessor::new(SaturationProcessorOptions {
+            factor: self.options.factor,
+            vibrance: self.options.vibrance,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
@@ -47,6 +47,10 @@ impl Pipeline for SepiaPipeline {
 
         Ok(final_image)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
 }
 
 impl SepiaPipeline {
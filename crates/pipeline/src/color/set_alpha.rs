@@ -0,0 +1,49 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::common::region::Mask;
+use picturify_processing::processors::color::set_alpha::{
+    SetAlphaProcessor, SetAlphaProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct SetAlphaPipelineOptions {
+    pub alpha: Mask,
+}
+
+pub struct SetAlphaPipeline {
+    options: SetAlphaPipelineOptions,
+}
+
+impl SetAlphaPipeline {
+    pub fn new(options: SetAlphaPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const SET_ALPHA_PROCESSOR_NAME: &str = "SetAlpha";
+
+impl Pipeline for SetAlphaPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(SET_ALPHA_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = SetAlphaProcessor::new(SetAlphaProcessorOptions {
+            alpha: self.options.alpha.clone(),
+        });
+
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+}
@@ -0,0 +1,53 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::temperature::{
+    TemperatureProcessor, TemperatureProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct TemperaturePipelineOptions {
+    pub temperature: f32,
+    pub tint: f32,
+}
+
+pub struct TemperaturePipeline {
+    options: TemperaturePipelineOptions,
+}
+
+impl TemperaturePipeline {
+    pub fn new(options: TemperaturePipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const TEMPERATURE_PROCESSOR_NAME: &str = "Temperature";
+
+impl Pipeline for TemperaturePipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(TEMPERATURE_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = TemperatureProcessor::new(TemperatureProcessorOptions {
+            temperature: self.options.temperature,
+            tint: self.options.tint,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
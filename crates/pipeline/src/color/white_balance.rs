@@ -0,0 +1,50 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::geometry::coord::Coord;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::white_balance::{
+    WhiteBalanceMethod, WhiteBalanceProcessor, WhiteBalanceProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct WhiteBalancePipelineOptions {
+    pub method: WhiteBalanceMethod,
+    pub point: Option<Coord>,
+}
+
+pub struct WhiteBalancePipeline {
+    options: WhiteBalancePipelineOptions,
+}
+
+impl WhiteBalancePipeline {
+    pub fn new(options: WhiteBalancePipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const WHITE_BALANCE_PROCESSOR_NAME: &str = "WhiteBalance";
+
+impl Pipeline for WhiteBalancePipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(WHITE_BALANCE_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = WhiteBalanceProcessor::new(WhiteBalanceProcessorOptions {
+            method: self.options.method,
+            point: self.options.point,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+
+        pipeline_progress.increment_combined();
+        Ok(final_image)
+    }
+}
@@ -0,0 +1,49 @@
+use picturify_core::core::animation::{AnimatedImage, AnimationFrame};
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+// Runs the wrapped pipeline over every frame of an animation, frame delays, disposal and the
+// loop count are kept
+pub struct AnimationPipeline {
+    pipeline: Box<dyn Pipeline>,
+}
+
+impl AnimationPipeline {
+    pub fn new(pipeline: Box<dyn Pipeline>) -> Self {
+        Self { pipeline }
+    }
+}
+
+const ANIMATION_PROCESSOR_NAME: &str = "Animation";
+
+impl AnimationPipeline {
+    pub fn run(
+        &self,
+        animation: AnimatedImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<AnimatedImage> {
+        let loop_count = animation.loop_count();
+        let frames = animation.into_frames();
+
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+        pipeline_progress.new_individual(ANIMATION_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+        let mut progress = pipeline_progress.get_current_individual_progress();
+        progress.setup(frames.len());
+
+        let frames = frames
+            .into_iter()
+            .map(|frame| {
+                let image = self.pipeline.run(frame.image, None)?;
+                progress.increment();
+                Ok(AnimationFrame { image, ..frame })
+            })
+            .collect::<PipelinePicturifyResult<Vec<_>>>()?;
+
+        pipeline_progress.increment_combined();
+
+        Ok(AnimatedImage::new(frames, loop_count)?)
+    }
+}
@@ -0,0 +1,58 @@
+use picturify_core::conversions::icc::ColorTransform;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::geometry::size::Size;
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+// Runs the pipeline in a working color space, converting the image into it first
+// and back to the output space afterwards. The working image is 8-bit, the values
+// rounded away are kept for the pixels the pipeline leaves alone.
+pub struct ColorManagedPipeline {
+    input: ColorTransform,
+    output: ColorTransform,
+    pipeline: Box<dyn Pipeline>,
+}
+
+impl ColorManagedPipeline {
+    pub fn new(input: ColorTransform, output: ColorTransform, pipeline: Box<dyn Pipeline>) -> Self {
+        Self {
+            input,
+            output,
+            pipeline,
+        }
+    }
+}
+
+impl Pipeline for ColorManagedPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let (image, precise) = self.input.apply_precise(image)?;
+        let image = self.pipeline.run(image, pipeline_progress)?;
+
+        Ok(self.output.apply_restoring(image, &precise)?)
+    }
+
+    fn run_tile(
+        &self,
+        image: FastImage,
+        row: usize,
+        image_size: Size,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let (image, precise) = self.input.apply_precise(image)?;
+        let image = self
+            .pipeline
+            .run_tile(image, row, image_size, pipeline_progress)?;
+
+        Ok(self.output.apply_restoring(image, &precise)?)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        self.pipeline.tile_overlap()
+    }
+}
@@ -1,2 +1,7 @@
+pub mod animation_pipeline;
+pub mod color_managed_pipeline;
 pub mod enlargement_crop_pipeline;
+pub mod passthrough_pipeline;
 pub mod pipeline_progress;
+pub mod region_pipeline;
+pub mod tiled_pipeline;
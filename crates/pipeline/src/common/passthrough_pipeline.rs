@@ -0,0 +1,29 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct PassthroughPipeline;
+
+const PASSTHROUGH_PROCESSOR_NAME: &str = "Passthrough";
+
+impl Pipeline for PassthroughPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(PASSTHROUGH_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+        pipeline_progress.increment_combined();
+
+        Ok(image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(0)
+    }
+}
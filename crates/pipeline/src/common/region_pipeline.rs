@@ -0,0 +1,61 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::geometry::size::Size;
+use picturify_processing::common::region::Region;
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct RegionPipeline {
+    region: Region,
+    pipeline: Box<dyn Pipeline>,
+}
+
+impl RegionPipeline {
+    pub fn new(region: Region, pipeline: Box<dyn Pipeline>) -> Self {
+        Self { region, pipeline }
+    }
+
+    fn run_in_region(
+        &self,
+        region: &Region,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        // pipelines that can't be tiled may depend on the whole image, give them all of it
+        let margin = self.pipeline.tile_overlap().unwrap_or(usize::MAX);
+
+        region.apply(image, margin, |image| {
+            self.pipeline.run(image, pipeline_progress)
+        })
+    }
+}
+
+impl Pipeline for RegionPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        self.run_in_region(&self.region, image, pipeline_progress)
+    }
+
+    // the region is given for the whole image, so it is cut down to the rows of the tile
+    fn run_tile(
+        &self,
+        image: FastImage,
+        row: usize,
+        image_size: Size,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let (_, height): (usize, usize) = image.size().into();
+        match self.region.rows(row, height, image_size)? {
+            Some(region) => self.run_in_region(&region, image, pipeline_progress),
+            None => Ok(image),
+        }
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        self.pipeline.tile_overlap()
+    }
+}
@@ -0,0 +1,106 @@
+use std::path::Path;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::streaming::{open_row_reader, write_rows_to_file};
+use picturify_core::error::pipeline::{PipelinePicturifyError, PipelinePicturifyResult};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct TiledPipelineOptions {
+    pub tile_height: usize,
+}
+
+// Streams the input through the pipeline in horizontal tiles, each extended by the
+// pipeline's overlap so that the stitched output matches processing the whole image
+pub struct TiledPipeline {
+    options: TiledPipelineOptions,
+    pipeline: Box<dyn Pipeline>,
+}
+
+impl TiledPipeline {
+    pub fn new(options: TiledPipelineOptions, pipeline: Box<dyn Pipeline>) -> Self {
+        Self { options, pipeline }
+    }
+}
+
+const TILED_PROCESSOR_NAME: &str = "Tiled";
+
+impl TiledPipeline {
+    pub fn run<P, Q>(
+        &self,
+        input: P,
+        output: Q,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<()>
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        let overlap = self
+            .pipeline
+            .tile_overlap()
+            .ok_or(PipelinePicturifyError::TilingNotSupported)?;
+        let tile_height = self.options.tile_height.max(1);
+
+        let mut reader = open_row_reader(input)?;
+        let size = reader.size();
+        let (width, height): (usize, usize) = size.into();
+        let tile_count = height.div_ceil(tile_height);
+        let row_len = width * 4;
+
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+        pipeline_progress.new_individual(TILED_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+        let mut progress = pipeline_progress.get_current_individual_progress();
+        progress.setup(tile_count);
+
+        // rows [window_start, window_start + window.len() / row_len) of the input
+        let mut window: Vec<u8> = Vec::new();
+        let mut window_start = 0;
+        let mut next_tile = 0;
+
+        write_rows_to_file(output, size, || {
+            if next_tile == tile_count {
+                return Ok(None);
+            }
+
+            let tile_start = next_tile * tile_height;
+            let tile_end = (tile_start + tile_height).min(height);
+            let read_start = tile_start.saturating_sub(overlap);
+            let read_end = (tile_end + overlap).min(height);
+
+            window.drain(..(read_start - window_start) * row_len);
+            window_start = read_start;
+            let window_end = window_start + window.len() / row_len;
+            if window_end < read_end {
+                let rows = reader.read_rows(read_end - window_end)?;
+                window.extend_from_slice(&rows.to_rgba_vec());
+            }
+
+            let tile =
+                FastImage::from_rgba_vec((width, read_end - read_start).into(), window.clone());
+            let processed = self.pipeline.run_tile(tile, read_start, size, None)?;
+            if processed.size() != (width, read_end - read_start).into() {
+                return Err(PipelinePicturifyError::TileSizeMismatch);
+            }
+
+            let processed = processed.to_rgba_vec();
+            let skip = (tile_start - read_start) * row_len;
+            let take = (tile_end - tile_start) * row_len;
+            let rows = FastImage::from_rgba_vec(
+                (width, tile_end - tile_start).into(),
+                processed[skip..skip + take].to_vec(),
+            );
+
+            next_tile += 1;
+            progress.increment();
+
+            Ok(Some(rows))
+        })?;
+
+        pipeline_progress.increment_combined();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::palette::Srgba;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::color::quantization::{
+    QuantizationProcessor, QuantizationProcessorOptions,
+};
+use picturify_processing::processors::edge::xdog::{XDogProcessor, XDogProcessorOptions};
+use picturify_processing::processors::geometry::crop::{CropBorder, CropProcessorOptions};
+use picturify_processing::processors::geometry::enlargement::{
+    EnlargementBorder, EnlargementProcessorOptions, EnlargementStrategy,
+};
+use picturify_processing::processors::math::multiply::{
+    MultiplyProcessor, MultiplyProcessorOptions,
+};
+use picturify_processing::processors::noise::bilateral_blur::{
+    BilateralBlurProcessor, BilateralBlurProcessorOptions,
+};
+use picturify_processing::processors::noise::kuwahara::{
+    KuwaharaProcessor, KuwaharaProcessorOptions,
+};
+
+use crate::common::enlargement_crop_pipeline::{
+    EnlargementCropPipeline, EnlargementCropPipelineOptions,
+};
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+// ratio between the gaussians of the edge detection
+const EDGE_K: f32 = 1.6;
+const BILATERAL_SIGMA_INTENSITY: f32 = 0.1;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CartoonSmoothing {
+    Bilateral,
+    Kuwahara,
+}
+
+impl ValueEnum for CartoonSmoothing {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[CartoonSmoothing::Bilateral, CartoonSmoothing::Kuwahara]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            CartoonSmoothing::Bilateral => Some(PossibleValue::new("bilateral")),
+            CartoonSmoothing::Kuwahara => Some(PossibleValue::new("kuwahara")),
+        }
+    }
+}
+
+pub struct CartoonPipelineOptions {
+    pub smoothing: CartoonSmoothing,
+    pub radius: usize,
+    pub levels: u8,
+    pub sigma: f32,
+    pub sharpness: f32,
+    pub epsilon: f32,
+    pub phi: f32,
+}
+
+// Flattens the colors with an edge preserving blur and quantization, then multiplies the
+// result with XDoG lines found on the smoothed image
+pub struct CartoonPipeline {
+    options: CartoonPipelineOptions,
+}
+
+impl CartoonPipeline {
+    pub fn new(options: CartoonPipelineOptions) -> Self {
+        Self { options }
+    }
+
+    fn create_smoothing_processor(&self) -> Box<dyn CpuProcessor> {
+        match self.options.smoothing {
+            CartoonSmoothing::Bilateral => {
+                Box::new(BilateralBlurProcessor::new(BilateralBlurProcessorOptions {
+                    radius: self.options.radius,
+                    sigma_spatial: (self.options.radius as f32 / 2.0).max(0.5),
+                    sigma_intensity: BILATERAL_SIGMA_INTENSITY,
+                    use_fast_approximation: true,
+                }))
+            }
+            CartoonSmoothing::Kuwahara => {
+                Box::new(KuwaharaProcessor::new(KuwaharaProcessorOptions {
+                    radius: self.options.radius,
+                }))
+            }
+        }
+    }
+
+    fn create_edge_processor(&self) -> XDogProcessor {
+        XDogProcessor::new(XDogProcessorOptions {
+            sigma: self.options.sigma,
+            k: EDGE_K,
+            sharpness: self.options.sharpness,
+            epsilon: self.options.epsilon,
+            phi: self.options.phi,
+        })
+    }
+}
+
+const SMOOTHING_PROCESSOR_NAME: &str = "Smoothing";
+const QUANTIZATION_PROCESSOR_NAME: &str = "Quantization";
+const XDOG_PROCESSOR_NAME: &str = "XDog";
+const MULTIPLY_PROCESSOR_NAME: &str = "Multiply";
+
+impl Pipeline for CartoonPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(SMOOTHING_PROCESSOR_NAME.to_string());
+        pipeline_progress.new_individual(QUANTIZATION_PROCESSOR_NAME.to_string());
+        pipeline_progress.new_individual(XDOG_PROCESSOR_NAME.to_string());
+        pipeline_progress.new_individual(MULTIPLY_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(4);
+
+        let (width, height) = image.size().into();
+        let smoothing_pipeline = EnlargementCropPipeline::new(EnlargementCropPipelineOptions {
+            fast: false,
+            processor_name: SMOOTHING_PROCESSOR_NAME.to_string(),
+            processor: self.create_smoothing_processor(),
+            enlargement_processor_options: EnlargementProcessorOptions {
+                strategy: EnlargementStrategy::Constant(Srgba::new(0.0, 0.0, 0.0, 1.0).into()),
+                border: EnlargementBorder::from_all(self.options.radius),
+            },
+            crop_processor_options: CropProcessorOptions {
+                crop_border: CropBorder::new(
+                    width,
+                    height,
+                    self.options.radius,
+                    self.options.radius,
+                ),
+            },
+        });
+        let mut progress = pipeline_progress.get_current_individual_progress();
+        progress.setup(1);
+        let smoothed = smoothing_pipeline.run(image, None)?;
+        progress.increment();
+        pipeline_progress.increment_combined();
+
+        let quantization_processor = QuantizationProcessor::new(QuantizationProcessorOptions {
+            levels: self.options.levels,
+            use_fast_approximation: true,
+        });
+        let colors = quantization_processor.process(
+            smoothed.clone(),
+            pipeline_progress.get_current_individual_progress(),
+        )?;
+        pipeline_progress.increment_combined();
+
+        let edges = self.create_edge_processor().process(
+            smoothed,
+            pipeline_progress.get_current_individual_progress(),
+        )?;
+        pipeline_progress.increment_combined();
+
+        let multiply_processor = MultiplyProcessor::new(MultiplyProcessorOptions {
+            second_image: edges,
+        });
+        let final_image = multiply_processor
+            .process(colors, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.options.radius + self.create_edge_processor().kernel_radius())
+    }
+}
@@ -0,0 +1,59 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::edge::difference_of_gaussians::{
+    DifferenceOfGaussiansProcessor, DifferenceOfGaussiansProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct DifferenceOfGaussiansPipelineOptions {
+    pub sigma: f32,
+    pub k: f32,
+    pub gain: f32,
+}
+
+pub struct DifferenceOfGaussiansPipeline {
+    options: DifferenceOfGaussiansPipelineOptions,
+}
+
+impl DifferenceOfGaussiansPipeline {
+    pub fn new(options: DifferenceOfGaussiansPipelineOptions) -> Self {
+        Self { options }
+    }
+
+    fn create_processor(&self) -> DifferenceOfGaussiansProcessor {
+        DifferenceOfGaussiansProcessor::new(DifferenceOfGaussiansProcessorOptions {
+            sigma: self.options.sigma,
+            k: self.options.k,
+            gain: self.options.gain,
+        })
+    }
+}
+
+const DIFFERENCE_OF_GAUSSIANS_PROCESSOR_NAME: &str = "DifferenceOfGaussians";
+
+impl Pipeline for DifferenceOfGaussiansPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(DIFFERENCE_OF_GAUSSIANS_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = self.create_processor();
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
+}
@@ -6,6 +6,7 @@ use crate::pipeline::Pipeline;
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::pipeline::PipelinePicturifyResult;
 use picturify_core::palette::Srgba;
+use picturify_processing::common::processors::CpuProcessor;
 use picturify_processing::processors::edge::emboss::{EmbossProcessor, EmbossProcessorOptions};
 use picturify_processing::processors::geometry::crop::{CropBorder, CropProcessorOptions};
 use picturify_processing::processors::geometry::enlargement::{
@@ -24,6 +25,12 @@ impl EmbossPipeline {
     pub fn new(options: EmbossPipelineOptions) -> Self {
         Self { options }
     }
+
+    fn create_processor(&self) -> EmbossProcessor {
+        EmbossProcessor::new(EmbossProcessorOptions {
+            use_fast_approximation: self.options.fast,
+        })
+    }
 }
 
 const EMBOSS_PROCESSOR_NAME: &str = "Emboss";
@@ -34,10 +41,7 @@ impl Pipeline for EmbossPipeline {
         image: FastImage,
         pipeline_progress: Option<PipelineProgress>,
     ) -> PipelinePicturifyResult<FastImage> {
-        let processor = EmbossProcessor::new(EmbossProcessorOptions {
-            use_fast_approximation: self.options.fast,
-        });
-
+        let processor = self.create_processor();
         let (width, height) = image.size().into();
 
         let pipeline = EnlargementCropPipeline::new(EnlargementCropPipelineOptions {
@@ -55,4 +59,8 @@ impl Pipeline for EmbossPipeline {
 
         pipeline.run(image, pipeline_progress)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
 }
@@ -0,0 +1,54 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::edge::gradient::{
+    GradientMode, GradientOperator, GradientProcessor, GradientProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct GradientPipelineOptions {
+    pub operator: GradientOperator,
+    pub mode: GradientMode,
+    pub gain: f32,
+    pub fast: bool,
+}
+
+pub struct GradientPipeline {
+    options: GradientPipelineOptions,
+}
+
+impl GradientPipeline {
+    pub fn new(options: GradientPipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const GRADIENT_PROCESSOR_NAME: &str = "Gradient";
+
+// output is scaled by the strongest gradient of the whole image, so it cannot be tiled
+impl Pipeline for GradientPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(GRADIENT_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = GradientProcessor::new(GradientProcessorOptions {
+            operator: self.options.operator,
+            mode: self.options.mode,
+            gain: self.options.gain,
+            use_fast_approximation: self.options.fast,
+        });
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+}
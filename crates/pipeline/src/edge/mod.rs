@@ -1,3 +1,7 @@
+pub mod cartoon;
+pub mod difference_of_gaussians;
 pub mod emboss;
+pub mod gradient;
 pub mod prewitt;
 pub mod sobel;
+pub mod xdog;
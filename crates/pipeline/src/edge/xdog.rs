@@ -0,0 +1,61 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::edge::xdog::{XDogProcessor, XDogProcessorOptions};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct XDogPipelineOptions {
+    pub sigma: f32,
+    pub k: f32,
+    pub sharpness: f32,
+    pub epsilon: f32,
+    pub phi: f32,
+}
+
+pub struct XDogPipeline {
+    options: XDogPipelineOptions,
+}
+
+impl XDogPipeline {
+    pub fn new(options: XDogPipelineOptions) -> Self {
+        Self { options }
+    }
+
+    fn create_processor(&self) -> XDogProcessor {
+        XDogProcessor::new(XDogProcessorOptions {
+            sigma: self.options.sigma,
+            k: self.options.k,
+            sharpness: self.options.sharpness,
+            epsilon: self.options.epsilon,
+            phi: self.options.phi,
+        })
+    }
+}
+
+const XDOG_PROCESSOR_NAME: &str = "XDog";
+
+impl Pipeline for XDogPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(XDOG_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = self.create_processor();
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
+}
@@ -0,0 +1,61 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::anisotropic_diffusion::{
+    AnisotropicDiffusionProcessor, AnisotropicDiffusionProcessorOptions, ConductionFunction,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct AnisotropicDiffusionPipelineOptions {
+    pub iterations: usize,
+    pub kappa: f32,
+    pub lambda: f32,
+    pub function: ConductionFunction,
+}
+
+pub struct AnisotropicDiffusionPipeline {
+    options: AnisotropicDiffusionPipelineOptions,
+}
+
+impl AnisotropicDiffusionPipeline {
+    pub fn new(options: AnisotropicDiffusionPipelineOptions) -> Self {
+        Self { options }
+    }
+
+    fn create_processor(&self) -> AnisotropicDiffusionProcessor {
+        AnisotropicDiffusionProcessor::new(AnisotropicDiffusionProcessorOptions {
+            iterations: self.options.iterations,
+            kappa: self.options.kappa,
+            lambda: self.options.lambda,
+            function: self.options.function,
+        })
+    }
+}
+
+const ANISOTROPIC_DIFFUSION_PROCESSOR_NAME: &str = "AnisotropicDiffusion";
+
+impl Pipeline for AnisotropicDiffusionPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(ANISOTROPIC_DIFFUSION_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = self.create_processor();
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
+}
@@ -1,6 +1,7 @@
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::pipeline::PipelinePicturifyResult;
 use picturify_core::palette::Srgba;
+use picturify_processing::common::processors::CpuProcessor;
 use picturify_processing::processors::geometry::crop::{CropBorder, CropProcessorOptions};
 use picturify_processing::processors::geometry::enlargement::{
     EnlargementBorder, EnlargementProcessorOptions, EnlargementStrategy,
@@ -30,6 +31,15 @@ impl BilateralBlurPipeline {
     pub fn new(options: BilateralBlurPipelineOptions) -> Self {
         Self { options }
     }
+
+    fn create_processor(&self) -> BilateralBlurProcessor {
+        BilateralBlurProcessor::new(BilateralBlurProcessorOptions {
+            radius: self.options.radius,
+            sigma_spatial: self.options.sigma_spatial,
+            sigma_intensity: self.options.sigma_intensity,
+            use_fast_approximation: self.options.fast,
+        })
+    }
 }
 
 const BILATERAL_BLUR_PROCESSOR_NAME: &str = "BilateralBlur";
@@ -40,12 +50,7 @@ impl Pipeline for BilateralBlurPipeline {
         image: FastImage,
         pipeline_progress: Option<PipelineProgress>,
     ) -> PipelinePicturifyResult<FastImage> {
-        let processor = BilateralBlurProcessor::new(BilateralBlurProcessorOptions {
-            radius: self.options.radius,
-            sigma_spatial: self.options.sigma_spatial,
-            sigma_intensity: self.options.sigma_intensity,
-            use_fast_approximation: self.options.fast,
-        });
+        let processor = self.create_processor();
         let (width, height) = image.size().into();
         let pipeline = EnlargementCropPipeline::new(EnlargementCropPipelineOptions {
             fast: self.options.fast,
@@ -67,4 +72,8 @@ impl Pipeline for BilateralBlurPipeline {
 
         pipeline.run(image, pipeline_progress)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
 }
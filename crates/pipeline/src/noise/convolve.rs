@@ -0,0 +1,67 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::geometry::coord::Coord;
+use picturify_processing::common::kernels::convolution::ConvolutionKernel;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::convolve::{
+    ConvolveChannels, ConvolveProcessor, ConvolveProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct ConvolvePipelineOptions {
+    pub kernel: ConvolutionKernel,
+    pub normalize: bool,
+    pub divisor: f32,
+    pub bias: f32,
+    pub anchor: Option<Coord>,
+    pub channels: ConvolveChannels,
+}
+
+pub struct ConvolvePipeline {
+    options: ConvolvePipelineOptions,
+}
+
+impl ConvolvePipeline {
+    pub fn new(options: ConvolvePipelineOptions) -> Self {
+        Self { options }
+    }
+
+    fn create_processor(&self) -> ConvolveProcessor {
+        ConvolveProcessor::new(ConvolveProcessorOptions {
+            kernel: self.options.kernel.clone(),
+            normalize: self.options.normalize,
+            divisor: self.options.divisor,
+            bias: self.options.bias,
+            anchor: self.options.anchor,
+            channels: self.options.channels,
+        })
+    }
+}
+
+const CONVOLVE_PROCESSOR_NAME: &str = "Convolve";
+
+impl Pipeline for ConvolvePipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(CONVOLVE_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = self.create_processor();
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
+}
@@ -1,6 +1,7 @@
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::pipeline::PipelinePicturifyResult;
 use picturify_core::palette::Srgba;
+use picturify_processing::common::processors::CpuProcessor;
 use picturify_processing::processors::geometry::crop::{CropBorder, CropProcessorOptions};
 use picturify_processing::processors::geometry::enlargement::{
     EnlargementBorder, EnlargementProcessorOptions, EnlargementStrategy,
@@ -29,6 +30,14 @@ impl GaussianBlurPipeline {
     pub fn new(options: GaussianBlurPipelineOptions) -> Self {
         Self { options }
     }
+
+    fn create_processor(&self) -> GaussianBlurProcessor {
+        GaussianBlurProcessor::new(GaussianBlurProcessorOptions {
+            radius: self.options.radius,
+            sigma: self.options.sigma,
+            use_fast_approximation: self.options.fast,
+        })
+    }
 }
 
 const GAUSSIAN_BLUR_PROCESSOR_NAME: &str = "GaussianBlur";
@@ -39,11 +48,7 @@ impl Pipeline for GaussianBlurPipeline {
         image: FastImage,
         pipeline_progress: Option<PipelineProgress>,
     ) -> PipelinePicturifyResult<FastImage> {
-        let processor = GaussianBlurProcessor::new(GaussianBlurProcessorOptions {
-            radius: self.options.radius,
-            sigma: self.options.sigma,
-            use_fast_approximation: self.options.fast,
-        });
+        let processor = self.create_processor();
         let (width, height) = image.size().into();
         let pipeline = EnlargementCropPipeline::new(EnlargementCropPipelineOptions {
             fast: self.options.fast,
@@ -65,4 +70,8 @@ impl Pipeline for GaussianBlurPipeline {
 
         pipeline.run(image, pipeline_progress)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
 }
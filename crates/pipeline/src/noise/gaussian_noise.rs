@@ -0,0 +1,50 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::gaussian_noise::{
+    GaussianNoiseProcessor, GaussianNoiseProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct GaussianNoisePipelineOptions {
+    pub sigma: f32,
+    pub seed: Option<u64>,
+}
+
+pub struct GaussianNoisePipeline {
+    options: GaussianNoisePipelineOptions,
+}
+
+impl GaussianNoisePipeline {
+    pub fn new(options: GaussianNoisePipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const GAUSSIAN_NOISE_PROCESSOR_NAME: &str = "GaussianNoise";
+
+impl Pipeline for GaussianNoisePipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(GAUSSIAN_NOISE_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = GaussianNoiseProcessor::new(GaussianNoiseProcessorOptions {
+            sigma: self.options.sigma,
+            seed: self.options.seed,
+        });
+
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+}
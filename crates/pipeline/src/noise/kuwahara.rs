@@ -1,12 +1,19 @@
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::pipeline::PipelinePicturifyResult;
 use picturify_core::palette::Srgba;
+use picturify_processing::common::processors::CpuProcessor;
 use picturify_processing::processors::geometry::crop::{CropBorder, CropProcessorOptions};
 use picturify_processing::processors::geometry::enlargement::{
     EnlargementBorder, EnlargementProcessorOptions, EnlargementStrategy,
 };
+use picturify_processing::processors::noise::anisotropic_kuwahara::{
+    AnisotropicKuwaharaProcessor, AnisotropicKuwaharaProcessorOptions,
+};
+use picturify_processing::processors::noise::generalized_kuwahara::{
+    GeneralizedKuwaharaProcessor, GeneralizedKuwaharaProcessorOptions,
+};
 use picturify_processing::processors::noise::kuwahara::{
-    KuwaharaProcessor, KuwaharaProcessorOptions,
+    KuwaharaMethod, KuwaharaProcessor, KuwaharaProcessorOptions,
 };
 
 use crate::common::enlargement_crop_pipeline::{
@@ -15,9 +22,16 @@ use crate::common::enlargement_crop_pipeline::{
 use crate::common::pipeline_progress::PipelineProgress;
 use crate::pipeline::Pipeline;
 
+// window stretch and tensor smoothing suggested by Kyprianidis et al.
+const ANISOTROPIC_ECCENTRICITY: f32 = 1.0;
+const ANISOTROPIC_TENSOR_SIGMA: f32 = 2.0;
+
 pub struct KuwaharaPipelineOptions {
     pub fast: bool,
+    pub method: KuwaharaMethod,
     pub radius: usize,
+    pub sectors: usize,
+    pub sharpness: f32,
 }
 
 pub struct KuwaharaPipeline {
@@ -28,6 +42,30 @@ impl KuwaharaPipeline {
     pub fn new(options: KuwaharaPipelineOptions) -> Self {
         Self { options }
     }
+
+    fn create_processor(&self) -> Box<dyn CpuProcessor> {
+        match self.options.method {
+            KuwaharaMethod::Classic => Box::new(KuwaharaProcessor::new(KuwaharaProcessorOptions {
+                radius: self.options.radius,
+            })),
+            KuwaharaMethod::Generalized => Box::new(GeneralizedKuwaharaProcessor::new(
+                GeneralizedKuwaharaProcessorOptions {
+                    radius: self.options.radius,
+                    sectors: self.options.sectors,
+                    sharpness: self.options.sharpness,
+                },
+            )),
+            KuwaharaMethod::Anisotropic => Box::new(AnisotropicKuwaharaProcessor::new(
+                AnisotropicKuwaharaProcessorOptions {
+                    radius: self.options.radius,
+                    sectors: self.options.sectors,
+                    sharpness: self.options.sharpness,
+                    eccentricity: ANISOTROPIC_ECCENTRICITY,
+                    tensor_sigma: ANISOTROPIC_TENSOR_SIGMA,
+                },
+            )),
+        }
+    }
 }
 
 const KUWAHARA_PROCESSOR_NAME: &str = "Kuwahara";
@@ -38,14 +76,25 @@ impl Pipeline for KuwaharaPipeline {
         image: FastImage,
         pipeline_progress: Option<PipelineProgress>,
     ) -> PipelinePicturifyResult<FastImage> {
-        let processor = KuwaharaProcessor::new(KuwaharaProcessorOptions {
-            radius: self.options.radius,
-        });
+        let processor = self.create_processor();
+        // sector based methods repeat the image edges themselves
+        if self.options.method != KuwaharaMethod::Classic {
+            let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+            pipeline_progress.new_individual(KUWAHARA_PROCESSOR_NAME.to_string());
+            pipeline_progress.setup_combined(1);
+
+            let final_image =
+                processor.process(image, pipeline_progress.get_current_individual_progress())?;
+            pipeline_progress.increment_combined();
+
+            return Ok(final_image);
+        }
+
         let (width, height) = image.size().into();
         let pipeline = EnlargementCropPipeline::new(EnlargementCropPipelineOptions {
             fast: self.options.fast,
             processor_name: KUWAHARA_PROCESSOR_NAME.to_string(),
-            processor: Box::new(processor),
+            processor,
             enlargement_processor_options: EnlargementProcessorOptions {
                 strategy: EnlargementStrategy::Constant(Srgba::new(0.0, 0.0, 0.0, 1.0).into()),
                 border: EnlargementBorder::from_all(self.options.radius),
@@ -62,4 +111,8 @@ impl Pipeline for KuwaharaPipeline {
 
         pipeline.run(image, pipeline_progress)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
 }
@@ -1,6 +1,7 @@
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::pipeline::PipelinePicturifyResult;
 use picturify_core::palette::Srgba;
+use picturify_processing::common::processors::CpuProcessor;
 use picturify_processing::processors::geometry::crop::{CropBorder, CropProcessorOptions};
 use picturify_processing::processors::geometry::enlargement::{
     EnlargementBorder, EnlargementProcessorOptions, EnlargementStrategy,
@@ -28,6 +29,13 @@ impl MeanBlurPipeline {
     pub fn new(options: MeanBlurPipelineOptions) -> Self {
         Self { options }
     }
+
+    fn create_processor(&self) -> MeanBlurProcessor {
+        MeanBlurProcessor::new(MeanBlurProcessorOptions {
+            radius: self.options.radius,
+            use_fast_approximation: self.options.fast,
+        })
+    }
 }
 
 const MEAN_BLUR_PROCESSOR_NAME: &str = "MeanBlur";
@@ -38,10 +46,7 @@ impl Pipeline for MeanBlurPipeline {
         image: FastImage,
         pipeline_progress: Option<PipelineProgress>,
     ) -> PipelinePicturifyResult<FastImage> {
-        let processor = MeanBlurProcessor::new(MeanBlurProcessorOptions {
-            radius: self.options.radius,
-            use_fast_approximation: self.options.fast,
-        });
+        let processor = self.create_processor();
         let (width, height) = image.size().into();
         let pipeline = EnlargementCropPipeline::new(EnlargementCropPipelineOptions {
             fast: self.options.fast,
@@ -63,4 +68,8 @@ impl Pipeline for MeanBlurPipeline {
 
         pipeline.run(image, pipeline_progress)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
 }
@@ -1,6 +1,7 @@
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::pipeline::PipelinePicturifyResult;
 use picturify_core::palette::Srgba;
+use picturify_processing::common::processors::CpuProcessor;
 use picturify_processing::processors::geometry::crop::{CropBorder, CropProcessorOptions};
 use picturify_processing::processors::geometry::enlargement::{
     EnlargementBorder, EnlargementProcessorOptions, EnlargementStrategy,
@@ -28,6 +29,12 @@ impl MedianBlurPipeline {
     pub fn new(options: MedianBlurPipelineOptions) -> Self {
         Self { options }
     }
+
+    fn create_processor(&self) -> MedianBlurProcessor {
+        MedianBlurProcessor::new(MedianBlurProcessorOptions {
+            radius: self.options.radius,
+        })
+    }
 }
 
 const MEDIAN_BLUR_PROCESSOR_NAME: &str = "MedianBlur";
@@ -38,9 +45,7 @@ impl Pipeline for MedianBlurPipeline {
         image: FastImage,
         pipeline_progress: Option<PipelineProgress>,
     ) -> PipelinePicturifyResult<FastImage> {
-        let processor = MedianBlurProcessor::new(MedianBlurProcessorOptions {
-            radius: self.options.radius,
-        });
+        let processor = self.create_processor();
         let (width, height) = image.size().into();
         let pipeline = EnlargementCropPipeline::new(EnlargementCropPipelineOptions {
             fast: self.options.fast,
@@ -62,4 +67,8 @@ impl Pipeline for MedianBlurPipeline {
 
         pipeline.run(image, pipeline_progress)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
 }
@@ -0,0 +1,58 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::geometry::angle::Angle;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::motion_blur::{
+    MotionBlurProcessor, MotionBlurProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct MotionBlurPipelineOptions {
+    pub angle: Angle,
+    pub length: f32,
+}
+
+pub struct MotionBlurPipeline {
+    options: MotionBlurPipelineOptions,
+}
+
+impl MotionBlurPipeline {
+    pub fn new(options: MotionBlurPipelineOptions) -> Self {
+        Self { options }
+    }
+
+    fn create_processor(&self) -> MotionBlurProcessor {
+        MotionBlurProcessor::new(MotionBlurProcessorOptions {
+            angle: self.options.angle,
+            length: self.options.length,
+        })
+    }
+}
+
+const MOTION_BLUR_PROCESSOR_NAME: &str = "MotionBlur";
+
+impl Pipeline for MotionBlurPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(MOTION_BLUR_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = self.create_processor();
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
+}
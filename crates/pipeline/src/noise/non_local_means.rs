@@ -0,0 +1,59 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::non_local_means::{
+    NonLocalMeansProcessor, NonLocalMeansProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct NonLocalMeansPipelineOptions {
+    pub search_radius: usize,
+    pub patch_radius: usize,
+    pub strength: f32,
+}
+
+pub struct NonLocalMeansPipeline {
+    options: NonLocalMeansPipelineOptions,
+}
+
+impl NonLocalMeansPipeline {
+    pub fn new(options: NonLocalMeansPipelineOptions) -> Self {
+        Self { options }
+    }
+
+    fn create_processor(&self) -> NonLocalMeansProcessor {
+        NonLocalMeansProcessor::new(NonLocalMeansProcessorOptions {
+            search_radius: self.options.search_radius,
+            patch_radius: self.options.patch_radius,
+            strength: self.options.strength,
+        })
+    }
+}
+
+const NON_LOCAL_MEANS_PROCESSOR_NAME: &str = "NonLocalMeans";
+
+impl Pipeline for NonLocalMeansPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(NON_LOCAL_MEANS_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = self.create_processor();
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
+}
@@ -0,0 +1,50 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::poisson_noise::{
+    PoissonNoiseProcessor, PoissonNoiseProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct PoissonNoisePipelineOptions {
+    pub scale: f32,
+    pub seed: Option<u64>,
+}
+
+pub struct PoissonNoisePipeline {
+    options: PoissonNoisePipelineOptions,
+}
+
+impl PoissonNoisePipeline {
+    pub fn new(options: PoissonNoisePipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const POISSON_NOISE_PROCESSOR_NAME: &str = "PoissonNoise";
+
+impl Pipeline for PoissonNoisePipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(POISSON_NOISE_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = PoissonNoiseProcessor::new(PoissonNoiseProcessorOptions {
+            scale: self.options.scale,
+            seed: self.options.seed,
+        });
+
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+}
@@ -0,0 +1,55 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::geometry::angle::Angle;
+use picturify_core::geometry::coord::Coord;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::radial_blur::{
+    RadialBlurProcessor, RadialBlurProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct RadialBlurPipelineOptions {
+    pub center: Option<Coord>,
+    pub angle: Angle,
+}
+
+pub struct RadialBlurPipeline {
+    options: RadialBlurPipelineOptions,
+}
+
+impl RadialBlurPipeline {
+    pub fn new(options: RadialBlurPipelineOptions) -> Self {
+        Self { options }
+    }
+
+    fn create_processor(&self) -> RadialBlurProcessor {
+        RadialBlurProcessor::new(RadialBlurProcessorOptions {
+            center: self.options.center,
+            angle: self.options.angle,
+        })
+    }
+}
+
+const RADIAL_BLUR_PROCESSOR_NAME: &str = "RadialBlur";
+
+impl Pipeline for RadialBlurPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(RADIAL_BLUR_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = self.create_processor();
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+}
@@ -0,0 +1,52 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::salt_and_pepper_noise::{
+    SaltAndPepperNoiseProcessor, SaltAndPepperNoiseProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct SaltAndPepperNoisePipelineOptions {
+    pub amount: f32,
+    pub salt_ratio: f32,
+    pub seed: Option<u64>,
+}
+
+pub struct SaltAndPepperNoisePipeline {
+    options: SaltAndPepperNoisePipelineOptions,
+}
+
+impl SaltAndPepperNoisePipeline {
+    pub fn new(options: SaltAndPepperNoisePipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const SALT_AND_PEPPER_NOISE_PROCESSOR_NAME: &str = "SaltAndPepperNoise";
+
+impl Pipeline for SaltAndPepperNoisePipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(SALT_AND_PEPPER_NOISE_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = SaltAndPepperNoiseProcessor::new(SaltAndPepperNoiseProcessorOptions {
+            amount: self.options.amount,
+            salt_ratio: self.options.salt_ratio,
+            seed: self.options.seed,
+        });
+
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+}
@@ -1,11 +1,14 @@
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::pipeline::PipelinePicturifyResult;
 use picturify_core::palette::Srgba;
+use picturify_processing::common::processors::CpuProcessor;
 use picturify_processing::processors::geometry::crop::{CropBorder, CropProcessorOptions};
 use picturify_processing::processors::geometry::enlargement::{
     EnlargementBorder, EnlargementProcessorOptions, EnlargementStrategy,
 };
-use picturify_processing::processors::noise::sharpen::{SharpenProcessor, SharpenProcessorOptions};
+use picturify_processing::processors::noise::sharpen::{
+    SharpenMethod, SharpenProcessor, SharpenProcessorOptions,
+};
 
 use crate::common::enlargement_crop_pipeline::{
     EnlargementCropPipeline, EnlargementCropPipelineOptions,
@@ -14,6 +17,12 @@ use crate::common::pipeline_progress::PipelineProgress;
 use crate::pipeline::Pipeline;
 
 pub struct SharpenPipelineOptions {
+    pub method: SharpenMethod,
+    pub radius: usize,
+    pub sigma: f32,
+    pub amount: f32,
+    pub threshold: f32,
+    pub luminance_only: bool,
     pub fast: bool,
 }
 
@@ -25,6 +34,18 @@ impl SharpenPipeline {
     pub fn new(options: SharpenPipelineOptions) -> Self {
         Self { options }
     }
+
+    fn create_processor(&self) -> SharpenProcessor {
+        SharpenProcessor::new(SharpenProcessorOptions {
+            method: self.options.method,
+            radius: self.options.radius,
+            sigma: self.options.sigma,
+            amount: self.options.amount,
+            threshold: self.options.threshold,
+            luminance_only: self.options.luminance_only,
+            use_fast_approximation: self.options.fast,
+        })
+    }
 }
 
 const SHARPEN_PROCESSOR_NAME: &str = "Sharpen";
@@ -35,9 +56,20 @@ impl Pipeline for SharpenPipeline {
         image: FastImage,
         pipeline_progress: Option<PipelineProgress>,
     ) -> PipelinePicturifyResult<FastImage> {
-        let processor = SharpenProcessor::new(SharpenProcessorOptions {
-            use_fast_approximation: self.options.fast,
-        });
+        let processor = self.create_processor();
+        // blurring methods repeat the image edges themselves
+        if self.options.method != SharpenMethod::Kernel {
+            let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+            pipeline_progress.new_individual(SHARPEN_PROCESSOR_NAME.to_string());
+            pipeline_progress.setup_combined(1);
+
+            let final_image =
+                processor.process(image, pipeline_progress.get_current_individual_progress())?;
+            pipeline_progress.increment_combined();
+
+            return Ok(final_image);
+        }
+
         let (width, height) = image.size().into();
         let pipeline = EnlargementCropPipeline::new(EnlargementCropPipelineOptions {
             fast: self.options.fast,
@@ -54,4 +86,8 @@ impl Pipeline for SharpenPipeline {
 
         pipeline.run(image, pipeline_progress)
     }
+
+    fn tile_overlap(&self) -> Option<usize> {
+        Some(self.create_processor().kernel_radius())
+    }
 }
@@ -0,0 +1,50 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::speckle_noise::{
+    SpeckleNoiseProcessor, SpeckleNoiseProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct SpeckleNoisePipelineOptions {
+    pub sigma: f32,
+    pub seed: Option<u64>,
+}
+
+pub struct SpeckleNoisePipeline {
+    options: SpeckleNoisePipelineOptions,
+}
+
+impl SpeckleNoisePipeline {
+    pub fn new(options: SpeckleNoisePipelineOptions) -> Self {
+        Self { options }
+    }
+}
+
+const SPECKLE_NOISE_PROCESSOR_NAME: &str = "SpeckleNoise";
+
+impl Pipeline for SpeckleNoisePipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(SPECKLE_NOISE_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = SpeckleNoiseProcessor::new(SpeckleNoiseProcessorOptions {
+            sigma: self.options.sigma,
+            seed: self.options.seed,
+        });
+
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+}
@@ -0,0 +1,54 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::geometry::coord::Coord;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::zoom_blur::{
+    ZoomBlurProcessor, ZoomBlurProcessorOptions,
+};
+
+use crate::common::pipeline_progress::PipelineProgress;
+use crate::pipeline::Pipeline;
+
+pub struct ZoomBlurPipelineOptions {
+    pub center: Option<Coord>,
+    pub strength: f32,
+}
+
+pub struct ZoomBlurPipeline {
+    options: ZoomBlurPipelineOptions,
+}
+
+impl ZoomBlurPipeline {
+    pub fn new(options: ZoomBlurPipelineOptions) -> Self {
+        Self { options }
+    }
+
+    fn create_processor(&self) -> ZoomBlurProcessor {
+        ZoomBlurProcessor::new(ZoomBlurProcessorOptions {
+            center: self.options.center,
+            strength: self.options.strength,
+        })
+    }
+}
+
+const ZOOM_BLUR_PROCESSOR_NAME: &str = "ZoomBlur";
+
+impl Pipeline for ZoomBlurPipeline {
+    fn run(
+        &self,
+        image: FastImage,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        let mut pipeline_progress = pipeline_progress.unwrap_or_default();
+
+        pipeline_progress.new_individual(ZOOM_BLUR_PROCESSOR_NAME.to_string());
+        pipeline_progress.setup_combined(1);
+
+        let processor = self.create_processor();
+        let final_image =
+            processor.process(image, pipeline_progress.get_current_individual_progress())?;
+        pipeline_progress.increment_combined();
+
+        Ok(final_image)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::common::pipeline_progress::PipelineProgress;
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::pipeline::PipelinePicturifyResult;
+use picturify_core::geometry::size::Size;
 
 pub trait Pipeline {
     fn run(
@@ -8,4 +9,18 @@ pub trait Pipeline {
         image: FastImage,
         pipeline_progress: Option<PipelineProgress>,
     ) -> PipelinePicturifyResult<FastImage>;
+    // runs on a tile whose first row is `row` of the whole image of `image_size`
+    fn run_tile(
+        &self,
+        image: FastImage,
+        _row: usize,
+        _image_size: Size,
+        pipeline_progress: Option<PipelineProgress>,
+    ) -> PipelinePicturifyResult<FastImage> {
+        self.run(image, pipeline_progress)
+    }
+    // rows of context each tile needs from its neighbours, None if the pipeline can't be tiled
+    fn tile_overlap(&self) -> Option<usize> {
+        None
+    }
 }
@@ -0,0 +1,78 @@
+use picturify_core::conversions::icc::{
+    apply, bradford_adaptation, multiply, xy_to_xyz, Matrix, D65_WHITE,
+};
+
+const MIN_TEMPERATURE: f32 = 1667.0;
+const MAX_TEMPERATURE: f32 = 25000.0;
+// a tint of 100 moves the white point by 0.033 in CIE 1960 v
+const TINT_SCALE: f32 = 1.0 / 3000.0;
+
+const LINEAR_SRGB_TO_XYZ: Matrix = [
+    [0.4124564, 0.3575761, 0.1804375],
+    [0.2126729, 0.7151522, 0.0721750],
+    [0.0193339, 0.119192, 0.9503041],
+];
+
+const XYZ_TO_LINEAR_SRGB: Matrix = [
+    [3.2404542, -1.5371385, -0.4985314],
+    [-0.969266, 1.8760108, 0.041556],
+    [0.0556434, -0.2040259, 1.0572252],
+];
+
+// Linear sRGB transform that maps the given neutral color to the D65 white
+pub fn adaptation_from_neutral(neutral: [f32; 3]) -> Option<Matrix> {
+    let white = apply(&LINEAR_SRGB_TO_XYZ, neutral);
+    if white[1] <= f32::EPSILON {
+        return None;
+    }
+
+    Some(adaptation_from_white(white.map(|v| v / white[1])))
+}
+
+// Linear sRGB transform that maps a light source of the given temperature in Kelvin
+// to the D65 white, positive tint removes a green cast
+pub fn adaptation_from_temperature(temperature: f32, tint: f32) -> Matrix {
+    let (x, y) = temperature_to_chromaticity(temperature);
+
+    let denominator = -2.0 * x + 12.0 * y + 3.0;
+    let u = 4.0 * x / denominator;
+    let v = 6.0 * y / denominator + tint * TINT_SCALE;
+
+    let denominator = 2.0 * u - 8.0 * v + 4.0;
+    let white = xy_to_xyz((3.0 * u / denominator, 2.0 * v / denominator));
+
+    adaptation_from_white(white)
+}
+
+fn adaptation_from_white(source_white: [f32; 3]) -> Matrix {
+    let cone_adaptation = bradford_adaptation(source_white, xy_to_xyz(D65_WHITE));
+    multiply(
+        &XYZ_TO_LINEAR_SRGB,
+        &multiply(&cone_adaptation, &LINEAR_SRGB_TO_XYZ),
+    )
+}
+
+// CIE daylight locus above 4000K, Planckian locus approximation below
+fn temperature_to_chromaticity(temperature: f32) -> (f32, f32) {
+    let t = temperature.clamp(MIN_TEMPERATURE, MAX_TEMPERATURE);
+    let (t2, t3) = (t * t, t * t * t);
+
+    if t >= 4000.0 {
+        let x = if t <= 7000.0 {
+            -4.6070e9 / t3 + 2.9678e6 / t2 + 0.09911e3 / t + 0.244063
+        } else {
+            -2.0064e9 / t3 + 1.9018e6 / t2 + 0.24748e3 / t + 0.237040
+        };
+        let y = -3.0 * x * x + 2.87 * x - 0.275;
+        (x, y)
+    } else {
+        let x = -0.2661239e9 / t3 - 0.2343589e6 / t2 + 0.8776956e3 / t + 0.179910;
+        let (x2, x3) = (x * x, x * x * x);
+        let y = if t <= 2222.0 {
+            -1.1063814 * x3 - 1.3481102 * x2 + 2.1855583 * x - 0.2021968
+        } else {
+            -0.9549476 * x3 - 1.3741859 * x2 + 2.09137 * x - 0.1674887
+        };
+        (x, y)
+    }
+}
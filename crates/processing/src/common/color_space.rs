@@ -0,0 +1,174 @@
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use picturify_core::palette::{FromColor, Hsl, Hsv, Lab, Lch, Oklab, Oklch, Srgb, Xyz};
+
+// D65 reference white, used to bring XYZ into the 0..1 range
+const XYZ_WHITE: [f32; 3] = [0.95047, 1.0, 1.08883];
+const LAB_AB_RANGE: f32 = 128.0;
+const LCH_MAX_CHROMA: f32 = 150.0;
+const OKLAB_AB_RANGE: f32 = 0.5;
+const OKLCH_MAX_CHROMA: f32 = 0.4;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ColorSpace {
+    Rgb,
+    Hsv,
+    Hsl,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+    Ycbcr,
+    Xyz,
+}
+
+impl ValueEnum for ColorSpace {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            ColorSpace::Rgb,
+            ColorSpace::Hsv,
+            ColorSpace::Hsl,
+            ColorSpace::Lab,
+            ColorSpace::Lch,
+            ColorSpace::Oklab,
+            ColorSpace::Oklch,
+            ColorSpace::Ycbcr,
+            ColorSpace::Xyz,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            ColorSpace::Rgb => Some(PossibleValue::new("rgb")),
+            ColorSpace::Hsv => Some(PossibleValue::new("hsv")),
+            ColorSpace::Hsl => Some(PossibleValue::new("hsl")),
+            ColorSpace::Lab => Some(PossibleValue::new("lab")),
+            ColorSpace::Lch => Some(PossibleValue::new("lch")),
+            ColorSpace::Oklab => Some(PossibleValue::new("oklab")),
+            ColorSpace::Oklch => Some(PossibleValue::new("oklch")),
+            ColorSpace::Ycbcr => Some(PossibleValue::new("ycbcr")),
+            ColorSpace::Xyz => Some(PossibleValue::new("xyz")),
+        }
+    }
+}
+
+impl ColorSpace {
+    // Converts an sRGB color into the three channels of the space, each mapped to 0..1
+    // so that they can be stored as grayscale images
+    pub fn to_channels(&self, rgb: Srgb) -> [f32; 3] {
+        match self {
+            ColorSpace::Rgb => [rgb.red, rgb.green, rgb.blue],
+            ColorSpace::Hsv => {
+                let hsv = Hsv::from_color(rgb);
+                [
+                    hsv.hue.into_positive_degrees() / 360.0,
+                    hsv.saturation,
+                    hsv.value,
+                ]
+            }
+            ColorSpace::Hsl => {
+                let hsl = Hsl::from_color(rgb);
+                [
+                    hsl.hue.into_positive_degrees() / 360.0,
+                    hsl.saturation,
+                    hsl.lightness,
+                ]
+            }
+            ColorSpace::Lab => {
+                let lab: Lab = Lab::from_color(rgb);
+                [
+                    lab.l / 100.0,
+                    (lab.a + LAB_AB_RANGE) / (2.0 * LAB_AB_RANGE),
+                    (lab.b + LAB_AB_RANGE) / (2.0 * LAB_AB_RANGE),
+                ]
+            }
+            ColorSpace::Lch => {
+                let lch: Lch = Lch::from_color(rgb);
+                [
+                    lch.l / 100.0,
+                    lch.chroma / LCH_MAX_CHROMA,
+                    lch.hue.into_positive_degrees() / 360.0,
+                ]
+            }
+            ColorSpace::Oklab => {
+                let oklab = Oklab::from_color(rgb);
+                [
+                    oklab.l,
+                    (oklab.a + OKLAB_AB_RANGE) / (2.0 * OKLAB_AB_RANGE),
+                    (oklab.b + OKLAB_AB_RANGE) / (2.0 * OKLAB_AB_RANGE),
+                ]
+            }
+            ColorSpace::Oklch => {
+                let oklch = Oklch::from_color(rgb);
+                [
+                    oklch.l,
+                    oklch.chroma / OKLCH_MAX_CHROMA,
+                    oklch.hue.into_positive_degrees() / 360.0,
+                ]
+            }
+            ColorSpace::Ycbcr => {
+                // full range BT.601, as used by JPEG
+                let y = 0.299 * rgb.red + 0.587 * rgb.green + 0.114 * rgb.blue;
+                let cb = 0.5 - 0.168736 * rgb.red - 0.331264 * rgb.green + 0.5 * rgb.blue;
+                let cr = 0.5 + 0.5 * rgb.red - 0.418688 * rgb.green - 0.081312 * rgb.blue;
+                [y, cb, cr]
+            }
+            ColorSpace::Xyz => {
+                let xyz: Xyz = Xyz::from_color(rgb);
+                [
+                    xyz.x / XYZ_WHITE[0],
+                    xyz.y / XYZ_WHITE[1],
+                    xyz.z / XYZ_WHITE[2],
+                ]
+            }
+        }
+    }
+
+    pub fn from_channels(&self, channels: [f32; 3]) -> Srgb {
+        let [first, second, third] = channels;
+        let rgb = match self {
+            ColorSpace::Rgb => Srgb::new(first, second, third),
+            ColorSpace::Hsv => Srgb::from_color(Hsv::new(first * 360.0, second, third)),
+            ColorSpace::Hsl => Srgb::from_color(Hsl::new(first * 360.0, second, third)),
+            ColorSpace::Lab => Srgb::from_color(Lab::new(
+                first * 100.0,
+                second * 2.0 * LAB_AB_RANGE - LAB_AB_RANGE,
+                third * 2.0 * LAB_AB_RANGE - LAB_AB_RANGE,
+            )),
+            ColorSpace::Lch => Srgb::from_color(Lch::new(
+                first * 100.0,
+                second * LCH_MAX_CHROMA,
+                third * 360.0,
+            )),
+            ColorSpace::Oklab => Srgb::from_color(Oklab::new(
+                first,
+                second * 2.0 * OKLAB_AB_RANGE - OKLAB_AB_RANGE,
+                third * 2.0 * OKLAB_AB_RANGE - OKLAB_AB_RANGE,
+            )),
+            ColorSpace::Oklch => {
+                Srgb::from_color(Oklch::new(first, second * OKLCH_MAX_CHROMA, third * 360.0))
+            }
+            ColorSpace::Ycbcr => {
+                let (cb, cr) = (second - 0.5, third - 0.5);
+                Srgb::new(
+                    first + 1.402 * cr,
+                    first - 0.344136 * cb - 0.714136 * cr,
+                    first + 1.772 * cb,
+                )
+            }
+            ColorSpace::Xyz => Srgb::from_color(Xyz::new(
+                first * XYZ_WHITE[0],
+                second * XYZ_WHITE[1],
+                third * XYZ_WHITE[2],
+            )),
+        };
+
+        Srgb::new(
+            rgb.red.clamp(0.0, 1.0),
+            rgb.green.clamp(0.0, 1.0),
+            rgb.blue.clamp(0.0, 1.0),
+        )
+    }
+}
@@ -0,0 +1,55 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::plane_image::{PlaneImage, PlaneImageF32};
+use picturify_core::palette::{FromColor, Lab, Srgb};
+
+use crate::common::sharpening::gaussian_blur_plane;
+
+// Lab lightness runs from 0 to 100
+const LIGHTNESS_SCALE: f32 = 255.0 / 100.0;
+
+// Lightness blurred with two gaussians, the wide one being k times the narrow one, in 0-255 units
+pub struct DifferenceOfGaussians {
+    narrow: PlaneImageF32,
+    wide: PlaneImageF32,
+}
+
+impl DifferenceOfGaussians {
+    pub fn new(image: &FastImage, sigma: f32, k: f32) -> Self {
+        let lightness = PlaneImage::from_fn(image.size(), |coord| {
+            let [red, green, blue] =
+                [0, 1, 2].map(|channel| image.get_image_pixel(coord).0[channel] as f32);
+            Lab::from_color(Srgb::new(red, green, blue) / 255.0).l * LIGHTNESS_SCALE
+        });
+
+        let sigma = sigma.max(f32::EPSILON);
+        let wide_sigma = sigma * k.max(1.0);
+        Self {
+            narrow: gaussian_blur_plane(&lightness, radius(sigma), sigma),
+            wide: gaussian_blur_plane(&lightness, radius(wide_sigma), wide_sigma),
+        }
+    }
+
+    // how far the wide gaussian reaches
+    pub fn kernel_radius(sigma: f32, k: f32) -> usize {
+        radius(sigma.max(f32::EPSILON) * k.max(1.0))
+    }
+
+    // (1 + sharpness) * narrow - sharpness * wide, the plain difference scaled by the
+    // sharpness and added to the narrow blur
+    pub fn sharpened(&self, sharpness: f32) -> PlaneImageF32 {
+        PlaneImage::from_fn(self.narrow.size(), |coord| {
+            let narrow = self.narrow.get(coord);
+            narrow + sharpness * (narrow - self.wide.get(coord))
+        })
+    }
+
+    pub fn difference(&self) -> PlaneImageF32 {
+        PlaneImage::from_fn(self.narrow.size(), |coord| {
+            self.narrow.get(coord) - self.wide.get(coord)
+        })
+    }
+}
+
+fn radius(sigma: f32) -> usize {
+    (3.0 * sigma).ceil() as usize
+}
@@ -0,0 +1,10 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::size::Size;
+use picturify_core::threading::progress::Progress;
+
+// creates an image from scratch instead of transforming an existing one
+pub trait CpuGenerator {
+    fn name(&self) -> &'static str;
+    fn generate(&self, size: Size, progress: Progress) -> ProcessingPicturifyResult<FastImage>;
+}
@@ -1,13 +1,20 @@
 use crate::common::functions::gaussian_2d;
 use core::fmt;
 use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::ReadFromFile;
 use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::error::{PicturifyError, PicturifyResult};
 use picturify_core::geometry::coord::Coord;
 use picturify_core::geometry::size::Size;
 use picturify_core::image::Rgba;
 use picturify_core::palette::LinSrgba;
-use std::fmt::Display;
+use picturify_core::pixel::alpha::PremultipliedAlpha;
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub(crate) const KERNEL_SUM_EPSILON: f32 = 1e-3;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ConvolutionKernel {
@@ -18,7 +25,10 @@ pub struct ConvolutionKernel {
 
 impl ConvolutionKernel {
     pub fn new(values: Vec<Vec<f32>>) -> ProcessingPicturifyResult<Self> {
-        let width = values[0].len();
+        let width = values.first().map_or(0, |row| row.len());
+        if width == 0 || values.iter().any(|row| row.len() != width) {
+            return Err(ProcessingPicturifyError::InvalidKernel);
+        }
         let height = values.len();
 
         let kernel = ConvolutionKernel {
@@ -35,7 +45,48 @@ impl ConvolutionKernel {
     }
 
     pub fn validate(&self) -> bool {
-        self.values.len() == self.width * self.height
+        self.width > 0 && self.height > 0 && self.values.len() == self.width * self.height
+    }
+
+    // rows are separated by ';' or new lines and values by ',' or whitespace, so
+    // "0,-1,0;-1,5,-1;0,-1,0" and CSV files both parse
+    pub fn from_text(text: &str) -> PicturifyResult<Self> {
+        let rows = text
+            .split([';', '\n'])
+            .map(str::trim)
+            .filter(|row| !row.is_empty() && !row.starts_with('#'))
+            .map(|row| {
+                row.split([',', ' ', '\t'])
+                    .filter(|value| !value.is_empty())
+                    .map(|value| {
+                        value
+                            .parse::<f32>()
+                            .map_err(|_| invalid_kernel(&format!("invalid value: {}", value)))
+                    })
+                    .collect()
+            })
+            .collect::<PicturifyResult<Vec<Vec<f32>>>>()?;
+
+        Self::from_rows(rows)
+    }
+
+    // either an array of rows or a serialized kernel
+    pub fn from_json(text: &str) -> PicturifyResult<Self> {
+        if let Ok(rows) = serde_json::from_str::<Vec<Vec<f32>>>(text) {
+            return Self::from_rows(rows);
+        }
+
+        let kernel = serde_json::from_str::<ConvolutionKernel>(text)
+            .map_err(|error| invalid_kernel(&error.to_string()))?;
+        if !kernel.validate() {
+            return Err(invalid_kernel("values do not match the width and height"));
+        }
+
+        Ok(kernel)
+    }
+
+    fn from_rows(rows: Vec<Vec<f32>>) -> PicturifyResult<Self> {
+        Self::new(rows).map_err(|_| invalid_kernel("expected a non-empty kernel with equal rows"))
     }
 
     pub fn new_mean(radius: usize) -> Self {
@@ -136,12 +187,23 @@ impl ConvolutionKernel {
         self.values[coord.array_index(self.width)]
     }
 
-    pub fn convolve_rgb_fast(&self, image: &FastImage, coord: Coord) -> Rgba<u8> {
-        let mut result_red_f32 = 0f32;
-        let mut result_green_f32 = 0f32;
-        let mut result_blue_f32 = 0f32;
+    pub fn sum(&self) -> f32 {
+        self.values.iter().sum()
+    }
+
+    // Kernel weighted sums of the premultiplied channels with the kernel cell `anchor` over
+    // `coord`, samples outside the image repeat the nearest edge pixel
+    pub fn convolve_premultiplied(
+        &self,
+        image: &FastImage,
+        coord: Coord,
+        anchor: (usize, usize),
+    ) -> [f32; 4] {
+        let mut sums = [0f32; 4];
 
         let (width, height) = self.size().into();
+        let (image_width, image_height): (usize, usize) = image.size().into();
+        let (x, y): (usize, usize) = coord.into();
 
         for i in 0..width {
             for j in 0..height {
@@ -152,29 +214,50 @@ impl ConvolutionKernel {
                     continue;
                 }
 
-                let (x, y): (usize, usize) = coord.into();
-                let inner_coord = (x + i - width / 2, y + j - height / 2).into();
-                let image_pixel = image.get_image_pixel(inner_coord);
-                result_red_f32 += image_pixel.0[0] as f32 * kernel_value;
-                result_green_f32 += image_pixel.0[1] as f32 * kernel_value;
-                result_blue_f32 += image_pixel.0[2] as f32 * kernel_value;
+                let inner_coord = (
+                    (x + i).saturating_sub(anchor.0).min(image_width - 1),
+                    (y + j).saturating_sub(anchor.1).min(image_height - 1),
+                )
+                    .into();
+                let image_pixel = image.get_image_pixel(inner_coord).premultiply();
+                for (sum, channel) in sums.iter_mut().zip(image_pixel.0) {
+                    *sum += channel as f32 * kernel_value;
+                }
             }
         }
 
-        let result_alpha = image.get_image_pixel(coord).0[3];
+        sums
+    }
+
+    // Colours are accumulated premultiplied by alpha so transparent neighbours don't
+    // darken edges. Kernels with a non-zero sum (blurs, sharpen) also filter alpha,
+    // derivative kernels keep the alpha of the centre pixel.
+    pub fn convolve_rgb_fast(&self, image: &FastImage, coord: Coord) -> Rgba<u8> {
+        let (width, height): (usize, usize) = self.size().into();
+        let [result_red_f32, result_green_f32, result_blue_f32, result_alpha_f32] =
+            self.convolve_premultiplied(image, coord, (width / 2, height / 2));
+        let kernel_sum = self.sum();
+
+        let result_alpha = match kernel_sum.abs() > KERNEL_SUM_EPSILON {
+            true => (result_alpha_f32 / kernel_sum).round().clamp(0.0, 255.0) as u8,
+            false => image.get_image_pixel(coord).0[3],
+        };
 
         Rgba([
-            result_red_f32.clamp(0.0, 255.0) as u8,
-            result_green_f32.clamp(0.0, 255.0) as u8,
-            result_blue_f32.clamp(0.0, 255.0) as u8,
+            result_red_f32.clamp(0.0, result_alpha as f32) as u8,
+            result_green_f32.clamp(0.0, result_alpha as f32) as u8,
+            result_blue_f32.clamp(0.0, result_alpha as f32) as u8,
             result_alpha,
         ])
+        .unpremultiply()
     }
 
     pub fn convolve_rgb_slow(&self, image: &FastImage, coord: Coord) -> LinSrgba {
         let mut result_red_f32 = 0f32;
         let mut result_green_f32 = 0f32;
         let mut result_blue_f32 = 0f32;
+        let mut result_alpha_f32 = 0f32;
+        let mut kernel_sum = 0f32;
 
         let (width, height) = self.size().into();
 
@@ -189,22 +272,28 @@ impl ConvolutionKernel {
 
                 let (x, y): (usize, usize) = coord.into();
                 let image_pixel_coord = (x + i - width / 2, y + j - height / 2).into();
-                let image_pixel = image.get_lin_srgba_pixel(image_pixel_coord);
+                let image_pixel = image.get_lin_srgba_pixel(image_pixel_coord).premultiply();
 
                 result_red_f32 += image_pixel.red * kernel_value;
                 result_green_f32 += image_pixel.green * kernel_value;
                 result_blue_f32 += image_pixel.blue * kernel_value;
+                result_alpha_f32 += image_pixel.alpha * kernel_value;
+                kernel_sum += kernel_value;
             }
         }
 
-        let result_alpha = image.get_lin_srgba_pixel(coord).alpha;
+        let result_alpha = match kernel_sum.abs() > KERNEL_SUM_EPSILON {
+            true => (result_alpha_f32 / kernel_sum).clamp(0.0, 1.0),
+            false => image.get_lin_srgba_pixel(coord).alpha,
+        };
 
         LinSrgba::new(
-            result_red_f32.clamp(0.0, 1.0),
-            result_green_f32.clamp(0.0, 1.0),
-            result_blue_f32.clamp(0.0, 1.0),
+            result_red_f32.clamp(0.0, result_alpha),
+            result_green_f32.clamp(0.0, result_alpha),
+            result_blue_f32.clamp(0.0, result_alpha),
             result_alpha,
         )
+        .unpremultiply()
     }
 }
 
@@ -224,3 +313,25 @@ impl Display for ConvolutionKernel {
         write!(f, "{}", result)
     }
 }
+
+impl ReadFromFile for ConvolutionKernel {
+    fn read_from_file<P>(path: P) -> PicturifyResult<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let is_json = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+
+        let text = read_to_string(path)?;
+        match is_json {
+            true => Self::from_json(&text),
+            false => Self::from_text(&text),
+        }
+    }
+}
+
+fn invalid_kernel(reason: &str) -> PicturifyError {
+    PicturifyError::InvalidKernel(reason.to_string())
+}
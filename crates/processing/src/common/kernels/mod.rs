@@ -1,4 +1,5 @@
 pub mod convolution;
 pub mod prewitt;
+pub mod scharr;
 pub mod sobel;
 pub mod xy;
@@ -0,0 +1,31 @@
+use crate::common::kernels::convolution::ConvolutionKernel;
+use crate::common::kernels::xy::XyKernels;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::utils::vec::rotate_left_2d;
+
+pub struct ScharrKernels;
+
+impl ScharrKernels {
+    pub fn create() -> ProcessingPicturifyResult<XyKernels> {
+        let x = ConvolutionKernel::new(Self::create_scharr_kernel_x())?;
+        let y = ConvolutionKernel::new(Self::create_scharr_kernel_y())?;
+
+        let kernel = XyKernels::new(x, y);
+
+        if kernel.validate() {
+            Ok(kernel)
+        } else {
+            Err(ProcessingPicturifyError::InvalidKernel)
+        }
+    }
+    fn create_scharr_kernel_x() -> Vec<Vec<f32>> {
+        vec![
+            vec![3.0, 0.0, -3.0],
+            vec![10.0, 0.0, -10.0],
+            vec![3.0, 0.0, -3.0],
+        ]
+    }
+    fn create_scharr_kernel_y() -> Vec<Vec<f32>> {
+        rotate_left_2d(Self::create_scharr_kernel_x())
+    }
+}
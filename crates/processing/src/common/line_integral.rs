@@ -0,0 +1,61 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::image::Rgba;
+
+// Averages the image along a path instead of convolving with a large kernel. The path maps
+// 0..=1 to image coordinates, it is sampled about once per pixel of its length with bilinear
+// interpolation, points outside the image take the nearest edge pixel.
+pub fn integrate_path<F>(image: &FastImage, length: f32, path: F) -> Rgba<u8>
+where
+    F: Fn(f32) -> (f32, f32),
+{
+    let samples = length.abs().ceil() as usize + 1;
+    let mut sum = [0.0f32; 4];
+    for sample in 0..samples {
+        let t = match samples {
+            1 => 0.5,
+            _ => sample as f32 / (samples - 1) as f32,
+        };
+        let (x, y) = path(t);
+        for (sum, value) in sum.iter_mut().zip(sample_premultiplied(image, x, y)) {
+            *sum += value;
+        }
+    }
+
+    let [red, green, blue, alpha] = sum;
+    if alpha <= 0.0 {
+        return Rgba([0, 0, 0, 0]);
+    }
+    let unpremultiply = |value: f32| (value / alpha * 255.0).round().clamp(0.0, 255.0) as u8;
+    Rgba([
+        unpremultiply(red),
+        unpremultiply(green),
+        unpremultiply(blue),
+        (alpha / samples as f32).round().clamp(0.0, 255.0) as u8,
+    ])
+}
+
+fn sample_premultiplied(image: &FastImage, x: f32, y: f32) -> [f32; 4] {
+    let (width, height): (usize, usize) = image.size().into();
+    let x = x.clamp(0.0, (width - 1) as f32);
+    let y = y.clamp(0.0, (height - 1) as f32);
+    let (left, top) = (x.floor() as usize, y.floor() as usize);
+    let (right, bottom) = ((left + 1).min(width - 1), (top + 1).min(height - 1));
+    let (fraction_x, fraction_y) = (x - left as f32, y - top as f32);
+
+    let corners = [
+        (left, top, (1.0 - fraction_x) * (1.0 - fraction_y)),
+        (right, top, fraction_x * (1.0 - fraction_y)),
+        (left, bottom, (1.0 - fraction_x) * fraction_y),
+        (right, bottom, fraction_x * fraction_y),
+    ];
+    let mut result = [0.0f32; 4];
+    for (x, y, weight) in corners {
+        let pixel = image.get_image_pixel((x, y).into());
+        let alpha = pixel.0[3] as f32;
+        for (result, value) in result.iter_mut().zip(pixel.0).take(3) {
+            *result += weight * value as f32 * alpha / 255.0;
+        }
+        result[3] += weight * alpha;
+    }
+    result
+}
@@ -0,0 +1,79 @@
+use picturify_core::core::apply_fn_to_pixels::{ApplyFnToImagePixels, ApplyFnToPalettePixels};
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::threading::progress::Progress;
+
+// entries of the float table, values in between are interpolated
+const FLOAT_LUT_SIZE: usize = 4096;
+
+// Tone function sampled for every 8-bit channel value
+pub struct ToneLut {
+    table: [u8; 256],
+}
+
+impl ToneLut {
+    pub fn new(function: impl Fn(f32) -> f32) -> Self {
+        let mut table = [0; 256];
+        for (i, value) in table.iter_mut().enumerate() {
+            *value = (function(i as f32 / 255.0).clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+        Self { table }
+    }
+
+    #[inline(always)]
+    pub fn get(&self, value: u8) -> u8 {
+        self.table[value as usize]
+    }
+}
+
+// Tone function sampled over 0..1 for float channel values
+pub struct FloatToneLut {
+    table: Vec<f32>,
+}
+
+impl FloatToneLut {
+    pub fn new(function: impl Fn(f32) -> f32) -> Self {
+        let table = (0..FLOAT_LUT_SIZE)
+            .map(|i| function(i as f32 / (FLOAT_LUT_SIZE - 1) as f32))
+            .collect();
+        Self { table }
+    }
+
+    #[inline(always)]
+    pub fn get(&self, value: f32) -> f32 {
+        let position = value.clamp(0.0, 1.0) * (FLOAT_LUT_SIZE - 1) as f32;
+        let index = (position as usize).min(FLOAT_LUT_SIZE - 2);
+        let t = position - index as f32;
+        self.table[index] * (1.0 - t) + self.table[index + 1] * t
+    }
+}
+
+// Applies one tone function per color channel, alpha is left untouched
+pub fn apply_tone_functions(
+    image: &mut FastImage,
+    functions: [&dyn Fn(f32) -> f32; 3],
+    use_fast_approximation: bool,
+    progress: Progress,
+) {
+    if use_fast_approximation {
+        let luts = functions.map(ToneLut::new);
+        image.par_apply_fn_to_image_pixel(
+            |pixel, _coord| {
+                for (channel, lut) in luts.iter().enumerate() {
+                    pixel.0[channel] = lut.get(pixel.0[channel]);
+                }
+            },
+            Some(progress),
+        );
+    } else {
+        let luts = functions.map(FloatToneLut::new);
+        image.par_apply_fn_to_srgba(
+            |mut pixel, _coord| {
+                pixel.red = luts[0].get(pixel.red).clamp(0.0, 1.0);
+                pixel.green = luts[1].get(pixel.green).clamp(0.0, 1.0);
+                pixel.blue = luts[2].get(pixel.blue).clamp(0.0, 1.0);
+                pixel
+            },
+            Some(progress),
+        );
+    }
+}
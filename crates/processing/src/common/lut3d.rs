@@ -0,0 +1,235 @@
+use std::fs::read_to_string;
+use std::path::Path;
+
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::ReadFromFile;
+use picturify_core::error::{PicturifyError, PicturifyResult};
+
+// largest lattice accepted, keeps a malformed header from allocating gigabytes
+const MAX_LUT_SIZE: usize = 256;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Lut3dInterpolation {
+    Trilinear,
+    Tetrahedral,
+}
+
+impl ValueEnum for Lut3dInterpolation {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Lut3dInterpolation::Trilinear,
+            Lut3dInterpolation::Tetrahedral,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            Lut3dInterpolation::Trilinear => Some(PossibleValue::new("trilinear")),
+            Lut3dInterpolation::Tetrahedral => Some(PossibleValue::new("tetrahedral")),
+        }
+    }
+}
+
+// Color lattice of size^3 entries, red changes fastest
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Lut3d {
+    size: usize,
+    table: Vec<[f32; 3]>,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+}
+
+impl Lut3d {
+    pub fn new(size: usize, table: Vec<[f32; 3]>) -> PicturifyResult<Self> {
+        if !(2..=MAX_LUT_SIZE).contains(&size) || table.len() != size * size * size {
+            return Err(invalid_lut("table does not match the lattice size"));
+        }
+
+        Ok(Self {
+            size,
+            table,
+            domain_min: [0.0; 3],
+            domain_max: [1.0; 3],
+        })
+    }
+
+    // Adobe / Resolve .cube text format
+    pub fn from_cube(text: &str) -> PicturifyResult<Self> {
+        let mut size = None;
+        let mut domain_min = [0.0; 3];
+        let mut domain_max = [1.0; 3];
+        let mut table = Vec::new();
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let keyword = words.next().unwrap_or_default();
+            match keyword {
+                "TITLE" => {}
+                "LUT_1D_SIZE" => return Err(invalid_lut("1D LUTs are not supported")),
+                "LUT_3D_SIZE" => {
+                    let value = words.next().and_then(|word| word.parse::<usize>().ok());
+                    size = Some(value.ok_or_else(|| invalid_lut("invalid LUT_3D_SIZE"))?);
+                }
+                "DOMAIN_MIN" => domain_min = parse_numbers(words)?,
+                "DOMAIN_MAX" => domain_max = parse_numbers(words)?,
+                "LUT_3D_INPUT_RANGE" => {
+                    let [min, max] = parse_numbers(words)?;
+                    domain_min = [min; 3];
+                    domain_max = [max; 3];
+                }
+                _ if keyword.starts_with(|c: char| c.is_ascii_alphabetic()) => {
+                    return Err(invalid_lut(&format!("unknown keyword {}", keyword)));
+                }
+                _ => table.push(parse_numbers(line.split_whitespace())?),
+            }
+        }
+
+        let size = size.ok_or_else(|| invalid_lut("missing LUT_3D_SIZE"))?;
+        if (0..3).any(|i| domain_max[i] <= domain_min[i]) {
+            return Err(invalid_lut("empty domain"));
+        }
+
+        let mut lut = Self::new(size, table)?;
+        lut.domain_min = domain_min;
+        lut.domain_max = domain_max;
+        Ok(lut)
+    }
+
+    // HALD CLUT of level L is an L^3 x L^3 image holding an L^2 lattice
+    pub fn from_hald(image: &FastImage) -> PicturifyResult<Self> {
+        let (width, height) = image.size().into();
+        let level = (1..=MAX_LUT_SIZE)
+            .take_while(|level| level * level * level <= width)
+            .find(|level| level * level * level == width);
+        let level = match level {
+            Some(level) if width == height => level,
+            _ => return Err(invalid_lut("HALD image must be a square of side level^3")),
+        };
+
+        let table = image
+            .pixels()
+            .map(|pixel| [0, 1, 2].map(|channel| pixel.0[channel] as f32 / 255.0))
+            .collect();
+
+        Self::new(level * level, table)
+    }
+
+    pub fn apply(&self, color: [f32; 3], interpolation: Lut3dInterpolation) -> [f32; 3] {
+        let last = (self.size - 1) as f32;
+        let position: [f32; 3] = [0, 1, 2].map(|i| {
+            let normalized =
+                (color[i] - self.domain_min[i]) / (self.domain_max[i] - self.domain_min[i]);
+            normalized.clamp(0.0, 1.0) * last
+        });
+        let base = position.map(|value| (value as usize).min(self.size - 2));
+        let fraction = [0, 1, 2].map(|i| position[i] - base[i] as f32);
+
+        let corner = |r: usize, g: usize, b: usize| {
+            let index =
+                (base[0] + r) + (base[1] + g) * self.size + (base[2] + b) * self.size * self.size;
+            self.table[index]
+        };
+
+        match interpolation {
+            Lut3dInterpolation::Trilinear => {
+                let [fr, fg, fb] = fraction;
+                let mut result = [0.0; 3];
+                for (r, g, b) in [
+                    (0, 0, 0),
+                    (1, 0, 0),
+                    (0, 1, 0),
+                    (1, 1, 0),
+                    (0, 0, 1),
+                    (1, 0, 1),
+                    (0, 1, 1),
+                    (1, 1, 1),
+                ] {
+                    let weight = (if r == 1 { fr } else { 1.0 - fr })
+                        * (if g == 1 { fg } else { 1.0 - fg })
+                        * (if b == 1 { fb } else { 1.0 - fb });
+                    let value = corner(r, g, b);
+                    for channel in 0..3 {
+                        result[channel] += weight * value[channel];
+                    }
+                }
+                result
+            }
+            Lut3dInterpolation::Tetrahedral => {
+                // the unit cube is split into six tetrahedra along its main diagonal,
+                // each walked from the origin corner to the opposite one
+                let [fr, fg, fb] = fraction;
+                let (steps, weights) = if fr >= fg && fg >= fb {
+                    ([(1, 0, 0), (1, 1, 0)], [1.0 - fr, fr - fg, fg - fb, fb])
+                } else if fr >= fb && fb >= fg {
+                    ([(1, 0, 0), (1, 0, 1)], [1.0 - fr, fr - fb, fb - fg, fg])
+                } else if fb >= fr && fr >= fg {
+                    ([(0, 0, 1), (1, 0, 1)], [1.0 - fb, fb - fr, fr - fg, fg])
+                } else if fg >= fr && fr >= fb {
+                    ([(0, 1, 0), (1, 1, 0)], [1.0 - fg, fg - fr, fr - fb, fb])
+                } else if fg >= fb && fb >= fr {
+                    ([(0, 1, 0), (0, 1, 1)], [1.0 - fg, fg - fb, fb - fr, fr])
+                } else {
+                    ([(0, 0, 1), (0, 1, 1)], [1.0 - fb, fb - fg, fg - fr, fr])
+                };
+
+                let vertices = [
+                    corner(0, 0, 0),
+                    corner(steps[0].0, steps[0].1, steps[0].2),
+                    corner(steps[1].0, steps[1].1, steps[1].2),
+                    corner(1, 1, 1),
+                ];
+                let mut result = [0.0; 3];
+                for (vertex, weight) in vertices.iter().zip(weights) {
+                    for channel in 0..3 {
+                        result[channel] += weight * vertex[channel];
+                    }
+                }
+                result
+            }
+        }
+    }
+}
+
+impl ReadFromFile for Lut3d {
+    fn read_from_file<P>(path: P) -> PicturifyResult<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let is_cube = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("cube"));
+
+        if is_cube {
+            Self::from_cube(&read_to_string(path)?)
+        } else {
+            Self::from_hald(&FastImage::read_from_file(path)?)
+        }
+    }
+}
+
+fn invalid_lut(reason: &str) -> PicturifyError {
+    PicturifyError::InvalidLut(reason.to_string())
+}
+
+fn parse_numbers<'a, const N: usize>(
+    mut words: impl Iterator<Item = &'a str>,
+) -> PicturifyResult<[f32; N]> {
+    let mut numbers = [0.0; N];
+    for value in numbers.iter_mut() {
+        *value = words
+            .next()
+            .and_then(|word| word.parse::<f32>().ok())
+            .ok_or_else(|| invalid_lut(&format!("expected {} numbers", N)))?;
+    }
+    Ok(numbers)
+}
@@ -0,0 +1,300 @@
+use serde::Serialize;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::plane_image::{PlaneImage, PlaneImageF32, PlaneImageU8};
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::geometry::size::Size;
+use picturify_core::image::Rgba;
+use picturify_core::rayon::prelude::*;
+
+use crate::common::sharpening::gaussian_blur_plane;
+
+const MAX_VALUE: f64 = 255.0;
+// window and stabilizing constants from Wang et al., for 8 bit channels
+const SSIM_RADIUS: usize = 5;
+const SSIM_SIGMA: f32 = 1.5;
+const SSIM_C1: f32 = (0.01 * 255.0) * (0.01 * 255.0);
+const SSIM_C2: f32 = (0.03 * 255.0) * (0.03 * 255.0);
+const MS_SSIM_WEIGHTS: [f64; 5] = [0.0448, 0.2856, 0.3001, 0.2363, 0.1333];
+// coarser scales are skipped when the image would get smaller than this
+const MS_SSIM_MIN_SIZE: usize = 8;
+const DIFFERENCE_BACKGROUND_SCALE: f32 = 0.25;
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct ChannelStatistics {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: u8,
+    pub max: u8,
+}
+
+impl ChannelStatistics {
+    pub fn new(plane: &PlaneImageU8) -> Self {
+        let count = plane.values().len().max(1) as f64;
+        let sum: f64 = plane.values().iter().map(|value| *value as f64).sum();
+        let squared_sum: f64 = plane
+            .values()
+            .iter()
+            .map(|value| (*value as f64).powi(2))
+            .sum();
+        let mean = sum / count;
+
+        Self {
+            mean,
+            std_dev: (squared_sum / count - mean * mean).max(0.0).sqrt(),
+            min: plane.values().iter().copied().min().unwrap_or(0),
+            max: plane.values().iter().copied().max().unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct ImageStatistics {
+    pub red: ChannelStatistics,
+    pub green: ChannelStatistics,
+    pub blue: ChannelStatistics,
+    pub alpha: ChannelStatistics,
+}
+
+impl ImageStatistics {
+    pub fn new(image: &FastImage) -> Self {
+        let [red, green, blue, alpha] = PlaneImageU8::split(image);
+        Self {
+            red: ChannelStatistics::new(&red),
+            green: ChannelStatistics::new(&green),
+            blue: ChannelStatistics::new(&blue),
+            alpha: ChannelStatistics::new(&alpha),
+        }
+    }
+}
+
+// psnr is infinite for identical channels
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct ChannelDifference {
+    pub mae: f64,
+    pub mse: f64,
+    pub psnr: f64,
+    pub max_delta: u8,
+}
+
+impl ChannelDifference {
+    fn from_sums(absolute_sum: f64, squared_sum: f64, max_delta: u8, count: f64) -> Self {
+        let mse = squared_sum / count;
+        Self {
+            mae: absolute_sum / count,
+            mse,
+            psnr: psnr(mse),
+            max_delta,
+        }
+    }
+}
+
+// Overall values cover the color channels, alpha is only reported on its own.
+// Both structural similarities are taken on the luma of the color channels.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct ComparisonMetrics {
+    pub mae: f64,
+    pub mse: f64,
+    pub psnr: f64,
+    pub ssim: f64,
+    pub ms_ssim: f64,
+    pub red: ChannelDifference,
+    pub green: ChannelDifference,
+    pub blue: ChannelDifference,
+    pub alpha: ChannelDifference,
+}
+
+impl ComparisonMetrics {
+    pub fn new(first: &FastImage, second: &FastImage) -> ProcessingPicturifyResult<Self> {
+        if first.size() != second.size() {
+            return Err(ProcessingPicturifyError::ImageSizeMismatch);
+        }
+
+        let sums = first
+            .rows()
+            .zip(second.rows())
+            .par_bridge()
+            .map(|(first, second)| {
+                let mut sums = [[0.0f64; 3]; 4];
+                for (first, second) in first.zip(second) {
+                    for (channel, sums) in sums.iter_mut().enumerate() {
+                        let difference = first.0[channel].abs_diff(second.0[channel]) as f64;
+                        sums[0] += difference;
+                        sums[1] += difference * difference;
+                        sums[2] = sums[2].max(difference);
+                    }
+                }
+                sums
+            })
+            .reduce(
+                || [[0.0f64; 3]; 4],
+                |mut total, sums| {
+                    for (total, sums) in total.iter_mut().zip(sums) {
+                        total[0] += sums[0];
+                        total[1] += sums[1];
+                        total[2] = total[2].max(sums[2]);
+                    }
+                    total
+                },
+            );
+
+        let (width, height): (usize, usize) = first.size().into();
+        let count = (width * height).max(1) as f64;
+        let [red, green, blue, alpha] = sums.map(|[absolute, squared, max_delta]| {
+            ChannelDifference::from_sums(absolute, squared, max_delta as u8, count)
+        });
+        let mse = (red.mse + green.mse + blue.mse) / 3.0;
+
+        let first = luma_plane(first);
+        let second = luma_plane(second);
+
+        Ok(Self {
+            mae: (red.mae + green.mae + blue.mae) / 3.0,
+            mse,
+            psnr: psnr(mse),
+            ssim: structural_similarity(&first, &second).0,
+            ms_ssim: multi_scale_structural_similarity(first, second),
+            red,
+            green,
+            blue,
+            alpha,
+        })
+    }
+}
+
+// Dimmed grayscale copy of the first image with every pixel whose largest channel difference is
+// above the threshold painted red, brighter for larger differences
+pub fn difference_image(
+    first: &FastImage,
+    second: &FastImage,
+    threshold: u8,
+) -> ProcessingPicturifyResult<FastImage> {
+    if first.size() != second.size() {
+        return Err(ProcessingPicturifyError::ImageSizeMismatch);
+    }
+
+    let luma = luma_plane(first);
+    let mut image = FastImage::empty(first.size());
+    image
+        .pixels_mut()
+        .zip(first.pixels().zip(second.pixels()))
+        .zip(luma.values())
+        .for_each(|((pixel, (first, second)), luma)| {
+            let difference = first
+                .0
+                .iter()
+                .zip(second.0)
+                .map(|(first, second)| first.abs_diff(second))
+                .max()
+                .unwrap_or(0);
+            *pixel = match difference > threshold {
+                true => Rgba([128 + difference / 2, 0, 0, 255]),
+                false => {
+                    let value = (luma * DIFFERENCE_BACKGROUND_SCALE).round() as u8;
+                    Rgba([value, value, value, 255])
+                }
+            };
+        });
+
+    Ok(image)
+}
+
+fn psnr(mse: f64) -> f64 {
+    match mse == 0.0 {
+        true => f64::INFINITY,
+        false => 10.0 * (MAX_VALUE * MAX_VALUE / mse).log10(),
+    }
+}
+
+// rec. 601 luma in 0-255 units
+fn luma_plane(image: &FastImage) -> PlaneImageF32 {
+    PlaneImage::from_fn(image.size(), |coord| {
+        let pixel = image.get_image_pixel(coord).0;
+        0.299 * pixel[0] as f32 + 0.587 * pixel[1] as f32 + 0.114 * pixel[2] as f32
+    })
+}
+
+fn product_plane(first: &PlaneImageF32, second: &PlaneImageF32) -> PlaneImageF32 {
+    PlaneImage::from_fn(first.size(), |coord| first.get(coord) * second.get(coord))
+}
+
+// returns the mean similarity and the mean of its contrast and structure part alone
+fn structural_similarity(first: &PlaneImageF32, second: &PlaneImageF32) -> (f64, f64) {
+    let blur = |plane: &PlaneImageF32| gaussian_blur_plane(plane, SSIM_RADIUS, SSIM_SIGMA);
+    let first_mean = blur(first);
+    let second_mean = blur(second);
+    let first_squared = blur(&product_plane(first, first));
+    let second_squared = blur(&product_plane(second, second));
+    let cross = blur(&product_plane(first, second));
+
+    let (similarity, contrast_structure) = (0..first.values().len())
+        .into_par_iter()
+        .map(|index| {
+            let first_mean = first_mean.values()[index];
+            let second_mean = second_mean.values()[index];
+            let first_variance = first_squared.values()[index] - first_mean * first_mean;
+            let second_variance = second_squared.values()[index] - second_mean * second_mean;
+            let covariance = cross.values()[index] - first_mean * second_mean;
+
+            let luminance = (2.0 * first_mean * second_mean + SSIM_C1)
+                / (first_mean * first_mean + second_mean * second_mean + SSIM_C1);
+            let contrast_structure =
+                (2.0 * covariance + SSIM_C2) / (first_variance + second_variance + SSIM_C2);
+            (
+                (luminance * contrast_structure) as f64,
+                contrast_structure as f64,
+            )
+        })
+        .reduce(
+            || (0.0, 0.0),
+            |(first_similarity, first_contrast), (second_similarity, second_contrast)| {
+                (
+                    first_similarity + second_similarity,
+                    first_contrast + second_contrast,
+                )
+            },
+        );
+
+    let count = first.values().len().max(1) as f64;
+    (similarity / count, contrast_structure / count)
+}
+
+// Contrast and structure are compared at every scale, luminance only at the coarsest one.
+// Weights of the scales an image is too small for are spread over the remaining ones.
+fn multi_scale_structural_similarity(mut first: PlaneImageF32, mut second: PlaneImageF32) -> f64 {
+    let mut scales = 1;
+    while scales < MS_SSIM_WEIGHTS.len()
+        && first.width().min(first.height()) / 2usize.pow(scales as u32) >= MS_SSIM_MIN_SIZE
+    {
+        scales += 1;
+    }
+    let total_weight: f64 = MS_SSIM_WEIGHTS[..scales].iter().sum();
+
+    let mut result = 1.0;
+    for (scale, weight) in MS_SSIM_WEIGHTS[..scales].iter().enumerate() {
+        let weight = weight / total_weight;
+        let (similarity, contrast_structure) = structural_similarity(&first, &second);
+        if scale + 1 == scales {
+            result *= similarity.max(0.0).powf(weight);
+        } else {
+            result *= contrast_structure.max(0.0).powf(weight);
+            first = downsample(&first);
+            second = downsample(&second);
+        }
+    }
+
+    result
+}
+
+// averages 2x2 blocks, an odd last row or column is dropped
+fn downsample(plane: &PlaneImageF32) -> PlaneImageF32 {
+    let size = Size::new(plane.width() / 2, plane.height() / 2);
+    PlaneImage::from_fn(size, |coord| {
+        let (x, y): (usize, usize) = coord.into();
+        let values = plane.values();
+        let width = plane.width();
+        let top = 2 * y * width + 2 * x;
+        let bottom = top + width;
+        (values[top] + values[top + 1] + values[bottom] + values[bottom + 1]) / 4.0
+    })
+}
@@ -1,3 +1,17 @@
+pub mod chromatic_adaptation;
+pub mod color_space;
+pub mod difference_of_gaussians;
 pub mod functions;
+pub mod generators;
 pub mod kernels;
+pub mod line_integral;
+pub mod lut3d;
+pub mod lut;
+pub mod metrics;
+pub mod noise;
+pub mod palette_generation;
 pub mod processors;
+pub mod region;
+pub mod sector_kuwahara;
+pub mod sharpening;
+pub mod structure_tensor;
@@ -0,0 +1,72 @@
+use std::f32::consts::PI;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::image::Rgba;
+use picturify_core::rayon::prelude::*;
+use picturify_core::threading::progress::{Progress, ProgressIteratorExt};
+
+// odd constant derived from the golden ratio, spreads row indices over the whole seed space
+const ROW_SEED_STEP: u64 = 0x9E37_79B9_7F4A_7C15;
+// above this mean the Poisson distribution is close enough to a normal one
+const POISSON_NORMAL_THRESHOLD: f32 = 30.0;
+
+pub fn resolve_seed(seed: Option<u64>) -> u64 {
+    seed.unwrap_or_else(rand::random)
+}
+
+// every row gets its own generator, so the result does not depend on thread scheduling
+pub fn row_rng(seed: u64, row: usize) -> StdRng {
+    StdRng::seed_from_u64(seed ^ (row as u64 + 1).wrapping_mul(ROW_SEED_STEP))
+}
+
+// Box-Muller transform, 1 - u keeps the logarithm finite
+pub fn standard_normal<R: Rng>(rng: &mut R) -> f32 {
+    let u1 = 1.0 - rng.gen::<f32>();
+    let u2 = rng.gen::<f32>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+pub fn poisson<R: Rng>(rng: &mut R, mean: f32) -> f32 {
+    if mean <= 0.0 {
+        return 0.0;
+    }
+    if mean > POISSON_NORMAL_THRESHOLD {
+        return (mean + mean.sqrt() * standard_normal(rng)).round().max(0.0);
+    }
+
+    // Knuth, multiplies uniform samples until the product drops below e^-mean
+    let limit = (-mean).exp();
+    let mut count = 0.0;
+    let mut product = rng.gen::<f32>();
+    while product > limit {
+        count += 1.0;
+        product *= rng.gen::<f32>();
+    }
+    count
+}
+
+pub fn apply_noise_fn<F>(image: &mut FastImage, seed: Option<u64>, mut progress: Progress, f: F)
+where
+    F: Fn(&mut Rgba<u8>, &mut StdRng) + Send + Sync,
+{
+    let seed = resolve_seed(seed);
+    let (_, height): (usize, usize) = image.size().into();
+
+    progress.setup(height);
+    image
+        .rows_mut()
+        .enumerate()
+        .progress(progress)
+        .par_bridge()
+        .for_each(|(y, row)| {
+            let mut rng = row_rng(seed, y);
+            row.for_each(|pixel| f(pixel, &mut rng));
+        });
+}
+
+pub fn to_channel(value: f32) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
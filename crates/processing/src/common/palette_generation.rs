@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::color_palette::{
+    distance_squared, nearest, srgb_to_lab, ColorPalette, MAX_PALETTE_SIZE,
+};
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::PicturifyResult;
+use picturify_core::palette::{FromColor, Lab, Srgb};
+
+// colors are binned to 5 bits per channel before clustering
+const HISTOGRAM_SHIFT: u8 = 3;
+const KMEANS_MAX_ITERATIONS: usize = 16;
+const KMEANS_CONVERGENCE: f32 = 0.01;
+const OCTREE_DEPTH: usize = 8;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum PaletteMethod {
+    MedianCut,
+    KMeans,
+    Octree,
+}
+
+impl ValueEnum for PaletteMethod {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            PaletteMethod::MedianCut,
+            PaletteMethod::KMeans,
+            PaletteMethod::Octree,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            PaletteMethod::MedianCut => Some(PossibleValue::new("median-cut")),
+            PaletteMethod::KMeans => Some(PossibleValue::new("k-means")),
+            PaletteMethod::Octree => Some(PossibleValue::new("octree")),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct WeightedColor {
+    color: [f32; 3],
+    weight: f32,
+}
+
+// Builds a palette of at most `colors` entries representing the visible pixels of the image
+pub fn generate_palette(
+    image: &FastImage,
+    colors: usize,
+    method: PaletteMethod,
+) -> PicturifyResult<ColorPalette> {
+    let colors = colors.clamp(1, MAX_PALETTE_SIZE);
+    let histogram = build_histogram(image);
+    if histogram.is_empty() {
+        return ColorPalette::new(vec![[0, 0, 0]]);
+    }
+
+    let centers = match method {
+        PaletteMethod::MedianCut => median_cut(&histogram, colors),
+        PaletteMethod::KMeans => k_means(&histogram, median_cut(&histogram, colors)),
+        PaletteMethod::Octree => octree(&histogram, colors),
+    };
+
+    ColorPalette::new(
+        centers
+            .iter()
+            .map(|color| color.map(|channel| channel.round().clamp(0.0, 255.0) as u8))
+            .collect(),
+    )
+}
+
+fn build_histogram(image: &FastImage) -> Vec<WeightedColor> {
+    let mut bins: HashMap<[u8; 3], ([f32; 3], f32)> = HashMap::new();
+    for pixel in image.pixels().filter(|pixel| pixel.0[3] > 0) {
+        let key = [0, 1, 2].map(|channel| pixel.0[channel] >> HISTOGRAM_SHIFT);
+        let (sum, weight) = bins.entry(key).or_insert(([0.0; 3], 0.0));
+        *sum = [0, 1, 2].map(|channel| sum[channel] + pixel.0[channel] as f32);
+        *weight += 1.0;
+    }
+
+    // sorted so the palette does not depend on hash map ordering
+    let mut bins: Vec<_> = bins.into_iter().collect();
+    bins.sort_by_key(|(key, _)| *key);
+    bins.into_iter()
+        .map(|(_, (sum, weight))| WeightedColor {
+            color: sum.map(|channel| channel / weight),
+            weight,
+        })
+        .collect()
+}
+
+fn weighted_mean(colors: &[WeightedColor]) -> [f32; 3] {
+    let mut sum = [0.0; 3];
+    let mut total = 0.0;
+    for color in colors {
+        sum = add_weighted(sum, color);
+        total += color.weight;
+    }
+    sum.map(|channel| channel / total)
+}
+
+// repeatedly splits the box with the widest channel range at its weighted median
+fn median_cut(histogram: &[WeightedColor], colors: usize) -> Vec<[f32; 3]> {
+    let channel_range = |colors: &[WeightedColor]| {
+        (0..3)
+            .map(|channel| {
+                let (min, max) = colors.iter().fold((f32::MAX, f32::MIN), |(min, max), c| {
+                    (min.min(c.color[channel]), max.max(c.color[channel]))
+                });
+                (channel, max - min)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap()
+    };
+
+    let mut boxes = vec![histogram.to_vec()];
+    while boxes.len() < colors {
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, colors)| colors.len() > 1)
+            .map(|(index, colors)| (index, channel_range(colors)))
+            .max_by(|a, b| a.1 .1.total_cmp(&b.1 .1));
+        let Some((index, (channel, _))) = widest else {
+            break;
+        };
+
+        let mut colors = boxes.swap_remove(index);
+        colors.sort_by(|a, b| a.color[channel].total_cmp(&b.color[channel]));
+        let half = colors.iter().map(|color| color.weight).sum::<f32>() / 2.0;
+        let mut accumulated = 0.0;
+        let split = colors
+            .iter()
+            .position(|color| {
+                accumulated += color.weight;
+                accumulated >= half
+            })
+            .unwrap_or(0)
+            .clamp(0, colors.len() - 2)
+            + 1;
+
+        let upper = colors.split_off(split);
+        boxes.push(colors);
+        boxes.push(upper);
+    }
+
+    boxes.iter().map(|colors| weighted_mean(colors)).collect()
+}
+
+// Lloyd iterations in Lab, starting from the median cut palette
+fn k_means(histogram: &[WeightedColor], initial: Vec<[f32; 3]>) -> Vec<[f32; 3]> {
+    let points: Vec<WeightedColor> = histogram
+        .iter()
+        .map(|color| WeightedColor {
+            color: srgb_to_lab(color.color),
+            weight: color.weight,
+        })
+        .collect();
+    let mut centers: Vec<[f32; 3]> = initial.into_iter().map(srgb_to_lab).collect();
+
+    for _ in 0..KMEANS_MAX_ITERATIONS {
+        let mut sums = vec![([0.0; 3], 0.0); centers.len()];
+        for point in &points {
+            let (sum, weight) = &mut sums[nearest(&centers, point.color)];
+            *sum = add_weighted(*sum, point);
+            *weight += point.weight;
+        }
+
+        let mut movement: f32 = 0.0;
+        for (center, (sum, weight)) in centers.iter_mut().zip(sums) {
+            if weight > 0.0 {
+                let updated = sum.map(|channel| channel / weight);
+                movement = movement.max(distance_squared(*center, updated));
+                *center = updated;
+            }
+        }
+        if movement < KMEANS_CONVERGENCE {
+            break;
+        }
+    }
+
+    centers
+        .into_iter()
+        .map(|[l, a, b]| {
+            let srgb = Srgb::from_color(Lab::new(l, a, b));
+            [srgb.red, srgb.green, srgb.blue].map(|channel| channel * 255.0)
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct OctreeNode {
+    children: [Option<usize>; 8],
+    sum: [f32; 3],
+    weight: f32,
+    is_leaf: bool,
+}
+
+// merges the lightest nodes of the deepest level until few enough leaves remain
+fn octree(histogram: &[WeightedColor], colors: usize) -> Vec<[f32; 3]> {
+    let mut nodes = vec![OctreeNode::default()];
+    let mut levels: Vec<Vec<usize>> = vec![Vec::new(); OCTREE_DEPTH];
+    let mut leaf_count = 0;
+
+    for color in histogram {
+        let rgb = color
+            .color
+            .map(|channel| channel.round().clamp(0.0, 255.0) as u8);
+        let mut node = 0;
+        for (level, nodes_at_level) in levels.iter_mut().enumerate() {
+            nodes[node].sum = add_weighted(nodes[node].sum, color);
+            nodes[node].weight += color.weight;
+
+            let shift = 7 - level;
+            let child = (((rgb[0] >> shift) & 1) << 2
+                | ((rgb[1] >> shift) & 1) << 1
+                | ((rgb[2] >> shift) & 1)) as usize;
+            node = match nodes[node].children[child] {
+                Some(existing) => existing,
+                None => {
+                    nodes.push(OctreeNode::default());
+                    let created = nodes.len() - 1;
+                    nodes[node].children[child] = Some(created);
+                    if level < OCTREE_DEPTH - 1 {
+                        nodes_at_level.push(created);
+                    }
+                    created
+                }
+            };
+        }
+
+        if !nodes[node].is_leaf {
+            nodes[node].is_leaf = true;
+            leaf_count += 1;
+        }
+        nodes[node].sum = add_weighted(nodes[node].sum, color);
+        nodes[node].weight += color.weight;
+    }
+
+    // levels[l] holds the nodes created below level l, which sit at depth l + 1
+    for level in levels.iter_mut().rev() {
+        if leaf_count <= colors {
+            break;
+        }
+        level.sort_by(|a, b| nodes[*a].weight.total_cmp(&nodes[*b].weight));
+        for &node in level.iter() {
+            if leaf_count <= colors {
+                break;
+            }
+            let children: Vec<usize> = nodes[node].children.iter().flatten().copied().collect();
+            leaf_count -= children.len() - 1;
+            nodes[node].children = [None; 8];
+            nodes[node].is_leaf = true;
+        }
+    }
+    if leaf_count > colors {
+        // the whole image collapses into the root
+        return vec![nodes[0].sum.map(|channel| channel / nodes[0].weight)];
+    }
+
+    let mut centers = Vec::new();
+    let mut stack = vec![0];
+    while let Some(node) = stack.pop() {
+        if nodes[node].is_leaf {
+            centers.push(nodes[node].sum.map(|channel| channel / nodes[node].weight));
+        } else {
+            stack.extend(nodes[node].children.iter().flatten());
+        }
+    }
+    centers
+}
+
+fn add_weighted(sum: [f32; 3], color: &WeightedColor) -> [f32; 3] {
+    [0, 1, 2].map(|channel| sum[channel] + color.color[channel] * color.weight)
+}
@@ -1,11 +1,23 @@
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::size::Size;
 use picturify_core::threading::progress::Progress;
 
 pub trait CpuProcessor {
     fn name(&self) -> &'static str;
     fn process(&self, image: FastImage, progress: Progress)
         -> ProcessingPicturifyResult<FastImage>;
+    // how far a single output pixel can look in each direction
+    fn kernel_radius(&self) -> usize {
+        0
+    }
+}
+
+// Processors that skip a border of their radius leave it to the enlargement, which is cropped
+// away afterwards. True when an image of the size has no pixels outside that border.
+pub fn is_border_only(size: Size, radius: usize) -> bool {
+    let (width, height): (usize, usize) = size.into();
+    width <= 2 * radius || height <= 2 * radius
 }
 
 pub trait GpuProcessor {
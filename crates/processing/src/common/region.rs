@@ -0,0 +1,303 @@
+use picturify_core::core::apply_fn_to_pixels::{ApplyFnToImagePixels, Offset};
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::geometry::coord::Coord;
+use picturify_core::geometry::size::Size;
+use picturify_core::image::Rgba;
+use picturify_core::rayon::prelude::*;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::functions::gaussian_1d;
+use crate::common::processors::CpuProcessor;
+use crate::processors::geometry::crop::CropBorder;
+
+#[derive(Clone)]
+pub struct Mask {
+    size: Size,
+    weights: Vec<f32>,
+}
+
+impl Mask {
+    pub fn from_image(image: &FastImage) -> Self {
+        let weights = image
+            .pixels()
+            .map(|pixel| {
+                let [red, green, blue, alpha] = pixel.0;
+                let luma = 0.299 * red as f32 + 0.587 * green as f32 + 0.114 * blue as f32;
+                (luma / 255.0) * (alpha as f32 / 255.0)
+            })
+            .collect();
+
+        Self {
+            size: image.size(),
+            weights,
+        }
+    }
+
+    // softens the mask edge with a separable gaussian so the filtered area fades in
+    pub fn feather(self, radius: usize) -> Self {
+        if radius == 0 {
+            return self;
+        }
+
+        let sigma = (radius as f32 / 2.0).max(0.5);
+        let kernel: Vec<f32> = (0..=2 * radius)
+            .map(|i| gaussian_1d(i as f32 - radius as f32, sigma))
+            .collect();
+        let kernel_sum: f32 = kernel.iter().sum();
+        let kernel: Vec<f32> = kernel.iter().map(|value| value / kernel_sum).collect();
+
+        let (width, height): (usize, usize) = self.size.into();
+        let horizontal = blur_pass(&self.weights, width, height, &kernel, (1, 0));
+        let weights = blur_pass(&horizontal, width, height, &kernel, (0, 1));
+
+        Self {
+            size: self.size,
+            weights,
+        }
+    }
+
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    // the weights of `height` rows starting at `row`, which must lie inside the mask
+    fn rows(&self, row: usize, height: usize) -> Self {
+        let (width, _): (usize, usize) = self.size.into();
+        Self {
+            size: Size::new(width, height),
+            weights: self.weights[row * width..(row + height) * width].to_vec(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn weight(&self, coord: Coord) -> f32 {
+        let (width, _): (usize, usize) = self.size.into();
+        self.weights[coord.array_index(width)]
+    }
+}
+
+fn blur_pass(
+    weights: &[f32],
+    width: usize,
+    height: usize,
+    kernel: &[f32],
+    direction: (usize, usize),
+) -> Vec<f32> {
+    let radius = (kernel.len() / 2) as isize;
+    let mut result = vec![0.0; weights.len()];
+
+    result
+        .par_chunks_mut(width)
+        .enumerate()
+        .for_each(|(y, row)| {
+            row.iter_mut().enumerate().for_each(|(x, value)| {
+                *value = kernel
+                    .iter()
+                    .enumerate()
+                    .map(|(i, k)| {
+                        let shift = i as isize - radius;
+                        let sample_x = (x as isize + shift * direction.0 as isize)
+                            .clamp(0, width as isize - 1)
+                            as usize;
+                        let sample_y = (y as isize + shift * direction.1 as isize)
+                            .clamp(0, height as isize - 1)
+                            as usize;
+                        weights[sample_y * width + sample_x] * k
+                    })
+                    .sum();
+            });
+        });
+
+    result
+}
+
+// Restricts the effect of a filter to a rectangle and/or the pixels selected by a mask
+#[derive(Clone, Default)]
+pub struct Region {
+    pub roi: Option<CropBorder>,
+    pub mask: Option<Mask>,
+}
+
+impl Region {
+    pub fn is_full(&self) -> bool {
+        self.roi.is_none() && self.mask.is_none()
+    }
+
+    // The part of the region inside `height` rows starting at `row` of an image of `size`, in
+    // the coordinates of those rows, None when they miss the region of interest. The rows
+    // alone can't tell whether the region fits the image, so it is checked against all of it.
+    pub fn rows(
+        &self,
+        row: usize,
+        height: usize,
+        size: Size,
+    ) -> ProcessingPicturifyResult<Option<Self>> {
+        let bounds = self.bounds(size)?;
+        let (_, image_height): (usize, usize) = size.into();
+        let rows_end = row
+            .checked_add(height)
+            .filter(|end| *end <= image_height)
+            .ok_or(ProcessingPicturifyError::InvalidRegion)?;
+
+        let roi = match self.roi {
+            Some(_) => {
+                let start = bounds.y_offset.max(row);
+                let end = (bounds.y_offset + bounds.height).min(rows_end);
+                if start >= end {
+                    return Ok(None);
+                }
+                Some(CropBorder::new(
+                    bounds.width,
+                    end - start,
+                    bounds.x_offset,
+                    start - row,
+                ))
+            }
+            None => None,
+        };
+
+        Ok(Some(Self {
+            roi,
+            mask: self.mask.as_ref().map(|mask| mask.rows(row, height)),
+        }))
+    }
+
+    // runs `f` on the smallest part of the image that covers the region plus `margin`
+    // pixels of context, then blends the result back into the original image
+    pub fn apply<F, E>(&self, mut image: FastImage, margin: usize, f: F) -> Result<FastImage, E>
+    where
+        F: FnOnce(FastImage) -> Result<FastImage, E>,
+        E: From<ProcessingPicturifyError>,
+    {
+        if self.is_full() {
+            return f(image);
+        }
+
+        let size = image.size();
+        let bounds = self.bounds(size)?;
+        let context = expand_border(bounds, margin, size);
+
+        let processed = f(crop(&image, context))?;
+        if processed.size() != context.into() {
+            return Err(ProcessingPicturifyError::RegionSizeChanged.into());
+        }
+
+        let context_offset = context.offset();
+        let offset = Offset {
+            skip_rows: bounds.y_offset,
+            take_rows: bounds.height,
+            skip_columns: bounds.x_offset,
+            take_columns: bounds.width,
+        };
+        image.par_apply_fn_to_image_pixel_with_offset(
+            |pixel, coord| {
+                let weight = match &self.mask {
+                    Some(mask) => mask.weight(coord),
+                    None => 1.0,
+                };
+                let processed_pixel = processed.get_image_pixel(coord - context_offset);
+                *pixel = blend(*pixel, processed_pixel, weight);
+            },
+            None,
+            offset,
+        );
+
+        Ok(image)
+    }
+
+    fn bounds(&self, size: Size) -> ProcessingPicturifyResult<CropBorder> {
+        let (width, height): (usize, usize) = size.into();
+
+        if let Some(mask) = &self.mask {
+            if mask.size() != size {
+                return Err(ProcessingPicturifyError::InvalidMask);
+            }
+        }
+
+        match self.roi {
+            Some(roi) => {
+                let outside = |offset: usize, length: usize, limit: usize| {
+                    offset.checked_add(length).is_none_or(|end| end > limit)
+                };
+                if roi.width == 0
+                    || roi.height == 0
+                    || outside(roi.x_offset, roi.width, width)
+                    || outside(roi.y_offset, roi.height, height)
+                {
+                    return Err(ProcessingPicturifyError::InvalidRegion);
+                }
+                Ok(roi)
+            }
+            None => Ok(CropBorder::new(width, height, 0, 0)),
+        }
+    }
+}
+
+fn expand_border(border: CropBorder, margin: usize, size: Size) -> CropBorder {
+    let (width, height): (usize, usize) = size.into();
+    let x_offset = border.x_offset.saturating_sub(margin);
+    let y_offset = border.y_offset.saturating_sub(margin);
+    let right = (border.x_offset + border.width)
+        .saturating_add(margin)
+        .min(width);
+    let bottom = (border.y_offset + border.height)
+        .saturating_add(margin)
+        .min(height);
+
+    CropBorder::new(right - x_offset, bottom - y_offset, x_offset, y_offset)
+}
+
+fn crop(image: &FastImage, border: CropBorder) -> FastImage {
+    let mut cropped = FastImage::empty(border.into());
+    cropped.par_apply_fn_to_image_pixel(
+        |pixel, coord| {
+            *pixel = image.get_image_pixel(coord + border.offset());
+        },
+        None,
+    );
+
+    cropped
+}
+
+#[inline(always)]
+fn blend(original: Rgba<u8>, processed: Rgba<u8>, weight: f32) -> Rgba<u8> {
+    let mut result = original;
+    for i in 0..4 {
+        let value = original.0[i] as f32 * (1.0 - weight) + processed.0[i] as f32 * weight;
+        result.0[i] = value.round().clamp(0.0, 255.0) as u8;
+    }
+
+    result
+}
+
+pub struct RegionProcessor {
+    region: Region,
+    processor: Box<dyn CpuProcessor>,
+}
+
+impl RegionProcessor {
+    pub fn new(region: Region, processor: Box<dyn CpuProcessor>) -> Self {
+        Self { region, processor }
+    }
+}
+
+impl CpuProcessor for RegionProcessor {
+    fn name(&self) -> &'static str {
+        self.processor.name()
+    }
+    fn process(
+        &self,
+        image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let margin = self.processor.kernel_radius();
+        self.region.apply(image, margin, |image| {
+            self.processor.process(image, progress)
+        })
+    }
+
+    fn kernel_radius(&self) -> usize {
+        self.processor.kernel_radius()
+    }
+}
@@ -0,0 +1,138 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use crate::common::structure_tensor::LocalOrientation;
+
+// Keeps the local ellipse between half and twice the radius on either axis
+const MAX_ELLIPSE_STRETCH: f32 = 2.0;
+// Sector variances are compared in 0-255 units so the sharpness behaves the same at any scale
+const VARIANCE_SCALE: f32 = 255.0;
+
+// Generalized Kuwahara filter, the window is split into overlapping sectors with smooth weights
+// and the sector means are blended by how flat each sector is instead of picking one of them
+pub struct SectorKuwahara {
+    radius: f32,
+    sectors: usize,
+    sharpness: f32,
+}
+
+impl SectorKuwahara {
+    pub fn new(radius: usize, sectors: usize, sharpness: f32) -> Self {
+        Self {
+            radius: radius.max(1) as f32,
+            sectors: sectors.max(2),
+            sharpness: sharpness.max(0.0),
+        }
+    }
+
+    // farthest offset the window of any orientation can reach
+    pub fn kernel_radius(radius: usize) -> usize {
+        (radius.max(1) as f32 * MAX_ELLIPSE_STRETCH).ceil() as usize
+    }
+
+    // Filters one pixel of sRGB colors in 0-1 units, samples outside the image repeat the
+    // nearest edge pixel. The window is a circle for isotropic areas and an ellipse stretched
+    // along the edge with growing anisotropy, eccentricity tunes how fast it stretches.
+    pub fn filter(
+        &self,
+        colors: &[[f32; 3]],
+        width: usize,
+        height: usize,
+        (x, y): (usize, usize),
+        orientation: LocalOrientation,
+        eccentricity: f32,
+    ) -> [f32; 3] {
+        let eccentricity = eccentricity.max(f32::EPSILON);
+        let stretch = ((eccentricity + orientation.anisotropy) / eccentricity)
+            .clamp(1.0 / MAX_ELLIPSE_STRETCH, MAX_ELLIPSE_STRETCH);
+        let major = self.radius * stretch;
+        let minor = self.radius / stretch;
+        let (sin, cos) = orientation.angle.sin_cos();
+        let extent_x = (major * major * cos * cos + minor * minor * sin * sin)
+            .sqrt()
+            .ceil() as i32;
+        let extent_y = (major * major * sin * sin + minor * minor * cos * cos)
+            .sqrt()
+            .ceil() as i32;
+
+        let mut weights = vec![0.0f32; self.sectors];
+        let mut sums = vec![[0.0f32; 3]; self.sectors];
+        let mut squared_sums = vec![[0.0f32; 3]; self.sectors];
+        let mut sector_weights = vec![0.0f32; self.sectors];
+        for dy in -extent_y..=extent_y {
+            for dx in -extent_x..=extent_x {
+                // offset in the frame of the ellipse, scaled to the unit disc
+                let local_x = (cos * dx as f32 + sin * dy as f32) / major;
+                let local_y = (-sin * dx as f32 + cos * dy as f32) / minor;
+                let distance_squared = local_x * local_x + local_y * local_y;
+                if distance_squared > 1.0 {
+                    continue;
+                }
+
+                self.sector_weights(local_x, local_y, &mut sector_weights);
+                let falloff = (-distance_squared).exp();
+                let sample_x = (x as i32 + dx).clamp(0, width as i32 - 1) as usize;
+                let sample_y = (y as i32 + dy).clamp(0, height as i32 - 1) as usize;
+                let color = colors[sample_y * width + sample_x];
+                for (sector, sector_weight) in sector_weights.iter().enumerate() {
+                    let weight = sector_weight * falloff;
+                    if weight == 0.0 {
+                        continue;
+                    }
+                    weights[sector] += weight;
+                    for channel in 0..3 {
+                        sums[sector][channel] += weight * color[channel];
+                        squared_sums[sector][channel] += weight * color[channel] * color[channel];
+                    }
+                }
+            }
+        }
+
+        let mut result = [0.0f32; 3];
+        let mut total = 0.0f32;
+        // every flatness can underflow to zero for huge sharpness values
+        let mut flattest = (f32::MAX, [0.0f32; 3]);
+        for ((weight, sum), squared_sum) in weights.iter().zip(&sums).zip(&squared_sums) {
+            if *weight == 0.0 {
+                continue;
+            }
+            let mean = sum.map(|value| value / weight);
+            let variance: f32 = (0..3)
+                .map(|channel| {
+                    (squared_sum[channel] / weight - mean[channel] * mean[channel]).abs()
+                })
+                .sum();
+            if variance < flattest.0 {
+                flattest = (variance, mean);
+            }
+            let flatness = 1.0 / (1.0 + (VARIANCE_SCALE * variance).powf(self.sharpness / 2.0));
+            total += flatness;
+            for channel in 0..3 {
+                result[channel] += flatness * mean[channel];
+            }
+        }
+
+        match total > 0.0 {
+            true => result.map(|value| value / total),
+            false => flattest.1,
+        }
+    }
+
+    // Smooth sector indicators, every sector fades out halfway into its neighbors so each
+    // direction is covered by two sectors. The center belongs to all of them.
+    fn sector_weights(&self, local_x: f32, local_y: f32, weights: &mut [f32]) {
+        if local_x == 0.0 && local_y == 0.0 {
+            weights.fill(1.0);
+            return;
+        }
+        let sector_angle = TAU / self.sectors as f32;
+        let angle = local_y.atan2(local_x).rem_euclid(TAU);
+        for (sector, weight) in weights.iter_mut().enumerate() {
+            let center = sector as f32 * sector_angle;
+            let difference = (angle - center + TAU / 2.0).rem_euclid(TAU) - TAU / 2.0;
+            *weight = match difference.abs() < sector_angle {
+                true => (difference / sector_angle * FRAC_PI_2).cos().powi(2),
+                false => 0.0,
+            };
+        }
+    }
+}
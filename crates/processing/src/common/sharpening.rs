@@ -0,0 +1,126 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::plane_image::{PlaneImage, PlaneImageF32};
+use picturify_core::palette::{FromColor, Lab, Srgb};
+use picturify_core::rayon::prelude::*;
+
+use crate::common::functions::gaussian_1d;
+
+// Lab lightness runs from 0 to 100, scaled so thresholds mean the same in both modes
+const LIGHTNESS_SCALE: f32 = 255.0 / 100.0;
+
+// Planes sharpening works on in 0-255 units, either the three color channels or only the Lab
+// lightness, which keeps color noise from being amplified
+pub struct SharpeningPlanes {
+    planes: Vec<PlaneImageF32>,
+    chroma: Option<Vec<[f32; 2]>>,
+}
+
+impl SharpeningPlanes {
+    pub fn from_image(image: &FastImage, luminance_only: bool) -> Self {
+        if luminance_only {
+            let lab = PlaneImage::from_fn(image.size(), |coord| {
+                let [red, green, blue] =
+                    [0, 1, 2].map(|channel| image.get_image_pixel(coord).0[channel] as f32);
+                Lab::from_color(Srgb::new(red, green, blue) / 255.0)
+            });
+            return Self {
+                planes: vec![PlaneImage::from_fn(image.size(), |coord| {
+                    lab.get(coord).l * LIGHTNESS_SCALE
+                })],
+                chroma: Some(lab.values().iter().map(|lab| [lab.a, lab.b]).collect()),
+            };
+        }
+
+        let planes = (0..3)
+            .map(|channel| {
+                PlaneImage::from_fn(image.size(), |coord| {
+                    image.get_image_pixel(coord).0[channel] as f32
+                })
+            })
+            .collect();
+        Self {
+            planes,
+            chroma: None,
+        }
+    }
+
+    pub fn planes_mut(&mut self) -> &mut [PlaneImageF32] {
+        &mut self.planes
+    }
+
+    // alpha is left untouched
+    pub fn write_to_image(&self, image: &mut FastImage) {
+        match &self.chroma {
+            Some(chroma) => image
+                .pixels_mut()
+                .zip(self.planes[0].values().iter().zip(chroma))
+                .for_each(|(pixel, (lightness, [a, b]))| {
+                    let lab = Lab::new(lightness / LIGHTNESS_SCALE, *a, *b);
+                    let srgb = Srgb::from_color(lab) * 255.0;
+                    for (channel, value) in
+                        pixel.0.iter_mut().zip([srgb.red, srgb.green, srgb.blue])
+                    {
+                        *channel = value.round().clamp(0.0, 255.0) as u8;
+                    }
+                }),
+            None => image.pixels_mut().enumerate().for_each(|(index, pixel)| {
+                for (channel, plane) in pixel.0.iter_mut().zip(&self.planes) {
+                    *channel = plane.values()[index].round().clamp(0.0, 255.0) as u8;
+                }
+            }),
+        }
+    }
+}
+
+// separable blur, samples outside the plane repeat the nearest edge value
+pub fn gaussian_blur_plane(plane: &PlaneImageF32, radius: usize, sigma: f32) -> PlaneImageF32 {
+    let (width, height) = (plane.width(), plane.height());
+    if width == 0 {
+        return plane.clone();
+    }
+    let sigma = sigma.max(f32::EPSILON);
+    let weights: Vec<f32> = (-(radius as i32)..=radius as i32)
+        .map(|offset| gaussian_1d(offset as f32, sigma))
+        .collect();
+    let total: f32 = weights.iter().sum();
+    let weights: Vec<f32> = weights.iter().map(|weight| weight / total).collect();
+    let clamp = |value: usize, offset: usize, max: usize| {
+        (value + offset).saturating_sub(radius).min(max - 1)
+    };
+
+    let mut horizontal: PlaneImageF32 = PlaneImage::empty(plane.size());
+    horizontal
+        .values_mut()
+        .par_chunks_mut(width)
+        .enumerate()
+        .for_each(|(y, row)| {
+            for (x, value) in row.iter_mut().enumerate() {
+                *value = weights
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, weight)| {
+                        weight * plane.values()[y * width + clamp(x, offset, width)]
+                    })
+                    .sum();
+            }
+        });
+
+    let mut blurred: PlaneImageF32 = PlaneImage::empty(plane.size());
+    blurred
+        .values_mut()
+        .par_chunks_mut(width)
+        .enumerate()
+        .for_each(|(y, row)| {
+            for (x, value) in row.iter_mut().enumerate() {
+                *value = weights
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, weight)| {
+                        weight * horizontal.values()[clamp(y, offset, height) * width + x]
+                    })
+                    .sum();
+            }
+        });
+
+    blurred
+}
@@ -0,0 +1,100 @@
+use std::f32::consts::FRAC_PI_2;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::plane_image::PlaneImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::coord::Coord;
+
+use crate::common::kernels::sobel::SobelKernels;
+use crate::common::sharpening::gaussian_blur_plane;
+
+// Local orientation of every pixel, taken from the smoothed structure tensor of the sRGB channels
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LocalOrientation {
+    // direction along the edge in radians, perpendicular to the dominant gradient
+    pub angle: f32,
+    // 0 for isotropic areas up to 1 for perfectly straight edges
+    pub anisotropy: f32,
+}
+
+pub struct StructureTensor {
+    orientations: PlaneImage<LocalOrientation>,
+}
+
+impl StructureTensor {
+    pub fn new(image: &FastImage, sigma: f32) -> ProcessingPicturifyResult<Self> {
+        let (width, height): (usize, usize) = image.size().into();
+        let kernels = SobelKernels::create()?;
+        let kernel_radius = kernels.radius() as i32;
+        let taps: Vec<(i32, i32, f32, f32)> = kernels
+            .iter()
+            .map(|(coord, x_value, y_value)| {
+                let (x, y): (i32, i32) = coord.into();
+                (x - kernel_radius, y - kernel_radius, x_value, y_value)
+            })
+            .collect();
+
+        // the three distinct tensor components, summed over the channels
+        let tensor = PlaneImage::from_fn(image.size(), |coord| {
+            let (x, y): (i32, i32) = coord.into();
+            let mut gradient_x = [0.0f32; 3];
+            let mut gradient_y = [0.0f32; 3];
+            for (dx, dy, x_value, y_value) in &taps {
+                let sample = image.get_image_pixel(Coord::new(
+                    (x + dx).clamp(0, width as i32 - 1),
+                    (y + dy).clamp(0, height as i32 - 1),
+                ));
+                for channel in 0..3 {
+                    let value = sample.0[channel] as f32 / 255.0;
+                    gradient_x[channel] += x_value * value;
+                    gradient_y[channel] += y_value * value;
+                }
+            }
+            let mut components = [0.0f32; 3];
+            for (gx, gy) in gradient_x.into_iter().zip(gradient_y) {
+                components[0] += gx * gx;
+                components[1] += gx * gy;
+                components[2] += gy * gy;
+            }
+            components
+        });
+
+        let [e, f, g] = [0, 1, 2].map(|component| {
+            let plane = PlaneImage::from_fn(image.size(), |coord| tensor.get(coord)[component]);
+            gaussian_blur_plane(&plane, Self::smoothing_radius(sigma), sigma)
+        });
+
+        let orientations = PlaneImage::from_fn(image.size(), |coord| {
+            let (e, f, g) = (e.get(coord), f.get(coord), g.get(coord));
+            let root = ((e - g) * (e - g) + 4.0 * f * f).sqrt();
+            let major = (e + g + root) / 2.0;
+            let minor = (e + g - root) / 2.0;
+            let (tangent_x, tangent_y) = (major - e, -f);
+            // no off-diagonal term means the gradient runs along x, so the edge runs along y
+            let angle = match tangent_x != 0.0 || tangent_y != 0.0 {
+                true => tangent_y.atan2(tangent_x),
+                false => FRAC_PI_2,
+            };
+            let anisotropy = match major + minor > f32::EPSILON {
+                true => (major - minor) / (major + minor),
+                false => 0.0,
+            };
+            LocalOrientation { angle, anisotropy }
+        });
+
+        Ok(Self { orientations })
+    }
+
+    // how far the gradients and the smoothing reach together
+    pub fn kernel_radius(sigma: f32) -> usize {
+        Self::smoothing_radius(sigma) + 1
+    }
+
+    pub fn orientation(&self, coord: Coord) -> LocalOrientation {
+        *self.orientations.get(coord)
+    }
+
+    fn smoothing_radius(sigma: f32) -> usize {
+        (3.0 * sigma.max(f32::EPSILON)).ceil() as usize
+    }
+}
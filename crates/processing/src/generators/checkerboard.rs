@@ -0,0 +1,47 @@
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::size::Size;
+use picturify_core::image::Rgba;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::generators::CpuGenerator;
+
+pub struct CheckerboardGeneratorOptions {
+    pub cell_size: usize,
+    pub first: Rgba<u8>,
+    pub second: Rgba<u8>,
+}
+
+pub struct CheckerboardGenerator {
+    options: CheckerboardGeneratorOptions,
+}
+
+impl CheckerboardGenerator {
+    pub fn new(options: CheckerboardGeneratorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuGenerator for CheckerboardGenerator {
+    fn name(&self) -> &'static str {
+        "checkerboard"
+    }
+    fn generate(&self, size: Size, progress: Progress) -> ProcessingPicturifyResult<FastImage> {
+        let cell_size = self.options.cell_size.max(1);
+        let mut image = FastImage::empty(size);
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let (x, y): (usize, usize) = coord.into();
+                *pixel = if (x / cell_size + y / cell_size) % 2 == 1 {
+                    self.options.second
+                } else {
+                    self.options.first
+                };
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
@@ -0,0 +1,62 @@
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::size::Size;
+use picturify_core::image::Rgba;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::generators::CpuGenerator;
+
+// EBU order: white, yellow, cyan, green, magenta, red, blue, black
+const BARS: [[bool; 3]; 8] = [
+    [true, true, true],
+    [true, true, false],
+    [false, true, true],
+    [false, true, false],
+    [true, false, true],
+    [true, false, false],
+    [false, false, true],
+    [false, false, false],
+];
+
+pub struct ColorBarsGeneratorOptions {
+    // level of the lit channels, 0.75 gives the common 75% bars
+    pub intensity: f32,
+}
+
+pub struct ColorBarsGenerator {
+    options: ColorBarsGeneratorOptions,
+}
+
+impl ColorBarsGenerator {
+    pub fn new(options: ColorBarsGeneratorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuGenerator for ColorBarsGenerator {
+    fn name(&self) -> &'static str {
+        "color-bars"
+    }
+    fn generate(&self, size: Size, progress: Progress) -> ProcessingPicturifyResult<FastImage> {
+        let (width, _): (usize, usize) = size.into();
+        let level = (self.options.intensity.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        let mut image = FastImage::empty(size);
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let (x, _): (usize, usize) = coord.into();
+                let bar = BARS[x * BARS.len() / width];
+                *pixel = Rgba([
+                    if bar[0] { level } else { 0 },
+                    if bar[1] { level } else { 0 },
+                    if bar[2] { level } else { 0 },
+                    u8::MAX,
+                ]);
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
@@ -0,0 +1,89 @@
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::angle::Angle;
+use picturify_core::geometry::size::Size;
+use picturify_core::image::Rgba;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::generators::CpuGenerator;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientKind {
+    Linear,
+    Radial,
+}
+
+impl ValueEnum for GradientKind {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[GradientKind::Linear, GradientKind::Radial]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            GradientKind::Linear => Some(PossibleValue::new("linear")),
+            GradientKind::Radial => Some(PossibleValue::new("radial")),
+        }
+    }
+}
+
+pub struct GradientGeneratorOptions {
+    pub kind: GradientKind,
+    pub start: Rgba<u8>,
+    pub end: Rgba<u8>,
+    // direction of linear gradients, 0 runs left to right and positive angles turn clockwise
+    pub angle: Angle,
+}
+
+pub struct GradientGenerator {
+    options: GradientGeneratorOptions,
+}
+
+impl GradientGenerator {
+    pub fn new(options: GradientGeneratorOptions) -> Self {
+        Self { options }
+    }
+
+    fn mix(&self, t: f32) -> Rgba<u8> {
+        let t = t.clamp(0.0, 1.0);
+        Rgba([0, 1, 2, 3].map(|channel| {
+            let start = self.options.start.0[channel] as f32;
+            let end = self.options.end.0[channel] as f32;
+            (start + (end - start) * t).round() as u8
+        }))
+    }
+}
+
+impl CpuGenerator for GradientGenerator {
+    fn name(&self) -> &'static str {
+        "gradient"
+    }
+    fn generate(&self, size: Size, progress: Progress) -> ProcessingPicturifyResult<FastImage> {
+        let (width, height): (usize, usize) = size.into();
+        let (width, height) = (width as f32, height as f32);
+        let (sin, cos) = self.options.angle.to_sin_cos();
+        // length of the image projected onto the gradient direction, so both ends touch a corner
+        let extent = (width * cos.abs() + height * sin.abs()).max(f32::EPSILON);
+        let radius = ((width * width + height * height).sqrt() / 2.0).max(f32::EPSILON);
+
+        let mut image = FastImage::empty(size);
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let (x, y): (usize, usize) = coord.into();
+                let dx = x as f32 + 0.5 - width / 2.0;
+                let dy = y as f32 + 0.5 - height / 2.0;
+                let t = match self.options.kind {
+                    GradientKind::Linear => 0.5 + (dx * cos + dy * sin) / extent,
+                    GradientKind::Radial => (dx * dx + dy * dy).sqrt() / radius,
+                };
+                *pixel = self.mix(t);
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod checkerboard;
+pub mod color_bars;
+pub mod gradient;
+pub mod perlin_noise;
+pub mod zone_plate;
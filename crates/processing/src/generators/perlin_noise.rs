@@ -0,0 +1,129 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::size::Size;
+use picturify_core::image::Rgba;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::generators::CpuGenerator;
+use crate::common::noise::resolve_seed;
+
+const GRADIENTS: [(f32, f32); 8] = [
+    (1.0, 1.0),
+    (-1.0, 1.0),
+    (1.0, -1.0),
+    (-1.0, -1.0),
+    (1.0, 0.0),
+    (-1.0, 0.0),
+    (0.0, 1.0),
+    (0.0, -1.0),
+];
+
+pub struct PerlinNoiseGeneratorOptions {
+    // size of the coarsest lattice cell in pixels
+    pub scale: f32,
+    pub octaves: usize,
+    // amplitude of every octave relative to the previous one
+    pub persistence: f32,
+    pub seed: Option<u64>,
+}
+
+// Grayscale fractal noise built from octaves of improved Perlin gradient noise
+pub struct PerlinNoiseGenerator {
+    options: PerlinNoiseGeneratorOptions,
+}
+
+impl PerlinNoiseGenerator {
+    pub fn new(options: PerlinNoiseGeneratorOptions) -> Self {
+        Self { options }
+    }
+}
+
+struct Permutation {
+    table: [u8; 512],
+}
+
+impl Permutation {
+    fn new(seed: u64) -> Self {
+        let mut values: Vec<u8> = (0..=255).collect();
+        values.shuffle(&mut StdRng::seed_from_u64(seed));
+
+        // doubled, so lattice lookups never have to wrap
+        let mut table = [0; 512];
+        for (index, value) in table.iter_mut().enumerate() {
+            *value = values[index % 256];
+        }
+        Self { table }
+    }
+
+    fn hash(&self, x: usize, y: usize) -> usize {
+        self.table[self.table[x & 255] as usize + (y & 255)] as usize
+    }
+
+    // roughly in -1..1
+    fn noise(&self, x: f32, y: f32) -> f32 {
+        let (cell_x, cell_y) = (x.floor(), y.floor());
+        let (fx, fy) = (x - cell_x, y - cell_y);
+        let (ix, iy) = (cell_x as i64 as usize, cell_y as i64 as usize);
+
+        let corner = |offset_x: usize, offset_y: usize| {
+            let (gx, gy) =
+                GRADIENTS[self.hash(ix.wrapping_add(offset_x), iy.wrapping_add(offset_y)) % 8];
+            gx * (fx - offset_x as f32) + gy * (fy - offset_y as f32)
+        };
+        let (u, v) = (fade(fx), fade(fy));
+
+        let top = lerp(corner(0, 0), corner(1, 0), u);
+        let bottom = lerp(corner(0, 1), corner(1, 1), u);
+        lerp(top, bottom, v)
+    }
+}
+
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+impl CpuGenerator for PerlinNoiseGenerator {
+    fn name(&self) -> &'static str {
+        "perlin-noise"
+    }
+    fn generate(&self, size: Size, progress: Progress) -> ProcessingPicturifyResult<FastImage> {
+        let permutation = Permutation::new(resolve_seed(self.options.seed));
+        let scale = self.options.scale.max(f32::EPSILON);
+        let octaves = self.options.octaves.max(1);
+        let persistence = self.options.persistence;
+        let total_amplitude: f32 = (0..octaves)
+            .map(|octave| persistence.powi(octave as i32))
+            .sum();
+
+        let mut image = FastImage::empty(size);
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let (x, y): (usize, usize) = coord.into();
+                let mut frequency = 1.0 / scale;
+                let mut amplitude = 1.0;
+                let mut value = 0.0;
+                for _ in 0..octaves {
+                    value +=
+                        amplitude * permutation.noise(x as f32 * frequency, y as f32 * frequency);
+                    frequency *= 2.0;
+                    amplitude *= persistence;
+                }
+                let value = value / total_amplitude.max(f32::EPSILON);
+                let value = ((0.5 + 0.5 * value).clamp(0.0, 1.0) * 255.0).round() as u8;
+                *pixel = Rgba([value, value, value, u8::MAX]);
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
@@ -0,0 +1,54 @@
+use std::f32::consts::PI;
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::size::Size;
+use picturify_core::image::Rgba;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::generators::CpuGenerator;
+
+pub struct ZonePlateGeneratorOptions {
+    // cycles per pixel reached at the middle of the shorter edge, 0.5 is the Nyquist limit
+    pub frequency: f32,
+}
+
+// Concentric rings whose frequency grows linearly with the distance from the center, any
+// resampling or filtering artifacts show up as moire
+pub struct ZonePlateGenerator {
+    options: ZonePlateGeneratorOptions,
+}
+
+impl ZonePlateGenerator {
+    pub fn new(options: ZonePlateGeneratorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuGenerator for ZonePlateGenerator {
+    fn name(&self) -> &'static str {
+        "zone-plate"
+    }
+    fn generate(&self, size: Size, progress: Progress) -> ProcessingPicturifyResult<FastImage> {
+        let (width, height): (usize, usize) = size.into();
+        let (width, height) = (width as f32, height as f32);
+        let max_radius = (width.min(height) / 2.0).max(1.0);
+        let frequency = self.options.frequency;
+
+        let mut image = FastImage::empty(size);
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let (x, y): (usize, usize) = coord.into();
+                let dx = x as f32 + 0.5 - width / 2.0;
+                let dy = y as f32 + 0.5 - height / 2.0;
+                let phase = PI * frequency * (dx * dx + dy * dy) / max_radius;
+                let value = ((0.5 + 0.5 * phase.cos()) * 255.0).round() as u8;
+                *pixel = Rgba([value, value, value, u8::MAX]);
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
@@ -1,6 +1,7 @@
 extern crate core;
 
 pub mod common;
+pub mod generators;
 pub mod metadata;
 pub mod processors;
 #[cfg(feature = "gpu")]
@@ -46,4 +46,8 @@ impl CpuProcessor for LaplacianOfGaussianProcessor {
 
         processor.process(image, progress)
     }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.radius
+    }
 }
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToPalettePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::palette::{Srgb, WithAlpha};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::color_space::ColorSpace;
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize)]
+pub struct ChannelExtractionProcessorOptions {
+    pub space: ColorSpace,
+    pub channel: usize,
+}
+
+pub struct ChannelExtractionProcessor {
+    options: ChannelExtractionProcessorOptions,
+}
+
+impl ChannelExtractionProcessor {
+    pub fn new(options: ChannelExtractionProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for ChannelExtractionProcessor {
+    fn name(&self) -> &'static str {
+        "channel-extraction"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let space = self.options.space;
+        let channel = self.options.channel;
+        if channel > 2 {
+            return Err(ProcessingPicturifyError::InvalidChannelSelector);
+        }
+
+        image.par_apply_fn_to_srgba(
+            |pixel, _coord| {
+                let value = space.to_channels(pixel.color)[channel].clamp(0.0, 1.0);
+                Srgb::new(value, value, value).with_alpha(pixel.alpha)
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
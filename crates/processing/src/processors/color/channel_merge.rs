@@ -0,0 +1,61 @@
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToPalettePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::palette::WithAlpha;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::color_space::ColorSpace;
+use crate::common::processors::CpuProcessor;
+
+pub struct ChannelMergeProcessorOptions {
+    pub space: ColorSpace,
+    pub channels: [Option<FastImage>; 3],
+}
+
+// Replaces channels of the image, in the given color space, with grayscale images
+pub struct ChannelMergeProcessor {
+    options: ChannelMergeProcessorOptions,
+}
+
+impl ChannelMergeProcessor {
+    pub fn new(options: ChannelMergeProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for ChannelMergeProcessor {
+    fn name(&self) -> &'static str {
+        "channel-merge"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let space = self.options.space;
+        let channels = &self.options.channels;
+        if channels
+            .iter()
+            .flatten()
+            .any(|channel| channel.size() != image.size())
+        {
+            return Err(ProcessingPicturifyError::InvalidChannelImage);
+        }
+
+        image.par_apply_fn_to_srgba(
+            |pixel, coord| {
+                let mut values = space.to_channels(pixel.color);
+                for (value, channel) in values.iter_mut().zip(channels) {
+                    if let Some(channel) = channel {
+                        let [red, green, blue, _] = channel.get_image_pixel(coord).0;
+                        *value = (red as f32 + green as f32 + blue as f32) / (3.0 * 255.0);
+                    }
+                }
+                space.from_channels(values).with_alpha(pixel.alpha)
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::lut::apply_tone_functions;
+use crate::common::processors::CpuProcessor;
+
+// Tone curve through control points normalized to 0..1, interpolated with a
+// monotone cubic spline so it never overshoots between points
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Curve {
+    points: Vec<(f32, f32)>,
+}
+
+impl Curve {
+    pub fn new(mut points: Vec<(f32, f32)>) -> Self {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { points }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(vec![(0.0, 0.0), (1.0, 1.0)])
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.points.len() >= 2 && self.points.windows(2).all(|pair| pair[0].0 < pair[1].0)
+    }
+
+    pub fn eval(&self, x: f32) -> f32 {
+        let points = &self.points;
+        let last = points.len() - 1;
+        if x <= points[0].0 {
+            return points[0].1;
+        }
+        if x >= points[last].0 {
+            return points[last].1;
+        }
+
+        let segment = points.partition_point(|point| point.0 <= x) - 1;
+        let (x0, y0) = points[segment];
+        let (x1, y1) = points[segment + 1];
+        let width = x1 - x0;
+        let t = (x - x0) / width;
+
+        let m0 = self.tangent(segment) * width;
+        let m1 = self.tangent(segment + 1) * width;
+
+        let t2 = t * t;
+        let t3 = t2 * t;
+        (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+            + (t3 - 2.0 * t2 + t) * m0
+            + (-2.0 * t3 + 3.0 * t2) * y1
+            + (t3 - t2) * m1
+    }
+
+    fn secant(&self, segment: usize) -> f32 {
+        let (x0, y0) = self.points[segment];
+        let (x1, y1) = self.points[segment + 1];
+        (y1 - y0) / (x1 - x0)
+    }
+
+    // harmonic mean of the neighbouring secants keeps the spline monotone
+    fn tangent(&self, index: usize) -> f32 {
+        let last = self.points.len() - 1;
+        if index == 0 {
+            return self.secant(0);
+        }
+        if index == last {
+            return self.secant(last - 1);
+        }
+
+        let before = self.secant(index - 1);
+        let after = self.secant(index);
+        if before * after <= 0.0 {
+            0.0
+        } else {
+            2.0 / (1.0 / before + 1.0 / after)
+        }
+    }
+}
+
+// Each channel goes through its own curve first and the master curve after it
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Curves {
+    pub master: Curve,
+    pub red: Curve,
+    pub green: Curve,
+    pub blue: Curve,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CurvesProcessorOptions {
+    pub curves: Curves,
+    pub use_fast_approximation: bool,
+}
+
+pub struct CurvesProcessor {
+    options: CurvesProcessorOptions,
+}
+
+impl CurvesProcessor {
+    pub fn new(options: CurvesProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for CurvesProcessor {
+    fn name(&self) -> &'static str {
+        "curves"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let curves = &self.options.curves;
+        let all_curves = [&curves.master, &curves.red, &curves.green, &curves.blue];
+        if !all_curves.iter().all(|curve| curve.is_valid()) {
+            return Err(ProcessingPicturifyError::InvalidCurve);
+        }
+
+        let red = |x| curves.master.eval(curves.red.eval(x));
+        let green = |x| curves.master.eval(curves.green.eval(x));
+        let blue = |x| curves.master.eval(curves.blue.eval(x));
+        apply_tone_functions(
+            &mut image,
+            [&red, &green, &blue],
+            self.options.use_fast_approximation,
+            progress,
+        );
+
+        Ok(image)
+    }
+}
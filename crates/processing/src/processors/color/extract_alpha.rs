@@ -0,0 +1,27 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::plane_image::PlaneImageU8;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+
+pub struct ExtractAlphaProcessor;
+
+impl CpuProcessor for ExtractAlphaProcessor {
+    fn name(&self) -> &'static str {
+        "extract-alpha"
+    }
+    fn process(
+        &self,
+        image: FastImage,
+        mut progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        progress.setup(1);
+        // opaque grayscale image, usable directly as a mask
+        let alpha = PlaneImageU8::from_channel(&image, 3)
+            .map_err(ProcessingPicturifyError::from_plane_error)?;
+        progress.increment();
+
+        Ok(alpha.to_gray_image())
+    }
+}
@@ -0,0 +1,58 @@
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::image::Rgba;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+
+pub struct FlattenProcessorOptions {
+    pub background: Rgba<u8>,
+}
+
+pub struct FlattenProcessor {
+    options: FlattenProcessorOptions,
+}
+
+impl FlattenProcessor {
+    pub fn new(options: FlattenProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for FlattenProcessor {
+    fn name(&self) -> &'static str {
+        "flatten"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let background = self.options.background;
+        let background_alpha = background.0[3] as f32 / 255.0;
+
+        // composites the image over the background ("over" operator)
+        image.par_apply_fn_to_image_pixel(
+            |pixel, _coord| {
+                let alpha = pixel.0[3] as f32 / 255.0;
+                let below = background_alpha * (1.0 - alpha);
+                let result_alpha = alpha + below;
+                if result_alpha <= 0.0 {
+                    *pixel = Rgba([0, 0, 0, 0]);
+                    return;
+                }
+
+                for i in 0..3 {
+                    let value =
+                        (pixel.0[i] as f32 * alpha + background.0[i] as f32 * below) / result_alpha;
+                    pixel.0[i] = value.round().clamp(0.0, 255.0) as u8;
+                }
+                pixel.0[3] = (result_alpha * 255.0).round().clamp(0.0, 255.0) as u8;
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
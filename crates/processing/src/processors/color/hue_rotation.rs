@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToPalettePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::angle::Angle;
+use picturify_core::palette::{FromColor, Oklch, Srgb, WithAlpha};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize)]
+pub struct HueRotationProcessorOptions {
+    pub angle: Angle,
+}
+
+pub struct HueRotationProcessor {
+    options: HueRotationProcessorOptions,
+}
+
+impl HueRotationProcessor {
+    pub fn new(options: HueRotationProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for HueRotationProcessor {
+    fn name(&self) -> &'static str {
+        "hue-rotation"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let degrees = self.options.angle.to_degrees();
+
+        // rotating in Oklch keeps the perceived lightness of every pixel unchanged
+        image.par_apply_fn_to_srgba(
+            |pixel, _coord| {
+                let mut oklch = Oklch::from_color(pixel.color);
+                oklch.hue += degrees;
+                Srgb::from_color(oklch).with_alpha(pixel.alpha)
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
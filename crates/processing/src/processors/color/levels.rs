@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::lut::apply_tone_functions;
+use crate::common::processors::CpuProcessor;
+
+// All values are normalized to 0..1, gamma above 1 brightens the midtones
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct Levels {
+    pub input_black: f32,
+    pub input_white: f32,
+    pub gamma: f32,
+    pub output_black: f32,
+    pub output_white: f32,
+}
+
+impl Levels {
+    pub fn eval(&self, x: f32) -> f32 {
+        let t = ((x - self.input_black) / (self.input_white - self.input_black)).clamp(0.0, 1.0);
+        let t = t.powf(1.0 / self.gamma);
+        self.output_black + t * (self.output_white - self.output_black)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct LevelsProcessorOptions {
+    pub levels: Levels,
+    pub use_fast_approximation: bool,
+}
+
+pub struct LevelsProcessor {
+    options: LevelsProcessorOptions,
+}
+
+impl LevelsProcessor {
+    pub fn new(options: LevelsProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for LevelsProcessor {
+    fn name(&self) -> &'static str {
+        "levels"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let levels = self.options.levels;
+        if levels.input_white <= levels.input_black || levels.gamma <= 0.0 {
+            return Err(ProcessingPicturifyError::InvalidLevels);
+        }
+
+        let function = |x| levels.eval(x);
+        apply_tone_functions(
+            &mut image,
+            [&function, &function, &function],
+            self.options.use_fast_approximation,
+            progress,
+        );
+
+        Ok(image)
+    }
+}
@@ -0,0 +1,80 @@
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToPalettePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::palette::{FromColor, Lch, Oklab, Srgb, WithAlpha};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub enum LightnessSpace {
+    Oklab,
+    Lch,
+}
+
+impl ValueEnum for LightnessSpace {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[LightnessSpace::Oklab, LightnessSpace::Lch]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            LightnessSpace::Oklab => Some(PossibleValue::new("oklab")),
+            LightnessSpace::Lch => Some(PossibleValue::new("lch")),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct LightnessProcessorOptions {
+    pub factor: f32,
+    pub space: LightnessSpace,
+}
+
+pub struct LightnessProcessor {
+    options: LightnessProcessorOptions,
+}
+
+impl LightnessProcessor {
+    pub fn new(options: LightnessProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for LightnessProcessor {
+    fn name(&self) -> &'static str {
+        "lightness"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let factor = self.options.factor;
+
+        match self.options.space {
+            LightnessSpace::Oklab => image.par_apply_fn_to_srgba(
+                |pixel, _coord| {
+                    let mut oklab = Oklab::from_color(pixel.color);
+                    oklab.l = (oklab.l * factor).clamp(0.0, 1.0);
+                    Srgb::from_color(oklab).with_alpha(pixel.alpha)
+                },
+                Some(progress),
+            ),
+            LightnessSpace::Lch => image.par_apply_fn_to_srgba(
+                |pixel, _coord| {
+                    let mut lch: Lch = Lch::from_color(pixel.color);
+                    lch.l = (lch.l * factor).clamp(0.0, 100.0);
+                    Srgb::from_color(lch).with_alpha(pixel.alpha)
+                },
+                Some(progress),
+            ),
+        }
+
+        Ok(image)
+    }
+}
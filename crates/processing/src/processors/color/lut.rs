@@ -0,0 +1,51 @@
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToPalettePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::lut3d::{Lut3d, Lut3dInterpolation};
+use crate::common::processors::CpuProcessor;
+
+pub struct LutProcessorOptions {
+    pub lut: Lut3d,
+    pub interpolation: Lut3dInterpolation,
+}
+
+pub struct LutProcessor {
+    options: LutProcessorOptions,
+}
+
+impl LutProcessor {
+    pub fn new(options: LutProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for LutProcessor {
+    fn name(&self) -> &'static str {
+        "lut"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let lut = &self.options.lut;
+        let interpolation = self.options.interpolation;
+
+        // LUTs are graded on encoded values, so they are sampled with sRGB components
+        image.par_apply_fn_to_srgba(
+            |mut pixel, _coord| {
+                let [red, green, blue] =
+                    lut.apply([pixel.red, pixel.green, pixel.blue], interpolation);
+                pixel.red = red.clamp(0.0, 1.0);
+                pixel.green = green.clamp(0.0, 1.0);
+                pixel.blue = blue.clamp(0.0, 1.0);
+                pixel
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
@@ -1,8 +1,22 @@
 pub mod brightness;
+pub mod channel_extraction;
+pub mod channel_merge;
+pub mod curves;
+pub mod extract_alpha;
+pub mod flatten;
 pub mod gamma;
 pub mod grayscale;
+pub mod hue_rotation;
+pub mod levels;
+pub mod lightness;
+pub mod lut;
 pub mod negative;
+pub mod palette_quantization;
 pub mod quantization;
 pub mod remapping;
+pub mod saturation;
 pub mod sepia;
+pub mod set_alpha;
+pub mod temperature;
 pub mod threshold;
+pub mod white_balance;
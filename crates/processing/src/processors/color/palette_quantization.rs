@@ -0,0 +1,195 @@
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::color_palette::{nearest, srgb_to_lab, ColorPalette};
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::coord::Coord;
+use picturify_core::image::Rgba;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+
+const BAYER_8X8: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+// error diffusion kernels as (dx, dy, weight), weights are divided by the divisor
+const FLOYD_STEINBERG: (&[(i32, i32, f32)], f32) =
+    (&[(1, 0, 7.0), (-1, 1, 3.0), (0, 1, 5.0), (1, 1, 1.0)], 16.0);
+// Atkinson only spreads 6/8 of the error, which keeps contrast high
+const ATKINSON: (&[(i32, i32, f32)], f32) = (
+    &[
+        (1, 0, 1.0),
+        (2, 0, 1.0),
+        (-1, 1, 1.0),
+        (0, 1, 1.0),
+        (1, 1, 1.0),
+        (0, 2, 1.0),
+    ],
+    8.0,
+);
+const JARVIS: (&[(i32, i32, f32)], f32) = (
+    &[
+        (1, 0, 7.0),
+        (2, 0, 5.0),
+        (-2, 1, 3.0),
+        (-1, 1, 5.0),
+        (0, 1, 7.0),
+        (1, 1, 5.0),
+        (2, 1, 3.0),
+        (-2, 2, 1.0),
+        (-1, 2, 3.0),
+        (0, 2, 5.0),
+        (1, 2, 3.0),
+        (2, 2, 1.0),
+    ],
+    48.0,
+);
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Dithering {
+    None,
+    FloydSteinberg,
+    Atkinson,
+    Jarvis,
+    Bayer,
+}
+
+impl ValueEnum for Dithering {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Dithering::None,
+            Dithering::FloydSteinberg,
+            Dithering::Atkinson,
+            Dithering::Jarvis,
+            Dithering::Bayer,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            Dithering::None => Some(PossibleValue::new("none")),
+            Dithering::FloydSteinberg => Some(PossibleValue::new("floyd-steinberg")),
+            Dithering::Atkinson => Some(PossibleValue::new("atkinson")),
+            Dithering::Jarvis => Some(PossibleValue::new("jarvis")),
+            Dithering::Bayer => Some(PossibleValue::new("bayer")),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PaletteQuantizationProcessorOptions {
+    pub palette: ColorPalette,
+    pub dithering: Dithering,
+}
+
+// Maps every pixel to the perceptually nearest palette color, alpha is left untouched
+pub struct PaletteQuantizationProcessor {
+    options: PaletteQuantizationProcessorOptions,
+}
+
+impl PaletteQuantizationProcessor {
+    pub fn new(options: PaletteQuantizationProcessorOptions) -> Self {
+        Self { options }
+    }
+
+    fn set_color(&self, pixel: &mut Rgba<u8>, index: usize) {
+        let color = self.options.palette.colors()[index];
+        pixel.0[..3].copy_from_slice(&color);
+    }
+
+    fn process_ordered(&self, mut image: FastImage, bayer: bool, progress: Progress) -> FastImage {
+        let palette_lab = self.options.palette.lab_colors();
+        // spread of the threshold map matches the average spacing of palette colors
+        let spread = 255.0 / (self.options.palette.len() as f32).cbrt();
+
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let (x, y): (usize, usize) = coord.into();
+                let offset = if bayer {
+                    (BAYER_8X8[y % 8][x % 8] as f32 + 0.5) / 64.0 - 0.5
+                } else {
+                    0.0
+                };
+                let color = [0, 1, 2].map(|channel| pixel.0[channel] as f32 + offset * spread);
+                let index = nearest(&palette_lab, srgb_to_lab(color));
+                self.set_color(pixel, index);
+            },
+            Some(progress),
+        );
+
+        image
+    }
+
+    fn process_diffusion(
+        &self,
+        mut image: FastImage,
+        (kernel, divisor): (&[(i32, i32, f32)], f32),
+        mut progress: Progress,
+    ) -> FastImage {
+        let palette_lab = self.options.palette.lab_colors();
+        let (width, height) = image.size().into();
+        let mut buffer: Vec<[f32; 3]> = image
+            .pixels()
+            .map(|pixel| srgb_to_lab([0, 1, 2].map(|channel| pixel.0[channel] as f32)))
+            .collect();
+
+        progress.setup(height);
+        for y in 0..height {
+            for x in 0..width {
+                let old = buffer[y * width + x];
+                let index = nearest(&palette_lab, old);
+                let error = [0, 1, 2].map(|channel| old[channel] - palette_lab[index][channel]);
+
+                for &(dx, dy, weight) in kernel {
+                    let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                    if nx >= 0 && (nx as usize) < width && (ny as usize) < height {
+                        let target = &mut buffer[ny as usize * width + nx as usize];
+                        for channel in 0..3 {
+                            target[channel] += error[channel] * weight / divisor;
+                        }
+                    }
+                }
+
+                let coord = Coord::from((x, y));
+                let mut pixel = image.get_image_pixel(coord);
+                self.set_color(&mut pixel, index);
+                image.set_image_pixel(coord, pixel);
+            }
+            progress.increment();
+        }
+
+        image
+    }
+}
+
+impl CpuProcessor for PaletteQuantizationProcessor {
+    fn name(&self) -> &'static str {
+        "palette-quantization"
+    }
+    fn process(
+        &self,
+        image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let image = match self.options.dithering {
+            Dithering::None => self.process_ordered(image, false, progress),
+            Dithering::Bayer => self.process_ordered(image, true, progress),
+            Dithering::FloydSteinberg => self.process_diffusion(image, FLOYD_STEINBERG, progress),
+            Dithering::Atkinson => self.process_diffusion(image, ATKINSON, progress),
+            Dithering::Jarvis => self.process_diffusion(image, JARVIS, progress),
+        };
+
+        Ok(image)
+    }
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToPalettePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::palette::{FromColor, Oklch, Srgb, WithAlpha};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+
+// approximate chroma of the most saturated sRGB colors in Oklch
+const MAX_CHROMA: f32 = 0.32;
+
+#[derive(Serialize, Deserialize)]
+pub struct SaturationProcessorOptions {
+    pub factor: f32,
+    pub vibrance: f32,
+}
+
+pub struct SaturationProcessor {
+    options: SaturationProcessorOptions,
+}
+
+impl SaturationProcessor {
+    pub fn new(options: SaturationProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for SaturationProcessor {
+    fn name(&self) -> &'static str {
+        "saturation"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let factor = self.options.factor;
+        let vibrance = self.options.vibrance;
+
+        // vibrance boosts muted colors more than those that are already saturated
+        image.par_apply_fn_to_srgba(
+            |pixel, _coord| {
+                let mut oklch = Oklch::from_color(pixel.color);
+                let muted = 1.0 - (oklch.chroma / MAX_CHROMA).clamp(0.0, 1.0);
+                oklch.chroma = (oklch.chroma * factor * (1.0 + vibrance * muted)).max(0.0);
+                Srgb::from_color(oklch).with_alpha(pixel.alpha)
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
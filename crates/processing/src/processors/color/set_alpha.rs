@@ -0,0 +1,46 @@
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+use crate::common::region::Mask;
+
+pub struct SetAlphaProcessorOptions {
+    pub alpha: Mask,
+}
+
+pub struct SetAlphaProcessor {
+    options: SetAlphaProcessorOptions,
+}
+
+impl SetAlphaProcessor {
+    pub fn new(options: SetAlphaProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for SetAlphaProcessor {
+    fn name(&self) -> &'static str {
+        "set-alpha"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let alpha = &self.options.alpha;
+        if alpha.size() != image.size() {
+            return Err(ProcessingPicturifyError::InvalidMask);
+        }
+
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                pixel.0[3] = (alpha.weight(coord) * 255.0).round().clamp(0.0, 255.0) as u8;
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
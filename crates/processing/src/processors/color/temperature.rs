@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use picturify_core::conversions::icc::apply;
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToPalettePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::chromatic_adaptation::adaptation_from_temperature;
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize)]
+pub struct TemperatureProcessorOptions {
+    // color temperature of the scene light in Kelvin
+    pub temperature: f32,
+    pub tint: f32,
+}
+
+pub struct TemperatureProcessor {
+    options: TemperatureProcessorOptions,
+}
+
+impl TemperatureProcessor {
+    pub fn new(options: TemperatureProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for TemperatureProcessor {
+    fn name(&self) -> &'static str {
+        "temperature"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let matrix = adaptation_from_temperature(self.options.temperature, self.options.tint);
+
+        image.par_apply_fn_to_lin_srgba(
+            |mut pixel, _coord| {
+                let [red, green, blue] = apply(&matrix, [pixel.red, pixel.green, pixel.blue]);
+                pixel.red = red.clamp(0.0, 1.0);
+                pixel.green = green.clamp(0.0, 1.0);
+                pixel.blue = blue.clamp(0.0, 1.0);
+                pixel
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
@@ -0,0 +1,166 @@
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use picturify_core::conversions::icc::apply;
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToPalettePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::util::image_rgba_to_palette_srgba;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::geometry::coord::Coord;
+use picturify_core::palette::LinSrgba;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::chromatic_adaptation::adaptation_from_neutral;
+use crate::common::processors::CpuProcessor;
+
+// share of the brightest pixels averaged by the white patch method
+const WHITE_PATCH_FRACTION: f32 = 0.01;
+// neutral color is averaged over a small window to reduce noise
+const NEUTRAL_SAMPLE_RADIUS: i32 = 2;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum WhiteBalanceMethod {
+    GrayWorld,
+    WhitePatch,
+    Neutral,
+}
+
+impl ValueEnum for WhiteBalanceMethod {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            WhiteBalanceMethod::GrayWorld,
+            WhiteBalanceMethod::WhitePatch,
+            WhiteBalanceMethod::Neutral,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            WhiteBalanceMethod::GrayWorld => Some(PossibleValue::new("gray-world")),
+            WhiteBalanceMethod::WhitePatch => Some(PossibleValue::new("white-patch")),
+            WhiteBalanceMethod::Neutral => Some(PossibleValue::new("neutral")),
+        }
+    }
+}
+
+pub struct WhiteBalanceProcessorOptions {
+    pub method: WhiteBalanceMethod,
+    // pixel that should become neutral, used by the neutral method
+    pub point: Option<Coord>,
+}
+
+pub struct WhiteBalanceProcessor {
+    options: WhiteBalanceProcessorOptions,
+}
+
+impl WhiteBalanceProcessor {
+    pub fn new(options: WhiteBalanceProcessorOptions) -> Self {
+        Self { options }
+    }
+
+    fn linear_pixels(image: &FastImage) -> Vec<LinSrgba> {
+        image
+            .pixels()
+            .map(|pixel| image_rgba_to_palette_srgba(*pixel).into_linear())
+            .collect()
+    }
+
+    fn gray_world(image: &FastImage) -> [f32; 3] {
+        let mut sum = [0.0; 3];
+        for pixel in Self::linear_pixels(image) {
+            sum[0] += pixel.red * pixel.alpha;
+            sum[1] += pixel.green * pixel.alpha;
+            sum[2] += pixel.blue * pixel.alpha;
+        }
+        sum
+    }
+
+    fn white_patch(image: &FastImage) -> [f32; 3] {
+        let pixels: Vec<LinSrgba> = Self::linear_pixels(image)
+            .into_iter()
+            .filter(|pixel| pixel.alpha > 0.0)
+            .collect();
+        if pixels.is_empty() {
+            return [0.0; 3];
+        }
+
+        let luminance =
+            |pixel: &LinSrgba| 0.2126 * pixel.red + 0.7152 * pixel.green + 0.0722 * pixel.blue;
+        let mut luminances: Vec<f32> = pixels.iter().map(luminance).collect();
+        let count = ((pixels.len() as f32 * WHITE_PATCH_FRACTION).ceil() as usize).max(1);
+        let threshold_index = pixels.len() - count;
+        let (_, threshold, _) = luminances.select_nth_unstable_by(threshold_index, f32::total_cmp);
+        let threshold = *threshold;
+
+        let mut sum = [0.0; 3];
+        for pixel in pixels.iter().filter(|pixel| luminance(pixel) >= threshold) {
+            sum[0] += pixel.red;
+            sum[1] += pixel.green;
+            sum[2] += pixel.blue;
+        }
+        sum
+    }
+
+    fn neutral(image: &FastImage, point: Coord) -> ProcessingPicturifyResult<[f32; 3]> {
+        if !point.in_bounds(image.size()) {
+            return Err(ProcessingPicturifyError::InvalidPoint);
+        }
+
+        let mut sum = [0.0; 3];
+        for y in -NEUTRAL_SAMPLE_RADIUS..=NEUTRAL_SAMPLE_RADIUS {
+            for x in -NEUTRAL_SAMPLE_RADIUS..=NEUTRAL_SAMPLE_RADIUS {
+                let coord = Coord::new(point.x() + x, point.y() + y);
+                if coord.in_bounds(image.size()) {
+                    let pixel = image.get_lin_srgba_pixel(coord);
+                    sum[0] += pixel.red;
+                    sum[1] += pixel.green;
+                    sum[2] += pixel.blue;
+                }
+            }
+        }
+        Ok(sum)
+    }
+}
+
+impl CpuProcessor for WhiteBalanceProcessor {
+    fn name(&self) -> &'static str {
+        "white-balance"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        // only the ratios between channels matter, so sums are as good as averages
+        let neutral = match self.options.method {
+            WhiteBalanceMethod::GrayWorld => Self::gray_world(&image),
+            WhiteBalanceMethod::WhitePatch => Self::white_patch(&image),
+            WhiteBalanceMethod::Neutral => {
+                let point = self
+                    .options
+                    .point
+                    .ok_or(ProcessingPicturifyError::InvalidPoint)?;
+                Self::neutral(&image, point)?
+            }
+        };
+
+        // nothing to balance against in a black image
+        let Some(matrix) = adaptation_from_neutral(neutral) else {
+            return Ok(image);
+        };
+
+        image.par_apply_fn_to_lin_srgba(
+            |mut pixel, _coord| {
+                let [red, green, blue] = apply(&matrix, [pixel.red, pixel.green, pixel.blue]);
+                pixel.red = red.clamp(0.0, 1.0);
+                pixel.green = green.clamp(0.0, 1.0);
+                pixel.blue = blue.clamp(0.0, 1.0);
+                pixel
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
@@ -1,18 +1,18 @@
-use serde::{Deserialize, Serialize};
 use crate::common::kernels::prewitt::PrewittKernels;
+use crate::common::kernels::scharr::ScharrKernels;
 use crate::common::kernels::sobel::SobelKernels;
 use crate::common::processors::CpuProcessor;
+use crate::processors::internal::gradient_based::GradientField;
 use crate::processors::noise::gaussian_blur::{
     GaussianBlurProcessor, GaussianBlurProcessorOptions,
 };
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToPlanePixels;
 use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::plane_image::{PlaneImage, PlaneImageF32, PlaneImageU8};
 use picturify_core::error::processing::ProcessingPicturifyResult;
 use picturify_core::geometry::coord::Coord;
-use picturify_core::image::Rgba;
-use picturify_core::pixel::colors::Colors;
-use picturify_core::pixel::traits::RgbaF32Pixel;
-use picturify_core::rayon::prelude::*;
 use picturify_core::threading::progress::Progress;
+use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
 pub enum CannyEdgeDetectionType {
@@ -76,28 +76,24 @@ enum GradientDirection {
     NorthWest,
 }
 
-struct ImageGradientResult {
-    gradient_magnitude: Vec<f32>,
-    gradient_direction: Vec<GradientDirection>,
-    width: usize,
-    height: usize,
-}
-
-impl ImageGradientResult {
-    fn new(width: usize, height: usize) -> Self {
-        Self {
-            gradient_magnitude: vec![0.0; width * height],
-            gradient_direction: vec![GradientDirection::North; width * height],
-            width,
-            height,
+impl GradientDirection {
+    fn from_derivatives(magnitude_x: f32, magnitude_y: f32) -> Self {
+        match (magnitude_y / magnitude_x).atan() {
+            angle if angle < -3.0 * std::f32::consts::FRAC_PI_4 => GradientDirection::North,
+            angle if angle < -std::f32::consts::FRAC_PI_4 => GradientDirection::NorthEast,
+            angle if angle < std::f32::consts::FRAC_PI_4 => GradientDirection::East,
+            angle if angle < 3.0 * std::f32::consts::FRAC_PI_4 => GradientDirection::SouthEast,
+            angle if angle < 5.0 * std::f32::consts::FRAC_PI_4 => GradientDirection::South,
+            angle if angle < 7.0 * std::f32::consts::FRAC_PI_4 => GradientDirection::SouthWest,
+            angle if angle < 9.0 * std::f32::consts::FRAC_PI_4 => GradientDirection::West,
+            _ => GradientDirection::NorthWest,
         }
     }
 }
 
-struct IntensityResult {
-    intensity: Vec<f32>,
-    width: usize,
-    height: usize,
+struct ImageGradientResult {
+    gradient_magnitude: PlaneImageF32,
+    gradient_direction: PlaneImage<GradientDirection>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -107,12 +103,6 @@ enum PixelThresholdValue {
     High,
 }
 
-struct DoubleThresholdResult {
-    pixels: Vec<PixelThresholdValue>,
-    width: usize,
-    height: usize,
-}
-
 impl CannyProcessor {
     fn apply_gaussian_blur(&self, image: FastImage) -> ProcessingPicturifyResult<FastImage> {
         let sigma = self.options.sigma;
@@ -134,94 +124,49 @@ impl CannyProcessor {
         let kernels = match self.options.edge_detection_type {
             CannyEdgeDetectionType::Sobel => SobelKernels::create(),
             CannyEdgeDetectionType::Prewitt => PrewittKernels::create(),
-            CannyEdgeDetectionType::Scharr => {
-                unimplemented!("Scharr kernels are not implemented yet")
-            }
+            CannyEdgeDetectionType::Scharr => ScharrKernels::create(),
         }?;
 
-        let (width_usize, height_usize): (usize, usize) = image.size().into();
-        let (width_i32, height_i32) = (width_usize as i32, height_usize as i32);
-
         let kernel_radius = kernels.radius() as i32;
-        let mut result = ImageGradientResult::new(width_usize, height_usize);
-
-        result
-            .gradient_magnitude
-            .iter_mut()
-            .zip(result.gradient_direction.iter_mut())
-            .enumerate()
-            .par_bridge()
-            .for_each(|(index_1d, (gradient_magnitude, gradient_direction))| {
-                let pixel_coord = Coord::from_1d_index(index_1d, width_usize);
-
-                if pixel_coord.x() < kernel_radius
-                    || pixel_coord.x() >= width_i32 - kernel_radius
-                    || pixel_coord.y() < kernel_radius
-                    || pixel_coord.y() >= height_i32 - kernel_radius
-                {
-                    return;
-                }
-
-                let mut magnitude_x = 0.0;
-                let mut magnitude_y = 0.0;
-
-                kernels.iter().for_each(|(coord, x_value, y_value)| {
-                    let pixel = image.get_image_pixel((pixel_coord + coord) - kernel_radius);
-
-                    let colors = pixel.red_f32() + pixel.green_f32() + pixel.blue_f32();
-
-                    magnitude_x += x_value * colors;
-                    magnitude_y += y_value * colors;
-                });
-
-                magnitude_x /= 3.0;
-                magnitude_y /= 3.0;
-
-                let actual_magnitude = (magnitude_x.powi(2) + magnitude_y.powi(2)).sqrt();
-                *gradient_magnitude = actual_magnitude;
-
-                let direction = match (magnitude_y / magnitude_x).atan() {
-                    angle if angle < -3.0 * std::f32::consts::FRAC_PI_4 => GradientDirection::North,
-                    angle if angle < -std::f32::consts::FRAC_PI_4 => GradientDirection::NorthEast,
-                    angle if angle < std::f32::consts::FRAC_PI_4 => GradientDirection::East,
-                    angle if angle < 3.0 * std::f32::consts::FRAC_PI_4 => {
-                        GradientDirection::SouthEast
-                    }
-                    angle if angle < 5.0 * std::f32::consts::FRAC_PI_4 => GradientDirection::South,
-                    angle if angle < 7.0 * std::f32::consts::FRAC_PI_4 => {
-                        GradientDirection::SouthWest
-                    }
-                    angle if angle < 9.0 * std::f32::consts::FRAC_PI_4 => GradientDirection::West,
-                    _ => GradientDirection::NorthWest,
-                };
-
-                *gradient_direction = direction;
-            });
+        let field = GradientField::new(&image, &kernels, true)?;
+        let size = field.size();
+        let (width, height): (i32, i32) = size.into();
+        // pixels the kernels cannot fully cover have no gradient
+        let inside = |coord: Coord| {
+            coord.x() >= kernel_radius
+                && coord.x() < width - kernel_radius
+                && coord.y() >= kernel_radius
+                && coord.y() < height - kernel_radius
+        };
 
-        Ok(result)
+        Ok(ImageGradientResult {
+            gradient_magnitude: PlaneImage::from_fn(size, |coord| match inside(coord) {
+                true => *field.magnitude().get(coord),
+                false => 0.0,
+            }),
+            // the suppression directions follow the raw kernel responses, not dx and dy
+            gradient_direction: PlaneImage::from_fn(size, |coord| match inside(coord) {
+                true => {
+                    let (response_x, response_y) = field.kernel_responses(coord);
+                    GradientDirection::from_derivatives(response_x, response_y)
+                }
+                false => GradientDirection::North,
+            }),
+        })
     }
 
     fn apply_non_maximum_supression(
         &self,
         gradient: ImageGradientResult,
-    ) -> ProcessingPicturifyResult<IntensityResult> {
-        let width = gradient.width;
-        let height = gradient.height;
-
-        let mut result = IntensityResult {
-            intensity: gradient.gradient_magnitude.clone(),
-            width,
-            height,
-        };
+    ) -> ProcessingPicturifyResult<PlaneImageF32> {
+        let width = gradient.gradient_magnitude.width();
+        let height = gradient.gradient_magnitude.height();
+        let magnitudes = gradient.gradient_magnitude.values();
 
-        result
-            .intensity
-            .iter_mut()
-            .enumerate()
-            .par_bridge()
-            .for_each(|(index_1d, magnitude)| {
-                let pixel_coord = Coord::from_1d_index(index_1d, width);
+        let mut result = gradient.gradient_magnitude.clone();
 
+        result.par_apply_fn_to_plane_pixel(
+            |magnitude, pixel_coord| {
                 if pixel_coord.x() == 0
                     || pixel_coord.x() == width as i32 - 1
                     || pixel_coord.y() == 0
@@ -230,14 +175,15 @@ impl CannyProcessor {
                     return;
                 }
 
-                let direction = gradient.gradient_direction[index_1d];
+                let index_1d = pixel_coord.array_index(width);
+                let direction = *gradient.gradient_direction.get(pixel_coord);
 
-                let current_magnitude = gradient.gradient_magnitude[index_1d];
+                let current_magnitude = magnitudes[index_1d];
 
                 match direction {
                     GradientDirection::North | GradientDirection::South => {
-                        let north_magnitude = gradient.gradient_magnitude[index_1d - width];
-                        let south_magnitude = gradient.gradient_magnitude[index_1d + width];
+                        let north_magnitude = magnitudes[index_1d - width];
+                        let south_magnitude = magnitudes[index_1d + width];
 
                         if current_magnitude < north_magnitude
                             || current_magnitude < south_magnitude
@@ -246,10 +192,8 @@ impl CannyProcessor {
                         }
                     }
                     GradientDirection::NorthEast | GradientDirection::SouthWest => {
-                        let north_east_magnitude =
-                            gradient.gradient_magnitude[index_1d - width + 1];
-                        let south_west_magnitude =
-                            gradient.gradient_magnitude[index_1d + width - 1];
+                        let north_east_magnitude = magnitudes[index_1d - width + 1];
+                        let south_west_magnitude = magnitudes[index_1d + width - 1];
 
                         if current_magnitude < north_east_magnitude
                             || current_magnitude < south_west_magnitude
@@ -258,8 +202,8 @@ impl CannyProcessor {
                         }
                     }
                     GradientDirection::East | GradientDirection::West => {
-                        let east_magnitude = gradient.gradient_magnitude[index_1d + 1];
-                        let west_magnitude = gradient.gradient_magnitude[index_1d - 1];
+                        let east_magnitude = magnitudes[index_1d + 1];
+                        let west_magnitude = magnitudes[index_1d - 1];
 
                         if current_magnitude < east_magnitude || current_magnitude < west_magnitude
                         {
@@ -267,10 +211,8 @@ impl CannyProcessor {
                         }
                     }
                     GradientDirection::NorthWest | GradientDirection::SouthEast => {
-                        let north_west_magnitude =
-                            gradient.gradient_magnitude[index_1d - width - 1];
-                        let south_east_magnitude =
-                            gradient.gradient_magnitude[index_1d + width + 1];
+                        let north_west_magnitude = magnitudes[index_1d - width - 1];
+                        let south_east_magnitude = magnitudes[index_1d + width + 1];
 
                         if current_magnitude < north_west_magnitude
                             || current_magnitude < south_east_magnitude
@@ -279,53 +221,40 @@ impl CannyProcessor {
                         }
                     }
                 }
-            });
+            },
+            None,
+        );
 
         Ok(result)
     }
 
     fn apply_double_threshold(
         &self,
-        gradient: IntensityResult,
-    ) -> ProcessingPicturifyResult<DoubleThresholdResult> {
-        let width = gradient.width;
-        let height = gradient.height;
-
-        let mut result = DoubleThresholdResult {
-            pixels: vec![PixelThresholdValue::None; width * height],
-            width,
-            height,
-        };
-
-        result
-            .pixels
-            .iter_mut()
-            .enumerate()
-            .par_bridge()
-            .for_each(|(index_1d, pixel)| {
-                let magnitude = gradient.intensity[index_1d];
-
-                if magnitude < self.options.low_threshold {
-                    *pixel = PixelThresholdValue::Low;
-                } else if magnitude > self.options.high_threshold {
-                    *pixel = PixelThresholdValue::High;
-                }
-            });
-
-        Ok(result)
+        intensity: PlaneImageF32,
+    ) -> ProcessingPicturifyResult<PlaneImage<PixelThresholdValue>> {
+        Ok(PlaneImage::from_fn(intensity.size(), |coord| {
+            let magnitude = *intensity.get(coord);
+
+            if magnitude < self.options.low_threshold {
+                PixelThresholdValue::Low
+            } else if magnitude > self.options.high_threshold {
+                PixelThresholdValue::High
+            } else {
+                PixelThresholdValue::None
+            }
+        }))
     }
 
     fn apply_hysteresis(
         &self,
-        threshold: DoubleThresholdResult,
+        threshold: PlaneImage<PixelThresholdValue>,
     ) -> ProcessingPicturifyResult<FastImage> {
-        let width = threshold.width;
-        let height = threshold.height;
-        let mut output_image = FastImage::empty((width, height).into());
+        let width = threshold.width();
+        let height = threshold.height();
 
         // Create a copy of the threshold result to modify in-place
-        let input_pixels = threshold.pixels.clone();
-        let mut processed_pixels = threshold.pixels.clone();
+        let input_pixels = threshold.values().to_vec();
+        let mut processed_pixels = threshold.values().to_vec();
         let mut visited_pixels = vec![false; width * height];
 
         // Helper function to recursively follow edges
@@ -396,23 +325,13 @@ impl CannyProcessor {
         }
 
         // Set the pixel values in the output image based on the processed pixels
-        for y in 0..height {
-            for x in 0..width {
-                let idx = y * width + x;
-                match processed_pixels[idx] {
-                    PixelThresholdValue::None => {
-                        output_image.set_image_pixel((x, y).into(), Rgba::black())
-                    }
-                    PixelThresholdValue::Low => {
-                        output_image.set_image_pixel((x, y).into(), Rgba::black())
-                    }
-                    PixelThresholdValue::High => {
-                        output_image.set_image_pixel((x, y).into(), Rgba::white())
-                    }
-                }
+        let output_plane: PlaneImageU8 = PlaneImage::from_fn(threshold.size(), |coord| {
+            match processed_pixels[coord.array_index(width)] {
+                PixelThresholdValue::None | PixelThresholdValue::Low => 0,
+                PixelThresholdValue::High => u8::MAX,
             }
-        }
+        });
 
-        Ok(output_image)
+        Ok(output_plane.to_gray_image())
     }
 }
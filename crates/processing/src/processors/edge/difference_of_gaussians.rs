@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::plane_image::PlaneImageU8;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::difference_of_gaussians::DifferenceOfGaussians;
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DifferenceOfGaussiansProcessorOptions {
+    pub sigma: f32,
+    // ratio between the wide and the narrow gaussian
+    pub k: f32,
+    // multiplier of the response, which is small compared to the full 0-255 range
+    pub gain: f32,
+}
+
+// Band pass of the lightness, edges come out white on black like with the gradient filters
+pub struct DifferenceOfGaussiansProcessor {
+    options: DifferenceOfGaussiansProcessorOptions,
+}
+
+impl DifferenceOfGaussiansProcessor {
+    pub fn new(options: DifferenceOfGaussiansProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for DifferenceOfGaussiansProcessor {
+    fn name(&self) -> &'static str {
+        "difference-of-gaussians"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        mut progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        progress.setup(2);
+        let difference = DifferenceOfGaussians::new(&image, self.options.sigma, self.options.k);
+        progress.increment();
+
+        let edges: PlaneImageU8 = difference.difference().map(|difference| {
+            (self.options.gain * difference.abs())
+                .round()
+                .clamp(0.0, 255.0) as u8
+        });
+        edges
+            .write_gray_to(&mut image)
+            .map_err(ProcessingPicturifyError::from_plane_error)?;
+        progress.increment();
+
+        Ok(image)
+    }
+
+    fn kernel_radius(&self) -> usize {
+        DifferenceOfGaussians::kernel_radius(self.options.sigma, self.options.k)
+    }
+}
@@ -38,4 +38,8 @@ impl CpuProcessor for EmbossProcessor {
         });
         processor.process(image, progress)
     }
+
+    fn kernel_radius(&self) -> usize {
+        1
+    }
 }
@@ -0,0 +1,143 @@
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::palette::{FromColor, Hsv, Srgb};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::kernels::prewitt::PrewittKernels;
+use crate::common::kernels::sobel::SobelKernels;
+use crate::common::kernels::xy::XyKernels;
+use crate::common::processors::CpuProcessor;
+use crate::processors::internal::gradient_based::GradientField;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum GradientOperator {
+    Sobel,
+    Prewitt,
+}
+
+impl GradientOperator {
+    pub fn kernels(&self) -> ProcessingPicturifyResult<XyKernels> {
+        match self {
+            GradientOperator::Sobel => SobelKernels::create(),
+            GradientOperator::Prewitt => PrewittKernels::create(),
+        }
+    }
+}
+
+impl ValueEnum for GradientOperator {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[GradientOperator::Sobel, GradientOperator::Prewitt]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            GradientOperator::Sobel => Some(PossibleValue::new("sobel")),
+            GradientOperator::Prewitt => Some(PossibleValue::new("prewitt")),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum GradientMode {
+    Magnitude,
+    // direction as hue, magnitude as value
+    Orientation,
+    // signed derivatives around mid gray, brighter to the right or downwards
+    Dx,
+    Dy,
+}
+
+impl ValueEnum for GradientMode {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            GradientMode::Magnitude,
+            GradientMode::Orientation,
+            GradientMode::Dx,
+            GradientMode::Dy,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            GradientMode::Magnitude => Some(PossibleValue::new("magnitude")),
+            GradientMode::Orientation => Some(PossibleValue::new("orientation")),
+            GradientMode::Dx => Some(PossibleValue::new("dx")),
+            GradientMode::Dy => Some(PossibleValue::new("dy")),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GradientProcessorOptions {
+    pub operator: GradientOperator,
+    pub mode: GradientMode,
+    // multiplies the output after it is scaled by the strongest gradient of the image
+    pub gain: f32,
+    pub use_fast_approximation: bool,
+}
+
+// Renders one part of the gradient field, scaled by the strongest gradient so every image
+// uses the full range. Alpha is kept.
+pub struct GradientProcessor {
+    options: GradientProcessorOptions,
+}
+
+impl GradientProcessor {
+    pub fn new(options: GradientProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for GradientProcessor {
+    fn name(&self) -> &'static str {
+        "gradient"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let field = GradientField::new(
+            &image,
+            &self.options.operator.kernels()?,
+            self.options.use_fast_approximation,
+        )?;
+        let max_magnitude = field
+            .magnitude()
+            .values()
+            .iter()
+            .fold(f32::EPSILON, |max, magnitude| max.max(*magnitude));
+        let scale = self.options.gain / max_magnitude;
+
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let color = match self.options.mode {
+                    GradientMode::Magnitude => [field.magnitude().get(coord) * scale; 3],
+                    GradientMode::Orientation => {
+                        let hue = field.direction().get(coord).to_degrees();
+                        let value = (field.magnitude().get(coord) * scale).min(1.0);
+                        let srgb = Srgb::from_color(Hsv::new(hue, 1.0, value));
+                        [srgb.red, srgb.green, srgb.blue]
+                    }
+                    GradientMode::Dx => [0.5 + 0.5 * field.dx().get(coord) * scale; 3],
+                    GradientMode::Dy => [0.5 + 0.5 * field.dy().get(coord) * scale; 3],
+                };
+                for (channel, value) in pixel.0.iter_mut().zip(color) {
+                    *channel = (value * 255.0).round().clamp(0.0, 255.0) as u8;
+                }
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+
+    fn kernel_radius(&self) -> usize {
+        1
+    }
+}
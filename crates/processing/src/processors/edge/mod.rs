@@ -1,6 +1,9 @@
 pub mod canny;
+pub mod difference_of_gaussians;
 pub mod emboss;
+pub mod gradient;
 pub mod prewitt;
 pub mod prewitt_rgb;
 pub mod sobel;
 pub mod sobel_rgb;
+pub mod xdog;
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::threading::progress::Progress;
+
+use crate::common::difference_of_gaussians::DifferenceOfGaussians;
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct XDogProcessorOptions {
+    pub sigma: f32,
+    // ratio between the wide and the narrow gaussian
+    pub k: f32,
+    // weight of the difference added to the narrow blur, higher values give bolder lines
+    pub sharpness: f32,
+    // threshold from 0 to 1, darker areas get shaded
+    pub epsilon: f32,
+    // steepness of the soft threshold, large values give hard black and white
+    pub phi: f32,
+}
+
+// Extended difference of gaussians, a sharpened lightness passed through a soft threshold,
+// which gives dark lines and shading on white
+pub struct XDogProcessor {
+    options: XDogProcessorOptions,
+}
+
+impl XDogProcessor {
+    pub fn new(options: XDogProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for XDogProcessor {
+    fn name(&self) -> &'static str {
+        "xdog"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        mut progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        progress.setup(2);
+        let difference = DifferenceOfGaussians::new(&image, self.options.sigma, self.options.k);
+        progress.increment();
+
+        let tones = difference
+            .sharpened(self.options.sharpness)
+            .map(|sharpened| {
+                let value = sharpened / 255.0;
+                match value >= self.options.epsilon {
+                    true => 1.0,
+                    false => 1.0 + (self.options.phi * (value - self.options.epsilon)).tanh(),
+                }
+            });
+        tones
+            .write_gray_to(&mut image)
+            .map_err(ProcessingPicturifyError::from_plane_error)?;
+        progress.increment();
+
+        Ok(image)
+    }
+
+    fn kernel_radius(&self) -> usize {
+        DifferenceOfGaussians::kernel_radius(self.options.sigma, self.options.k)
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
 use picturify_core::core::fast_image::FastImage;
-use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
 use picturify_core::geometry::coord::Coord;
 use picturify_core::geometry::size::Size;
 use picturify_core::threading::progress::Progress;
@@ -61,7 +61,18 @@ impl CpuProcessor for CropProcessor {
         image: FastImage,
         progress: Progress,
     ) -> ProcessingPicturifyResult<FastImage> {
-        let mut new_image = FastImage::empty(self.options.crop_border.into());
+        let crop_border = self.options.crop_border;
+        let (width, height): (usize, usize) = image.size().into();
+        let outside = |offset: usize, length: usize, limit: usize| {
+            offset.checked_add(length).is_none_or(|end| end > limit)
+        };
+        if outside(crop_border.x_offset, crop_border.width, width)
+            || outside(crop_border.y_offset, crop_border.height, height)
+        {
+            return Err(ProcessingPicturifyError::InvalidRegion);
+        }
+
+        let mut new_image = FastImage::empty(crop_border.into());
 
         new_image.par_apply_fn_to_image_pixel(
             |pixel, coord| {
@@ -40,7 +40,12 @@ impl CpuProcessor for RotateFlexibleProcessor {
 
         let mut new_image = FastImage::empty(new_size);
         let rotation_origin = (width / 2, height / 2).into();
-        let mut shift: Coord = (new_width - width, new_height - height).into();
+        // rounding can make the rotated size smaller than the original, so the shift is signed
+        let mut shift: Coord = (
+            new_width as i32 - width as i32,
+            new_height as i32 - height as i32,
+        )
+            .into();
         shift /= 2;
 
         let angle = -self.options.angle;
@@ -59,6 +59,11 @@ impl CpuProcessor for ScaleProcessor {
         let strategy = self.options.strategy;
 
         let mut new_image = FastImage::empty(new_size);
+        // an empty image has nothing to sample, the result stays transparent
+        let (width, height): (usize, usize) = image.size().into();
+        if width == 0 || height == 0 {
+            return Ok(new_image);
+        }
 
         new_image.par_apply_fn_to_image_pixel(
             |pixel, coord| {
@@ -41,12 +41,12 @@ impl CpuProcessor for ConvolutionRgbProcessor {
         let half_kernel_width = kernel_width / 2;
         let half_kernel_height = kernel_height / 2;
 
-        progress.setup(height - 2 * half_kernel_height);
+        progress.setup(height.saturating_sub(2 * half_kernel_height));
         let offset = Offset {
             skip_rows: half_kernel_height,
-            take_rows: height - 2 * half_kernel_height,
+            take_rows: height.saturating_sub(2 * half_kernel_height),
             skip_columns: half_kernel_width,
-            take_columns: width - 2 * half_kernel_width,
+            take_columns: width.saturating_sub(2 * half_kernel_width),
         };
 
         new_image.par_apply_fn_to_image_pixel_with_offset(
@@ -1,17 +1,151 @@
 use crate::common::kernels::xy::XyKernels;
-use crate::common::processors::CpuProcessor;
+use crate::common::processors::{is_border_only, CpuProcessor};
 use picturify_core::core::apply_fn_to_pixels::{
     ApplyFnToImagePixels, ApplyFnToPalettePixels, Offset,
 };
 use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::plane_image::{PlaneImage, PlaneImageF32};
 use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
 use picturify_core::geometry::coord::Coord;
+use picturify_core::geometry::size::Size;
 use picturify_core::pixel::traits::RgbaF32Pixel;
 use picturify_core::rayon::prelude::*;
-use picturify_core::threading::progress::{Progress, ProgressIteratorExt};
-use std::sync::{Arc, Mutex};
+use picturify_core::threading::progress::Progress;
 use serde::{Deserialize, Serialize};
 
+// Derivatives of the mean sRGB intensity at every pixel, samples outside the image repeat the
+// nearest edge pixel. The kernels are calibrated against unit ramps, so dx always grows to the
+// right and dy downwards however the kernels are laid out, while keeping the kernel gain.
+pub struct GradientField {
+    dx: PlaneImageF32,
+    dy: PlaneImageF32,
+    magnitude: PlaneImageF32,
+    // radians from the x axis towards y, pointing to the brighter side
+    direction: PlaneImageF32,
+    orientation: [[f32; 2]; 2],
+}
+
+impl GradientField {
+    pub fn new(
+        image: &FastImage,
+        kernels: &XyKernels,
+        use_fast_approximation: bool,
+    ) -> ProcessingPicturifyResult<Self> {
+        let size = image.size();
+        let (width, height): (usize, usize) = size.into();
+        let orientation = Self::orientation(kernels)?;
+        let kernel_radius = kernels.radius() as i32;
+        let taps: Vec<(i32, i32, f32, f32)> = kernels
+            .iter()
+            .map(|(coord, x_value, y_value)| {
+                let (x, y): (i32, i32) = coord.into();
+                (x - kernel_radius, y - kernel_radius, x_value, y_value)
+            })
+            .collect();
+
+        let get_pixel_fn: fn(image: &FastImage, coord: Coord) -> Box<dyn RgbaF32Pixel> =
+            if use_fast_approximation {
+                |image, coord| Box::new(FastImage::get_image_pixel(image, coord))
+            } else {
+                |image, coord| Box::new(FastImage::get_lin_srgba_pixel(image, coord))
+            };
+        let intensity = PlaneImage::from_fn(size, |coord| {
+            let pixel = get_pixel_fn(image, coord);
+            (pixel.red_f32() + pixel.green_f32() + pixel.blue_f32()) / 3.0
+        });
+
+        let gradients = PlaneImage::from_fn(size, |coord| {
+            let (x, y): (i32, i32) = coord.into();
+            let mut response_x = 0.0;
+            let mut response_y = 0.0;
+            for (offset_x, offset_y, x_value, y_value) in &taps {
+                let value = *intensity.get(Coord::new(
+                    (x + offset_x).clamp(0, width as i32 - 1),
+                    (y + offset_y).clamp(0, height as i32 - 1),
+                ));
+                response_x += x_value * value;
+                response_y += y_value * value;
+            }
+            let [[xx, xy], [yx, yy]] = orientation;
+            (
+                xx * response_x + xy * response_y,
+                yx * response_x + yy * response_y,
+            )
+        });
+
+        Ok(Self {
+            dx: PlaneImage::from_fn(size, |coord| gradients.get(coord).0),
+            dy: PlaneImage::from_fn(size, |coord| gradients.get(coord).1),
+            magnitude: PlaneImage::from_fn(size, |coord| {
+                let (dx, dy) = gradients.get(coord);
+                (dx * dx + dy * dy).sqrt()
+            }),
+            direction: PlaneImage::from_fn(size, |coord| {
+                let (dx, dy) = gradients.get(coord);
+                dy.atan2(*dx)
+            }),
+            orientation,
+        })
+    }
+
+    pub fn size(&self) -> Size {
+        self.magnitude.size()
+    }
+
+    pub fn dx(&self) -> &PlaneImageF32 {
+        &self.dx
+    }
+
+    pub fn dy(&self) -> &PlaneImageF32 {
+        &self.dy
+    }
+
+    pub fn magnitude(&self) -> &PlaneImageF32 {
+        &self.magnitude
+    }
+
+    pub fn direction(&self) -> &PlaneImageF32 {
+        &self.direction
+    }
+
+    // responses of the x and y kernels as laid out, before they are mapped to dx and dy
+    pub fn kernel_responses(&self, coord: Coord) -> (f32, f32) {
+        let [[xx, xy], [yx, yy]] = self.orientation;
+        let determinant = xx * yy - xy * yx;
+        let (dx, dy) = (*self.dx.get(coord), *self.dy.get(coord));
+        (
+            (yy * dx - xy * dy) / determinant,
+            (xx * dy - yx * dx) / determinant,
+        )
+    }
+
+    // Maps the two kernel responses to dx and dy. Sobel and Prewitt kernels differ in sign and
+    // in which one measures which axis, their responses to ramps along x and y tell them apart.
+    fn orientation(kernels: &XyKernels) -> ProcessingPicturifyResult<[[f32; 2]; 2]> {
+        let radius = kernels.radius() as f32;
+        let mut ramp = [[0.0f32; 2]; 2];
+        for (coord, x_value, y_value) in kernels.iter() {
+            let (x, y): (i32, i32) = coord.into();
+            let (x, y) = (x as f32 - radius, y as f32 - radius);
+            ramp[0][0] += x_value * x;
+            ramp[0][1] += x_value * y;
+            ramp[1][0] += y_value * x;
+            ramp[1][1] += y_value * y;
+        }
+
+        let determinant = ramp[0][0] * ramp[1][1] - ramp[0][1] * ramp[1][0];
+        if determinant.abs() < f32::EPSILON {
+            return Err(ProcessingPicturifyError::InvalidKernel);
+        }
+        // the inverse scaled back up, so magnitudes keep the gain of the kernels
+        let scale = determinant.abs().sqrt() / determinant;
+        Ok([
+            [ramp[1][1] * scale, -ramp[0][1] * scale],
+            [-ramp[1][0] * scale, ramp[0][0] * scale],
+        ])
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct GradientBasedProcessorOptions {
     pub use_fast_approximation: bool,
@@ -35,78 +169,40 @@ impl CpuProcessor for GradientBasedProcessor {
     fn process(
         &self,
         mut image: FastImage,
-        mut progress: Progress,
+        progress: Progress,
     ) -> ProcessingPicturifyResult<FastImage> {
         let (width, height): (usize, usize) = image.size().into();
         let kernel_radius = self.options.xy_kernels.radius();
+        if is_border_only(image.size(), kernel_radius) {
+            return Ok(image);
+        }
 
-        let mut magnitude_vec =
-            vec![vec![0.0; width - 2 * kernel_radius]; height - 2 * kernel_radius];
-        let min_magnitude = Arc::new(Mutex::new(f32::MAX));
-        let max_magnitude = Arc::new(Mutex::new(f32::MIN));
-
-        let kernels = self.options.xy_kernels.clone();
-
-        let get_pixel_fn: fn(image: &FastImage, coord: Coord) -> Box<dyn RgbaF32Pixel> =
-            if self.options.use_fast_approximation {
-                |image, coord| Box::new(FastImage::get_image_pixel(image, coord))
-            } else {
-                |image, coord| Box::new(FastImage::get_lin_srgba_pixel(image, coord))
-            };
-
-        progress.setup((height - kernel_radius) * 2);
-        magnitude_vec
-            .iter_mut()
-            .enumerate()
-            .progress(progress.clone())
-            .par_bridge()
-            .for_each(|(y_mag, row)| {
-                let mut row_min_magnitude = f32::MAX;
-                let mut row_max_magnitude = f32::MIN;
-
-                row.iter_mut().enumerate().for_each(|(x_mag, magnitude)| {
-                    let pixel_coord: Coord = (x_mag + kernel_radius, y_mag + kernel_radius).into();
-
-                    let mut magnitude_x = 0.0;
-                    let mut magnitude_y = 0.0;
-
-                    kernels.iter().for_each(|(coord, x_value, y_value)| {
-                        let actual_coord = (pixel_coord + coord) - kernel_radius as i32;
-
-                        let pixel = get_pixel_fn(&image, actual_coord);
-
-                        let colors = pixel.red_f32() + pixel.green_f32() + pixel.blue_f32();
-
-                        magnitude_x += x_value * colors;
-                        magnitude_y += y_value * colors;
-                    });
-
-                    magnitude_x /= 3.0;
-                    magnitude_y /= 3.0;
-
-                    let actual_magnitude = (magnitude_x.powi(2) + magnitude_y.powi(2)).sqrt();
-                    *magnitude = actual_magnitude;
-
-                    row_min_magnitude = row_min_magnitude.min(actual_magnitude);
-                    row_max_magnitude = row_max_magnitude.max(actual_magnitude);
-                });
-
-                if row_min_magnitude < *min_magnitude.lock().unwrap() {
-                    *min_magnitude.lock().unwrap() = row_min_magnitude;
-                }
-
-                if row_max_magnitude > *max_magnitude.lock().unwrap() {
-                    *max_magnitude.lock().unwrap() = row_max_magnitude;
-                }
-            });
-
-        let min_magnitude = *min_magnitude.lock().unwrap();
-        let max_magnitude = *max_magnitude.lock().unwrap();
-
-        let mut inner_progress = Progress::new();
-        inner_progress.set_on_increment(move || {
-            progress.increment();
-        });
+        let field = GradientField::new(
+            &image,
+            &self.options.xy_kernels,
+            self.options.use_fast_approximation,
+        )?;
+
+        // the border is left to the enlargement, so it does not count towards the range
+        let (min_magnitude, max_magnitude) = field
+            .magnitude()
+            .values()
+            .par_chunks(width)
+            .skip(kernel_radius)
+            .take(height - 2 * kernel_radius)
+            .map(|row| {
+                row[kernel_radius..width - kernel_radius]
+                    .iter()
+                    .fold((f32::MAX, f32::MIN), |(min, max), magnitude| {
+                        (min.min(*magnitude), max.max(*magnitude))
+                    })
+            })
+            .reduce(
+                || (f32::MAX, f32::MIN),
+                |(first_min, first_max), (second_min, second_max)| {
+                    (first_min.min(second_min), first_max.max(second_max))
+                },
+            );
 
         let offset = Offset {
             skip_rows: kernel_radius,
@@ -115,14 +211,10 @@ impl CpuProcessor for GradientBasedProcessor {
             take_columns: width - 2 * kernel_radius,
         };
 
-        let coord_offset = (-(kernel_radius as i32), -(kernel_radius as i32)).into();
-
         if self.options.use_fast_approximation {
             image.par_apply_fn_to_image_pixel_with_offset(
                 |pixel, coord| {
-                    let (x, y): (usize, usize) = (coord + coord_offset).into();
-
-                    let magnitude = magnitude_vec[y][x];
+                    let magnitude = *field.magnitude().get(coord);
                     let magnitude =
                         ((magnitude - min_magnitude) / (max_magnitude - min_magnitude)) * 255.0;
                     let magnitude = magnitude as u8;
@@ -131,15 +223,13 @@ impl CpuProcessor for GradientBasedProcessor {
                     pixel[1] = magnitude;
                     pixel[2] = magnitude;
                 },
-                Some(inner_progress),
+                Some(progress),
                 offset,
             );
         } else {
             image.par_apply_fn_to_lin_srgba_with_offset(
                 |mut pixel, coord| {
-                    let (x, y): (usize, usize) = (coord + coord_offset).into();
-
-                    let magnitude = magnitude_vec[y][x];
+                    let magnitude = *field.magnitude().get(coord);
                     let magnitude = (magnitude - min_magnitude) / (max_magnitude - min_magnitude);
 
                     pixel.red = magnitude;
@@ -148,7 +238,7 @@ impl CpuProcessor for GradientBasedProcessor {
 
                     pixel
                 },
-                Some(inner_progress),
+                Some(progress),
                 offset,
             );
         }
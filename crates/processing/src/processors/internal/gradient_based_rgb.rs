@@ -1,5 +1,5 @@
 use crate::common::kernels::xy::XyKernels;
-use crate::common::processors::CpuProcessor;
+use crate::common::processors::{is_border_only, CpuProcessor};
 use picturify_core::core::apply_fn_to_pixels::{
     ApplyFnToImagePixels, ApplyFnToPalettePixels, Offset,
 };
@@ -41,6 +41,9 @@ impl CpuProcessor for GradientBasedRgbProcessor {
     ) -> ProcessingPicturifyResult<FastImage> {
         let (width, height): (usize, usize) = image.size().into();
         let kernel_radius = self.options.xy_kernels.radius();
+        if is_border_only(image.size(), kernel_radius) {
+            return Ok(image);
+        }
 
         let mut red_magnitude_vec =
             vec![vec![0.0; width - 2 * kernel_radius]; height - 2 * kernel_radius];
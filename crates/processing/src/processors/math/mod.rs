@@ -1,2 +1,3 @@
 pub mod add;
-mod subtract;
+pub mod multiply;
+pub mod subtract;
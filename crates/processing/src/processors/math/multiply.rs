@@ -0,0 +1,44 @@
+use crate::common::processors::CpuProcessor;
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::threading::progress::Progress;
+
+pub struct MultiplyProcessorOptions {
+    pub second_image: FastImage,
+}
+
+// Multiply blend of the color channels, alpha of the first image is kept
+pub struct MultiplyProcessor {
+    options: MultiplyProcessorOptions,
+}
+
+impl MultiplyProcessor {
+    pub fn new(options: MultiplyProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for MultiplyProcessor {
+    fn name(&self) -> &'static str {
+        "multiply"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let second_image = &self.options.second_image;
+
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let second_pixel = second_image.get_image_pixel(coord);
+                for (channel, second) in pixel.0.iter_mut().zip(second_pixel.0).take(3) {
+                    *channel = ((*channel as u16 * second as u16 + 127) / 255) as u8;
+                }
+            },
+            Some(progress),
+        );
+        Ok(image)
+    }
+}
@@ -0,0 +1,133 @@
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::rayon::prelude::*;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+
+// largest stable time step for the four neighbor scheme
+const MAX_LAMBDA: f32 = 0.25;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ConductionFunction {
+    // favors high contrast edges over low contrast ones
+    Exponential,
+    // favors wide regions over smaller ones
+    Quadratic,
+}
+
+impl ValueEnum for ConductionFunction {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            ConductionFunction::Exponential,
+            ConductionFunction::Quadratic,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            ConductionFunction::Exponential => Some(PossibleValue::new("exponential")),
+            ConductionFunction::Quadratic => Some(PossibleValue::new("quadratic")),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AnisotropicDiffusionProcessorOptions {
+    pub iterations: usize,
+    // gradient magnitude in 0-255 channel units above which diffusion mostly stops
+    pub kappa: f32,
+    pub lambda: f32,
+    pub function: ConductionFunction,
+}
+
+// Perona-Malik diffusion, smooths flat areas while edges stop the flow. The conduction is shared
+// by all channels, so edges stay aligned and do not get colored fringes.
+pub struct AnisotropicDiffusionProcessor {
+    options: AnisotropicDiffusionProcessorOptions,
+}
+
+impl AnisotropicDiffusionProcessor {
+    pub fn new(options: AnisotropicDiffusionProcessorOptions) -> Self {
+        Self { options }
+    }
+
+    fn conduction(&self, difference: [f32; 3]) -> f32 {
+        let kappa = self.options.kappa.max(f32::EPSILON);
+        let gradient_squared: f32 = difference.iter().map(|value| value * value).sum();
+        let ratio = gradient_squared / (kappa * kappa);
+        match self.options.function {
+            ConductionFunction::Exponential => (-ratio).exp(),
+            ConductionFunction::Quadratic => 1.0 / (1.0 + ratio),
+        }
+    }
+}
+
+impl CpuProcessor for AnisotropicDiffusionProcessor {
+    fn name(&self) -> &'static str {
+        "anisotropic-diffusion"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        mut progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let (width, height): (usize, usize) = image.size().into();
+        let lambda = self.options.lambda.clamp(0.0, MAX_LAMBDA);
+        let mut current: Vec<[f32; 3]> = image
+            .pixels()
+            .map(|pixel| [0, 1, 2].map(|channel| pixel.0[channel] as f32))
+            .collect();
+        let mut next = current.clone();
+
+        progress.setup(self.options.iterations);
+        for _ in 0..self.options.iterations {
+            next.par_chunks_mut(width.max(1))
+                .enumerate()
+                .for_each(|(y, row)| {
+                    for (x, value) in row.iter_mut().enumerate() {
+                        let center = current[y * width + x];
+                        // image borders act as insulators, nothing flows across them
+                        let neighbors = [
+                            (x > 0).then(|| current[y * width + x - 1]),
+                            (x + 1 < width).then(|| current[y * width + x + 1]),
+                            (y > 0).then(|| current[(y - 1) * width + x]),
+                            (y + 1 < height).then(|| current[(y + 1) * width + x]),
+                        ];
+
+                        let mut flow = [0.0; 3];
+                        for neighbor in neighbors.into_iter().flatten() {
+                            let difference =
+                                [0, 1, 2].map(|channel| neighbor[channel] - center[channel]);
+                            let conduction = self.conduction(difference);
+                            for channel in 0..3 {
+                                flow[channel] += conduction * difference[channel];
+                            }
+                        }
+                        *value = [0, 1, 2].map(|channel| center[channel] + lambda * flow[channel]);
+                    }
+                });
+            std::mem::swap(&mut current, &mut next);
+            progress.increment();
+        }
+
+        image
+            .pixels_mut()
+            .zip(current.iter())
+            .for_each(|(pixel, value)| {
+                for (channel, value) in pixel.0.iter_mut().zip(value) {
+                    *channel = value.round().clamp(0.0, 255.0) as u8;
+                }
+            });
+
+        Ok(image)
+    }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.iterations
+    }
+}
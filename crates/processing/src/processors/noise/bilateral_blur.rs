@@ -45,9 +45,9 @@ impl CpuProcessor for BilateralBlurProcessor {
 
         let offset = Offset {
             skip_rows: self.options.radius,
-            take_rows: height - 2 * self.options.radius,
+            take_rows: height.saturating_sub(2 * self.options.radius),
             skip_columns: self.options.radius,
-            take_columns: width - 2 * self.options.radius,
+            take_columns: width.saturating_sub(2 * self.options.radius),
         };
 
         let half_kernel_size = self.options.radius as i32;
@@ -107,4 +107,8 @@ impl CpuProcessor for BilateralBlurProcessor {
 
         Ok(new_image)
     }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.radius
+    }
 }
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::{ProcessingPicturifyError, ProcessingPicturifyResult};
+use picturify_core::geometry::coord::Coord;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::kernels::convolution::{ConvolutionKernel, KERNEL_SUM_EPSILON};
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct ConvolveChannels {
+    pub red: bool,
+    pub green: bool,
+    pub blue: bool,
+    pub alpha: bool,
+}
+
+impl ConvolveChannels {
+    fn as_array(&self) -> [bool; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+    }
+}
+
+pub struct ConvolveProcessorOptions {
+    pub kernel: ConvolutionKernel,
+    // divides by the kernel sum instead of the divisor, zero sum kernels keep the divisor
+    pub normalize: bool,
+    pub divisor: f32,
+    // added after the division, in 0-255 channel units
+    pub bias: f32,
+    // kernel cell placed over the output pixel, the kernel center when not set
+    pub anchor: Option<Coord>,
+    pub channels: ConvolveChannels,
+}
+
+// Runs an arbitrary kernel over the selected sRGB channels, the other channels are kept.
+// Samples outside the image repeat the nearest edge pixel. Colors are filtered premultiplied
+// by alpha so transparent neighbours don't bleed into the result.
+pub struct ConvolveProcessor {
+    options: ConvolveProcessorOptions,
+}
+
+impl ConvolveProcessor {
+    pub fn new(options: ConvolveProcessorOptions) -> Self {
+        Self { options }
+    }
+
+    fn anchor(&self) -> (usize, usize) {
+        let (width, height): (usize, usize) = self.options.kernel.size().into();
+        match self.options.anchor {
+            Some(anchor) => {
+                let (x, y): (i32, i32) = anchor.into();
+                (x.max(0) as usize, y.max(0) as usize)
+            }
+            None => (width / 2, height / 2),
+        }
+    }
+
+    fn divisor(&self) -> f32 {
+        let sum = self.options.kernel.sum();
+        match self.options.normalize && sum.abs() > KERNEL_SUM_EPSILON {
+            true => sum,
+            false => self.options.divisor,
+        }
+    }
+}
+
+impl CpuProcessor for ConvolveProcessor {
+    fn name(&self) -> &'static str {
+        "convolve"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let (kernel_width, kernel_height): (usize, usize) = self.options.kernel.size().into();
+        if let Some(anchor) = self.options.anchor {
+            let (x, y): (i32, i32) = anchor.into();
+            if x < 0 || y < 0 || x as usize >= kernel_width || y as usize >= kernel_height {
+                return Err(ProcessingPicturifyError::InvalidPoint);
+            }
+        }
+        let divisor = self.divisor();
+        if divisor == 0.0 {
+            return Err(ProcessingPicturifyError::InvalidDivisor);
+        }
+
+        let anchor = self.anchor();
+        let kernel_sum = self.options.kernel.sum();
+        let channels = self.options.channels.as_array();
+        let source = image.clone();
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let sums = self
+                    .options
+                    .kernel
+                    .convolve_premultiplied(&source, coord, anchor);
+                // colors are unpremultiplied by the kernel weighted alpha, derivative kernels
+                // use the alpha of the pixel itself
+                let alpha = match kernel_sum.abs() > KERNEL_SUM_EPSILON {
+                    true => sums[3] / kernel_sum,
+                    false => pixel.0[3] as f32,
+                };
+                let unpremultiply = match alpha > 0.0 {
+                    true => 255.0 / alpha,
+                    false => 0.0,
+                };
+
+                for (index, (channel, selected)) in pixel.0.iter_mut().zip(channels).enumerate() {
+                    if selected {
+                        let value = match index {
+                            3 => sums[3] / divisor,
+                            _ => sums[index] * unpremultiply / divisor,
+                        };
+                        *channel = (value + self.options.bias).round().clamp(0.0, 255.0) as u8;
+                    }
+                }
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+
+    fn kernel_radius(&self) -> usize {
+        let (width, height): (usize, usize) = self.options.kernel.size().into();
+        let (anchor_x, anchor_y) = self.anchor();
+        [
+            anchor_x,
+            width.saturating_sub(anchor_x + 1),
+            anchor_y,
+            height.saturating_sub(anchor_y + 1),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+    }
+}
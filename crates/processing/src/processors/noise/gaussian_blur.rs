@@ -45,4 +45,8 @@ impl CpuProcessor for GaussianBlurProcessor {
 
         processor.process(image, progress)
     }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.radius
+    }
 }
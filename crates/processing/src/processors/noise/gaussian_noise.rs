@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::noise::{apply_noise_fn, standard_normal, to_channel};
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GaussianNoiseProcessorOptions {
+    // standard deviation in 0-255 channel units
+    pub sigma: f32,
+    pub seed: Option<u64>,
+}
+
+// Adds independent normally distributed noise to every color channel, alpha is left untouched
+pub struct GaussianNoiseProcessor {
+    options: GaussianNoiseProcessorOptions,
+}
+
+impl GaussianNoiseProcessor {
+    pub fn new(options: GaussianNoiseProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for GaussianNoiseProcessor {
+    fn name(&self) -> &'static str {
+        "gaussian-noise"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let sigma = self.options.sigma;
+        apply_noise_fn(&mut image, self.options.seed, progress, |pixel, rng| {
+            for channel in &mut pixel.0[..3] {
+                *channel = to_channel(*channel as f32 + sigma * standard_normal(rng));
+            }
+        });
+
+        Ok(image)
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+use crate::common::sector_kuwahara::SectorKuwahara;
+use crate::common::structure_tensor::LocalOrientation;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GeneralizedKuwaharaProcessorOptions {
+    pub radius: usize,
+    pub sectors: usize,
+    // how strongly flat sectors win over the others, high values approach the classic filter
+    pub sharpness: f32,
+}
+
+// Kuwahara filter over a circular window split into gaussian weighted sectors, which avoids the
+// blocky artifacts of the four square quadrants. Works on all sRGB channels, alpha is kept.
+pub struct GeneralizedKuwaharaProcessor {
+    options: GeneralizedKuwaharaProcessorOptions,
+}
+
+impl GeneralizedKuwaharaProcessor {
+    pub fn new(options: GeneralizedKuwaharaProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for GeneralizedKuwaharaProcessor {
+    fn name(&self) -> &'static str {
+        "generalized-kuwahara"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let (width, height): (usize, usize) = image.size().into();
+        let filter = SectorKuwahara::new(
+            self.options.radius,
+            self.options.sectors,
+            self.options.sharpness,
+        );
+        let colors: Vec<[f32; 3]> = image
+            .pixels()
+            .map(|pixel| [0, 1, 2].map(|channel| pixel.0[channel] as f32 / 255.0))
+            .collect();
+
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let (x, y): (usize, usize) = coord.into();
+                let color = filter.filter(
+                    &colors,
+                    width,
+                    height,
+                    (x, y),
+                    LocalOrientation::default(),
+                    1.0,
+                );
+                for (channel, value) in pixel.0.iter_mut().zip(color) {
+                    *channel = (value * 255.0).round().clamp(0.0, 255.0) as u8;
+                }
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.radius.max(1)
+    }
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+use crate::common::sharpening::{gaussian_blur_plane, SharpeningPlanes};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HighPassSharpenProcessorOptions {
+    pub radius: usize,
+    pub sigma: f32,
+    pub amount: f32,
+    pub luminance_only: bool,
+}
+
+// Overlays the high pass of the image on itself, midtones get the strongest boost while
+// highlights and shadows are protected from clipping
+pub struct HighPassSharpenProcessor {
+    options: HighPassSharpenProcessorOptions,
+}
+
+impl HighPassSharpenProcessor {
+    pub fn new(options: HighPassSharpenProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for HighPassSharpenProcessor {
+    fn name(&self) -> &'static str {
+        "high-pass-sharpen"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        mut progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let mut planes = SharpeningPlanes::from_image(&image, self.options.luminance_only);
+
+        progress.setup(planes.planes_mut().len());
+        for plane in planes.planes_mut() {
+            let blurred = gaussian_blur_plane(plane, self.options.radius, self.options.sigma);
+            for (value, blurred) in plane.values_mut().iter_mut().zip(blurred.values()) {
+                let base = (*value / 255.0).clamp(0.0, 1.0);
+                let high_pass =
+                    (0.5 + self.options.amount * (*value - blurred) / 255.0).clamp(0.0, 1.0);
+                let overlay = if base < 0.5 {
+                    2.0 * base * high_pass
+                } else {
+                    1.0 - 2.0 * (1.0 - base) * (1.0 - high_pass)
+                };
+                *value = overlay * 255.0;
+            }
+            progress.increment();
+        }
+        planes.write_to_image(&mut image);
+
+        Ok(image)
+    }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.radius
+    }
+}
@@ -1,4 +1,6 @@
 use std::ops::Range;
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 
 use picturify_core::core::apply_fn_to_pixels::{ApplyFnToPalettePixels, Offset};
@@ -11,6 +13,32 @@ use picturify_core::threading::progress::Progress;
 
 use crate::common::processors::CpuProcessor;
 
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum KuwaharaMethod {
+    // four square quadrants on the HSV value, sectors and sharpness are ignored
+    Classic,
+    Generalized,
+    Anisotropic,
+}
+
+impl ValueEnum for KuwaharaMethod {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            KuwaharaMethod::Classic,
+            KuwaharaMethod::Generalized,
+            KuwaharaMethod::Anisotropic,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            KuwaharaMethod::Classic => Some(PossibleValue::new("classic")),
+            KuwaharaMethod::Generalized => Some(PossibleValue::new("generalized")),
+            KuwaharaMethod::Anisotropic => Some(PossibleValue::new("anisotropic")),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct KuwaharaProcessorOptions {
     pub radius: usize,
@@ -35,7 +63,7 @@ impl CpuProcessor for KuwaharaProcessor {
         mut image: FastImage,
         progress: Progress,
     ) -> ProcessingPicturifyResult<FastImage> {
-        let (width, height) = image.size().into();
+        let (width, height): (usize, usize) = image.size().into();
 
         let radius = self.options.radius;
 
@@ -52,9 +80,9 @@ impl CpuProcessor for KuwaharaProcessor {
 
         let offset = Offset {
             skip_rows: radius,
-            take_rows: height - radius * 2,
+            take_rows: height.saturating_sub(radius * 2),
             skip_columns: radius,
-            take_columns: width - radius * 2,
+            take_columns: width.saturating_sub(radius * 2),
         };
 
         image.par_apply_fn_to_pixel_with_offset(
@@ -120,6 +148,10 @@ impl CpuProcessor for KuwaharaProcessor {
 
         Ok(image)
     }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.radius
+    }
 }
 
 fn calculate_variance(
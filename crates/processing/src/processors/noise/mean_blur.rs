@@ -42,4 +42,8 @@ impl CpuProcessor for MeanBlurProcessor {
         });
         processor.process(image, progress)
     }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.radius
+    }
 }
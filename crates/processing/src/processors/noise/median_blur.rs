@@ -6,7 +6,7 @@ use picturify_core::error::processing::ProcessingPicturifyResult;
 use picturify_core::rayon::prelude::*;
 use picturify_core::threading::progress::{Progress, ProgressIteratorExt};
 
-use crate::common::processors::CpuProcessor;
+use crate::common::processors::{is_border_only, CpuProcessor};
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MedianBlurProcessorOptions {
@@ -34,6 +34,9 @@ impl CpuProcessor for MedianBlurProcessor {
     ) -> ProcessingPicturifyResult<FastImage> {
         let (width, height): (usize, usize) = image.size().into();
         let radius = self.options.radius;
+        if is_border_only(image.size(), radius) {
+            return Ok(image);
+        }
 
         let mut new_fast_image = image.clone();
 
@@ -88,6 +91,10 @@ impl CpuProcessor for MedianBlurProcessor {
 
         Ok(new_fast_image)
     }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.radius
+    }
 }
 
 // TODO: Optimize this function
@@ -1,6 +1,20 @@
+pub mod anisotropic_diffusion;
+pub mod anisotropic_kuwahara;
 pub mod bilateral_blur;
+pub mod convolve;
 pub mod gaussian_blur;
+pub mod gaussian_noise;
+pub mod generalized_kuwahara;
+pub mod high_pass_sharpen;
 pub mod kuwahara;
 pub mod mean_blur;
 pub mod median_blur;
+pub mod motion_blur;
+pub mod non_local_means;
+pub mod poisson_noise;
+pub mod radial_blur;
+pub mod salt_and_pepper_noise;
 pub mod sharpen;
+pub mod speckle_noise;
+pub mod unsharp_mask;
+pub mod zoom_blur;
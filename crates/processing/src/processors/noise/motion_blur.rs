@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::angle::Angle;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::line_integral::integrate_path;
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Copy, Clone)]
+pub struct MotionBlurProcessorOptions {
+    // direction of the motion, 0 is horizontal and angles turn clockwise
+    pub angle: Angle,
+    // length of the streak in pixels, centered on the pixel
+    pub length: f32,
+}
+
+pub struct MotionBlurProcessor {
+    options: MotionBlurProcessorOptions,
+}
+
+impl MotionBlurProcessor {
+    pub fn new(options: MotionBlurProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for MotionBlurProcessor {
+    fn name(&self) -> &'static str {
+        "motion-blur"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let length = self.options.length.max(0.0);
+        let (sin, cos) = self.options.angle.to_sin_cos();
+        let source = image.clone();
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let (x, y): (usize, usize) = coord.into();
+                *pixel = integrate_path(&source, length, |t| {
+                    let offset = (t - 0.5) * length;
+                    (x as f32 + offset * cos, y as f32 + offset * sin)
+                });
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+
+    fn kernel_radius(&self) -> usize {
+        (self.options.length.max(0.0) / 2.0).ceil() as usize
+    }
+}
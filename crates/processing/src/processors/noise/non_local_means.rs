@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::rayon::prelude::*;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NonLocalMeansProcessorOptions {
+    pub search_radius: usize,
+    pub patch_radius: usize,
+    // filter strength in 0-255 channel units, patches closer than this are averaged strongly
+    pub strength: f32,
+}
+
+// Averages every pixel with the pixels of its search window whose surrounding patches look alike.
+// Patch distances are taken from an integral image per window offset, so the cost does not
+// depend on the patch size.
+pub struct NonLocalMeansProcessor {
+    options: NonLocalMeansProcessorOptions,
+}
+
+impl NonLocalMeansProcessor {
+    pub fn new(options: NonLocalMeansProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for NonLocalMeansProcessor {
+    fn name(&self) -> &'static str {
+        "non-local-means"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        mut progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let (width, height): (usize, usize) = image.size().into();
+        if width == 0 || height == 0 {
+            return Ok(image);
+        }
+        let search = self.options.search_radius as i64;
+        let patch = self.options.patch_radius;
+        let strength = self.options.strength.max(f32::EPSILON);
+
+        // edges are replicated, so border patches are not compared against a constant color
+        let pad = self.options.search_radius + patch;
+        let padded_width = width + 2 * pad;
+        let padded_height = height + 2 * pad;
+        let padded: Vec<[f32; 3]> = (0..padded_width * padded_height)
+            .map(|index| {
+                let x = (index % padded_width).saturating_sub(pad).min(width - 1);
+                let y = (index / padded_width).saturating_sub(pad).min(height - 1);
+                let pixel = image.get_image_pixel((x, y).into());
+                [0, 1, 2].map(|channel| pixel.0[channel] as f32)
+            })
+            .collect();
+
+        // squared differences are needed for every pixel a patch of the image can cover
+        let area_width = width + 2 * patch;
+        let area_height = height + 2 * patch;
+        let integral_width = area_width + 1;
+        let mut integral = vec![0.0f64; integral_width * (area_height + 1)];
+        let mut weight_sum = vec![0.0f32; width * height];
+        let mut color_sum = vec![[0.0f32; 3]; width * height];
+        let patch_size = 2 * patch + 1;
+        let normalization = (patch_size * patch_size * 3) as f64;
+
+        progress.setup(((2 * search + 1) * (2 * search + 1)) as usize);
+        for dy in -search..=search {
+            for dx in -search..=search {
+                let shift = dy * padded_width as i64 + dx;
+                integral[integral_width..]
+                    .par_chunks_mut(integral_width)
+                    .enumerate()
+                    .for_each(|(y, row)| {
+                        let mut running = 0.0f64;
+                        let start = (y + self.options.search_radius) * padded_width
+                            + self.options.search_radius;
+                        for x in 0..area_width {
+                            let index = start + x;
+                            let first = padded[index];
+                            let second = padded[(index as i64 + shift) as usize];
+                            let distance: f32 = (0..3)
+                                .map(|channel| (first[channel] - second[channel]).powi(2))
+                                .sum();
+                            running += distance as f64;
+                            row[x + 1] = running;
+                        }
+                    });
+                for y in 1..=area_height {
+                    for x in 1..integral_width {
+                        integral[y * integral_width + x] += integral[(y - 1) * integral_width + x];
+                    }
+                }
+
+                weight_sum
+                    .par_chunks_mut(width)
+                    .zip(color_sum.par_chunks_mut(width))
+                    .enumerate()
+                    .for_each(|(y, (weights, colors))| {
+                        let top = y * integral_width;
+                        let bottom = (y + patch_size) * integral_width;
+                        for x in 0..width {
+                            let sum = integral[bottom + x + patch_size]
+                                - integral[top + x + patch_size]
+                                - integral[bottom + x]
+                                + integral[top + x];
+                            let distance = (sum / normalization) as f32;
+                            let weight = (-distance / (strength * strength)).exp();
+
+                            let neighbor = padded[((y + pad) as i64 + dy) as usize * padded_width
+                                + ((x + pad) as i64 + dx) as usize];
+                            weights[x] += weight;
+                            for channel in 0..3 {
+                                colors[x][channel] += weight * neighbor[channel];
+                            }
+                        }
+                    });
+                progress.increment();
+            }
+        }
+
+        image
+            .pixels_mut()
+            .zip(weight_sum.iter().zip(color_sum.iter()))
+            .for_each(|(pixel, (weight, color))| {
+                for (channel, color) in pixel.0.iter_mut().zip(color) {
+                    *channel = (color / weight).round().clamp(0.0, 255.0) as u8;
+                }
+            });
+
+        Ok(image)
+    }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.search_radius + self.options.patch_radius
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::noise::{apply_noise_fn, poisson, to_channel};
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PoissonNoiseProcessorOptions {
+    // photons counted per channel unit, higher values give less noise
+    pub scale: f32,
+    pub seed: Option<u64>,
+}
+
+// Shot noise, every channel value is replaced by a Poisson sample with that value as its mean
+pub struct PoissonNoiseProcessor {
+    options: PoissonNoiseProcessorOptions,
+}
+
+impl PoissonNoiseProcessor {
+    pub fn new(options: PoissonNoiseProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for PoissonNoiseProcessor {
+    fn name(&self) -> &'static str {
+        "poisson-noise"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let scale = self.options.scale.max(f32::EPSILON);
+        apply_noise_fn(&mut image, self.options.seed, progress, |pixel, rng| {
+            for channel in &mut pixel.0[..3] {
+                *channel = to_channel(poisson(rng, *channel as f32 * scale) / scale);
+            }
+        });
+
+        Ok(image)
+    }
+}
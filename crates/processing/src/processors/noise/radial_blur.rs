@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::angle::Angle;
+use picturify_core::geometry::coord::Coord;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::line_integral::integrate_path;
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RadialBlurProcessorOptions {
+    // center of rotation, the image center when not set
+    pub center: Option<Coord>,
+    // total arc every pixel is smeared over, half on each side
+    pub angle: Angle,
+}
+
+// Spin blur, every pixel is averaged along the arc of a circle around the center, so the
+// blur grows with the distance from it
+pub struct RadialBlurProcessor {
+    options: RadialBlurProcessorOptions,
+}
+
+impl RadialBlurProcessor {
+    pub fn new(options: RadialBlurProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for RadialBlurProcessor {
+    fn name(&self) -> &'static str {
+        "radial-blur"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let (width, height): (usize, usize) = image.size().into();
+        let (center_x, center_y) = match self.options.center {
+            Some(center) => (center.x() as f32, center.y() as f32),
+            None => (width as f32 / 2.0, height as f32 / 2.0),
+        };
+        let span = self.options.angle.to_radians();
+        let source = image.clone();
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let (x, y): (usize, usize) = coord.into();
+                let (dx, dy) = (x as f32 - center_x, y as f32 - center_y);
+                let radius = (dx * dx + dy * dy).sqrt();
+                *pixel = integrate_path(&source, radius * span, |t| {
+                    let (sin, cos) = ((t - 0.5) * span).sin_cos();
+                    (
+                        center_x + dx * cos - dy * sin,
+                        center_y + dx * sin + dy * cos,
+                    )
+                });
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
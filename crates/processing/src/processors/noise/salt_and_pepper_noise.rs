@@ -0,0 +1,54 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::noise::apply_noise_fn;
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SaltAndPepperNoiseProcessorOptions {
+    // fraction of pixels replaced by white or black
+    pub amount: f32,
+    // fraction of the replaced pixels that become white
+    pub salt_ratio: f32,
+    pub seed: Option<u64>,
+}
+
+pub struct SaltAndPepperNoiseProcessor {
+    options: SaltAndPepperNoiseProcessorOptions,
+}
+
+impl SaltAndPepperNoiseProcessor {
+    pub fn new(options: SaltAndPepperNoiseProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for SaltAndPepperNoiseProcessor {
+    fn name(&self) -> &'static str {
+        "salt-and-pepper-noise"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let amount = self.options.amount;
+        let salt_ratio = self.options.salt_ratio;
+        apply_noise_fn(&mut image, self.options.seed, progress, |pixel, rng| {
+            if rng.gen::<f32>() < amount {
+                let value = if rng.gen::<f32>() < salt_ratio {
+                    u8::MAX
+                } else {
+                    0
+                };
+                pixel.0[..3].fill(value);
+            }
+        });
+
+        Ok(image)
+    }
+}
@@ -1,4 +1,7 @@
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
+
 use picturify_core::core::fast_image::FastImage;
 use picturify_core::error::processing::ProcessingPicturifyResult;
 use picturify_core::threading::progress::Progress;
@@ -8,9 +11,45 @@ use crate::common::processors::CpuProcessor;
 use crate::processors::internal::convolution_rgb::{
     ConvolutionRgbProcessor, ConvolutionRgbProcessorOptions,
 };
+use crate::processors::noise::high_pass_sharpen::{
+    HighPassSharpenProcessor, HighPassSharpenProcessorOptions,
+};
+use crate::processors::noise::unsharp_mask::{UnsharpMaskProcessor, UnsharpMaskProcessorOptions};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum SharpenMethod {
+    // fixed 3x3 kernel, radius, sigma, threshold and luminance only are ignored
+    Kernel,
+    UnsharpMask,
+    HighPass,
+}
+
+impl ValueEnum for SharpenMethod {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            SharpenMethod::Kernel,
+            SharpenMethod::UnsharpMask,
+            SharpenMethod::HighPass,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            SharpenMethod::Kernel => Some(PossibleValue::new("kernel")),
+            SharpenMethod::UnsharpMask => Some(PossibleValue::new("unsharp-mask")),
+            SharpenMethod::HighPass => Some(PossibleValue::new("high-pass")),
+        }
+    }
+}
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SharpenProcessorOptions {
+    pub method: SharpenMethod,
+    pub radius: usize,
+    pub sigma: f32,
+    pub amount: f32,
+    pub threshold: f32,
+    pub luminance_only: bool,
     pub use_fast_approximation: bool,
 }
 
@@ -22,6 +61,37 @@ impl SharpenProcessor {
     pub fn new(options: SharpenProcessorOptions) -> Self {
         Self { options }
     }
+
+    fn process_kernel(
+        &self,
+        image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let processor = ConvolutionRgbProcessor::new(ConvolutionRgbProcessorOptions {
+            kernel: ConvolutionKernel::new_sharpen(),
+            use_fast_approximation: self.options.use_fast_approximation,
+        });
+        if self.options.amount == 1.0 {
+            return processor.process(image, progress);
+        }
+
+        // blends between the original and the fully sharpened image
+        let mut sharpened = processor.process(image.clone(), progress)?;
+        sharpened
+            .pixels_mut()
+            .zip(image.pixels())
+            .for_each(|(sharpened, original)| {
+                for (sharpened, original) in sharpened.0.iter_mut().zip(original.0).take(3) {
+                    let original = original as f32;
+                    let difference = *sharpened as f32 - original;
+                    *sharpened = (original + self.options.amount * difference)
+                        .round()
+                        .clamp(0.0, 255.0) as u8;
+                }
+            });
+
+        Ok(sharpened)
+    }
 }
 
 impl CpuProcessor for SharpenProcessor {
@@ -33,10 +103,34 @@ impl CpuProcessor for SharpenProcessor {
         image: FastImage,
         progress: Progress,
     ) -> ProcessingPicturifyResult<FastImage> {
-        let processor = ConvolutionRgbProcessor::new(ConvolutionRgbProcessorOptions {
-            kernel: ConvolutionKernel::new_sharpen(),
-            use_fast_approximation: self.options.use_fast_approximation,
-        });
-        processor.process(image, progress)
+        match self.options.method {
+            SharpenMethod::Kernel => self.process_kernel(image, progress),
+            SharpenMethod::UnsharpMask => {
+                UnsharpMaskProcessor::new(UnsharpMaskProcessorOptions {
+                    radius: self.options.radius,
+                    sigma: self.options.sigma,
+                    amount: self.options.amount,
+                    threshold: self.options.threshold,
+                    luminance_only: self.options.luminance_only,
+                })
+                .process(image, progress)
+            }
+            SharpenMethod::HighPass => {
+                HighPassSharpenProcessor::new(HighPassSharpenProcessorOptions {
+                    radius: self.options.radius,
+                    sigma: self.options.sigma,
+                    amount: self.options.amount,
+                    luminance_only: self.options.luminance_only,
+                })
+                .process(image, progress)
+            }
+        }
+    }
+
+    fn kernel_radius(&self) -> usize {
+        match self.options.method {
+            SharpenMethod::Kernel => 1,
+            SharpenMethod::UnsharpMask | SharpenMethod::HighPass => self.options.radius,
+        }
     }
 }
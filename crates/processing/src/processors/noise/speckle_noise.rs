@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::noise::{apply_noise_fn, standard_normal, to_channel};
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SpeckleNoiseProcessorOptions {
+    // standard deviation relative to the channel value
+    pub sigma: f32,
+    pub seed: Option<u64>,
+}
+
+// Multiplicative noise, bright areas get proportionally more of it than dark ones
+pub struct SpeckleNoiseProcessor {
+    options: SpeckleNoiseProcessorOptions,
+}
+
+impl SpeckleNoiseProcessor {
+    pub fn new(options: SpeckleNoiseProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for SpeckleNoiseProcessor {
+    fn name(&self) -> &'static str {
+        "speckle-noise"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let sigma = self.options.sigma;
+        apply_noise_fn(&mut image, self.options.seed, progress, |pixel, rng| {
+            for channel in &mut pixel.0[..3] {
+                let value = *channel as f32;
+                *channel = to_channel(value + value * sigma * standard_normal(rng));
+            }
+        });
+
+        Ok(image)
+    }
+}
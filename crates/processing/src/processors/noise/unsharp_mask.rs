@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::processors::CpuProcessor;
+use crate::common::sharpening::{gaussian_blur_plane, SharpeningPlanes};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UnsharpMaskProcessorOptions {
+    pub radius: usize,
+    pub sigma: f32,
+    // how much of the difference to the blurred image is added back
+    pub amount: f32,
+    // differences below this, in 0-255 channel units, are left alone so flat areas stay clean
+    pub threshold: f32,
+    pub luminance_only: bool,
+}
+
+pub struct UnsharpMaskProcessor {
+    options: UnsharpMaskProcessorOptions,
+}
+
+impl UnsharpMaskProcessor {
+    pub fn new(options: UnsharpMaskProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for UnsharpMaskProcessor {
+    fn name(&self) -> &'static str {
+        "unsharp-mask"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        mut progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let mut planes = SharpeningPlanes::from_image(&image, self.options.luminance_only);
+
+        progress.setup(planes.planes_mut().len());
+        for plane in planes.planes_mut() {
+            let blurred = gaussian_blur_plane(plane, self.options.radius, self.options.sigma);
+            for (value, blurred) in plane.values_mut().iter_mut().zip(blurred.values()) {
+                let detail = *value - blurred;
+                if detail.abs() >= self.options.threshold {
+                    *value += self.options.amount * detail;
+                }
+            }
+            progress.increment();
+        }
+        planes.write_to_image(&mut image);
+
+        Ok(image)
+    }
+
+    fn kernel_radius(&self) -> usize {
+        self.options.radius
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use picturify_core::core::apply_fn_to_pixels::ApplyFnToImagePixels;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::error::processing::ProcessingPicturifyResult;
+use picturify_core::geometry::coord::Coord;
+use picturify_core::threading::progress::Progress;
+
+use crate::common::line_integral::integrate_path;
+use crate::common::processors::CpuProcessor;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ZoomBlurProcessorOptions {
+    // point the streaks converge to, the image center when not set
+    pub center: Option<Coord>,
+    // fraction of the way to the center every pixel is averaged over, from 0 to 1
+    pub strength: f32,
+}
+
+pub struct ZoomBlurProcessor {
+    options: ZoomBlurProcessorOptions,
+}
+
+impl ZoomBlurProcessor {
+    pub fn new(options: ZoomBlurProcessorOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl CpuProcessor for ZoomBlurProcessor {
+    fn name(&self) -> &'static str {
+        "zoom-blur"
+    }
+    fn process(
+        &self,
+        mut image: FastImage,
+        progress: Progress,
+    ) -> ProcessingPicturifyResult<FastImage> {
+        let (width, height): (usize, usize) = image.size().into();
+        let (center_x, center_y) = match self.options.center {
+            Some(center) => (center.x() as f32, center.y() as f32),
+            None => (width as f32 / 2.0, height as f32 / 2.0),
+        };
+        let strength = self.options.strength.clamp(0.0, 1.0);
+        let source = image.clone();
+        image.par_apply_fn_to_image_pixel(
+            |pixel, coord| {
+                let (x, y): (usize, usize) = coord.into();
+                let (dx, dy) = (center_x - x as f32, center_y - y as f32);
+                let length = (dx * dx + dy * dy).sqrt() * strength;
+                *pixel = integrate_path(&source, length, |t| {
+                    (x as f32 + dx * strength * t, y as f32 + dy * strength * t)
+                });
+            },
+            Some(progress),
+        );
+
+        Ok(image)
+    }
+}
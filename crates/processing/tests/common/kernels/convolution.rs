@@ -1 +1,71 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::image::Rgba;
+use picturify_processing::common::kernels::convolution::ConvolutionKernel;
 
+const OPAQUE: [u8; 4] = [200, 50, 30, 255];
+const TRANSPARENT: [u8; 4] = [0, 0, 0, 0];
+// the fast path keeps premultiplied colors in 8 bits, at an alpha of a third a step is 3 levels
+const FAST_TOLERANCE: f32 = 4.0;
+
+// two opaque columns on the left, two fully transparent black ones on the right
+fn create_half_transparent_image() -> FastImage {
+    let values = (0..3)
+        .flat_map(|_| [OPAQUE, OPAQUE, TRANSPARENT, TRANSPARENT])
+        .flatten()
+        .collect();
+    FastImage::from_rgba_vec((4, 3).into(), values)
+}
+
+fn kernels() -> [ConvolutionKernel; 2] {
+    [
+        ConvolutionKernel::new_mean(1),
+        ConvolutionKernel::new_gaussian(1, 1.0),
+    ]
+}
+
+fn assert_keeps_color(color: [f32; 3], expected: [f32; 3], tolerance: f32) {
+    for (channel, expected) in color.iter().zip(expected) {
+        assert!(
+            (channel - expected).abs() <= tolerance,
+            "color {:?} darkened from {:?}",
+            color,
+            expected
+        );
+    }
+}
+
+#[test]
+fn convolve_rgb_fast_should_not_darken_next_to_transparent_pixels() {
+    let image = create_half_transparent_image();
+    let expected = OPAQUE.map(|channel| channel as f32);
+
+    for kernel in kernels() {
+        for x in [1, 2] {
+            let Rgba([red, green, blue, alpha]) = kernel.convolve_rgb_fast(&image, (x, 1).into());
+            assert!(alpha > 0 && alpha < 255);
+            assert_keeps_color(
+                [red, green, blue].map(|channel| channel as f32),
+                [expected[0], expected[1], expected[2]],
+                FAST_TOLERANCE,
+            );
+        }
+    }
+}
+
+#[test]
+fn convolve_rgb_slow_should_not_darken_next_to_transparent_pixels() {
+    let image = create_half_transparent_image();
+    let expected = image.get_lin_srgba_pixel((0, 0).into());
+
+    for kernel in kernels() {
+        for x in [1, 2] {
+            let pixel = kernel.convolve_rgb_slow(&image, (x, 1).into());
+            assert!(pixel.alpha > 0.0 && pixel.alpha < 1.0);
+            assert_keeps_color(
+                [pixel.red, pixel.green, pixel.blue],
+                [expected.red, expected.green, expected.blue],
+                0.001,
+            );
+        }
+    }
+}
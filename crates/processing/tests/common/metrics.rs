@@ -0,0 +1,116 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_processing::common::metrics::ComparisonMetrics;
+
+const EPSILON: f64 = 1e-9;
+
+fn noise_image(width: usize, height: usize, seed: u64) -> FastImage {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let values = (0..width * height)
+        .flat_map(|_| [rng.gen(), rng.gen(), rng.gen(), u8::MAX])
+        .collect();
+    FastImage::from_rgba_vec((width, height).into(), values)
+}
+
+fn offset_image(image: &FastImage, offset: u8) -> FastImage {
+    let values = image
+        .to_rgba_vec()
+        .chunks(4)
+        .flat_map(|pixel| {
+            [
+                pixel[0] + offset,
+                pixel[1] + offset,
+                pixel[2] + offset,
+                pixel[3],
+            ]
+        })
+        .collect();
+    FastImage::from_rgba_vec(image.size(), values)
+}
+
+fn assert_identical(image: &FastImage) {
+    let metrics = ComparisonMetrics::new(image, image).unwrap();
+    assert_eq!(metrics.mae, 0.0);
+    assert_eq!(metrics.mse, 0.0);
+    assert_eq!(metrics.psnr, f64::INFINITY);
+    assert!(
+        (metrics.ssim - 1.0).abs() < EPSILON,
+        "ssim {}",
+        metrics.ssim
+    );
+    assert!(
+        (metrics.ms_ssim - 1.0).abs() < EPSILON,
+        "ms-ssim {}",
+        metrics.ms_ssim
+    );
+}
+
+fn assert_dissimilar(first: &FastImage, second: &FastImage) {
+    let metrics = ComparisonMetrics::new(first, second).unwrap();
+    assert!(metrics.ssim < 1.0, "ssim {}", metrics.ssim);
+    assert!(metrics.ms_ssim < 1.0, "ms-ssim {}", metrics.ms_ssim);
+    assert!(metrics.ms_ssim >= 0.0, "ms-ssim {}", metrics.ms_ssim);
+}
+
+#[test]
+fn constant_offset_should_give_known_psnr() {
+    let first = FastImage::from_rgba_vec((16, 16).into(), vec![100; 16 * 16 * 4]);
+    let second = offset_image(&first, 10);
+
+    let metrics = ComparisonMetrics::new(&first, &second).unwrap();
+    assert!((metrics.mae - 10.0).abs() < EPSILON);
+    assert!((metrics.mse - 100.0).abs() < EPSILON);
+    assert!((metrics.psnr - 20.0 * (255.0f64 / 10.0).log10()).abs() < EPSILON);
+    assert_eq!(metrics.red.max_delta, 10);
+    assert_eq!(metrics.alpha.max_delta, 0);
+    assert_eq!(metrics.alpha.psnr, f64::INFINITY);
+}
+
+#[test]
+fn mae_and_mse_should_match_hand_computed_values() {
+    let first = FastImage::from_rgba_vec((2, 1).into(), vec![10, 20, 30, 255, 0, 0, 0, 255]);
+    let second = FastImage::from_rgba_vec((2, 1).into(), vec![13, 20, 26, 255, 0, 5, 0, 200]);
+
+    let metrics = ComparisonMetrics::new(&first, &second).unwrap();
+    assert!((metrics.red.mae - 1.5).abs() < EPSILON);
+    assert!((metrics.red.mse - 4.5).abs() < EPSILON);
+    assert!((metrics.green.mae - 2.5).abs() < EPSILON);
+    assert!((metrics.green.mse - 12.5).abs() < EPSILON);
+    assert!((metrics.blue.mae - 2.0).abs() < EPSILON);
+    assert!((metrics.blue.mse - 8.0).abs() < EPSILON);
+    assert!((metrics.alpha.mae - 27.5).abs() < EPSILON);
+    assert!((metrics.alpha.mse - 1512.5).abs() < EPSILON);
+    assert_eq!(
+        [
+            metrics.red.max_delta,
+            metrics.green.max_delta,
+            metrics.blue.max_delta,
+            metrics.alpha.max_delta
+        ],
+        [3, 5, 4, 55]
+    );
+    // alpha is left out of the overall values
+    assert!((metrics.mae - 2.0).abs() < EPSILON);
+    assert!((metrics.mse - 25.0 / 3.0).abs() < EPSILON);
+}
+
+#[test]
+fn identical_images_should_be_fully_similar() {
+    assert_identical(&noise_image(64, 64, 1));
+    assert_identical(&noise_image(37, 23, 2));
+    assert_identical(&noise_image(1, 1, 3));
+}
+
+#[test]
+fn noise_should_lower_similarity() {
+    assert_dissimilar(&noise_image(64, 64, 1), &noise_image(64, 64, 4));
+    assert_dissimilar(&noise_image(37, 23, 2), &noise_image(37, 23, 5));
+    assert_dissimilar(&noise_image(1, 1, 3), &noise_image(1, 1, 6));
+}
+
+#[test]
+fn images_of_different_sizes_should_fail() {
+    assert!(ComparisonMetrics::new(&noise_image(4, 4, 1), &noise_image(4, 5, 1)).is_err());
+}
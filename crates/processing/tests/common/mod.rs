@@ -1 +1,2 @@
 mod kernels;
+mod metrics;
@@ -0,0 +1,111 @@
+use std::env::var;
+use std::fs::{copy, create_dir_all};
+use std::path::{Path, PathBuf};
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::{ReadFromFile, WriteToFile};
+use picturify_processing::common::metrics::{difference_image, ComparisonMetrics};
+
+use crate::helpers::workspace_dir;
+
+// set to 1 to overwrite the references with the current outputs instead of comparing
+pub const BLESS_ENV_VAR: &str = "PICTURIFY_BLESS";
+
+#[derive(Clone, Copy, Debug)]
+pub struct GoldenTolerance {
+    // lowest accepted PSNR of the color channels
+    pub min_psnr: f64,
+    // largest accepted difference of a single channel, alpha included
+    pub max_delta: u8,
+}
+
+impl GoldenTolerance {
+    pub const EXACT: Self = Self {
+        min_psnr: f64::INFINITY,
+        max_delta: 0,
+    };
+}
+
+pub fn get_golden_input_png_path() -> PathBuf {
+    workspace_dir().join("assets/golden/input.png")
+}
+
+pub fn get_golden_reference_path(name: &str) -> PathBuf {
+    workspace_dir().join(format!("assets/golden/{}.png", name))
+}
+
+// failed comparisons leave the output and a diff image here, outside of the source tree
+pub fn get_golden_failure_dir() -> PathBuf {
+    workspace_dir().join("target/golden")
+}
+
+fn is_blessing() -> bool {
+    var(BLESS_ENV_VAR).is_ok_and(|value| value == "1")
+}
+
+fn write_failed_output(name: &str, actual: &FastImage) -> PathBuf {
+    let failure_dir = get_golden_failure_dir();
+    create_dir_all(&failure_dir).unwrap();
+    let actual_path = failure_dir.join(format!("{}_actual.png", name));
+    actual.write_to_file(&actual_path).unwrap();
+    actual_path
+}
+
+// Compares an output against the reference with the same name, panicking with the metrics and
+// the path of a diff image when it is outside the tolerance
+pub fn assert_golden(name: &str, output: &Path, tolerance: GoldenTolerance) {
+    let reference_path = get_golden_reference_path(name);
+    if is_blessing() {
+        copy(output, &reference_path).unwrap();
+        return;
+    }
+    if !reference_path.exists() {
+        panic!(
+            "missing golden reference {}, run the test with {}=1 to create it",
+            reference_path.display(),
+            BLESS_ENV_VAR
+        );
+    }
+
+    let reference = FastImage::read_from_file(&reference_path).unwrap();
+    let actual = FastImage::read_from_file(output).unwrap();
+    if reference.size() != actual.size() {
+        let actual_path = write_failed_output(name, &actual);
+        panic!(
+            "golden {} size mismatch, expected {:?}, got {:?}, output written to {}",
+            name,
+            reference.size(),
+            actual.size(),
+            actual_path.display()
+        );
+    }
+
+    let metrics = ComparisonMetrics::new(&reference, &actual).unwrap();
+    let max_delta = [metrics.red, metrics.green, metrics.blue, metrics.alpha]
+        .iter()
+        .map(|difference| difference.max_delta)
+        .max()
+        .unwrap_or(0);
+    if metrics.psnr >= tolerance.min_psnr && max_delta <= tolerance.max_delta {
+        return;
+    }
+
+    let actual_path = write_failed_output(name, &actual);
+    let diff_path = get_golden_failure_dir().join(format!("{}_diff.png", name));
+    difference_image(&reference, &actual, tolerance.max_delta)
+        .unwrap()
+        .write_to_file(&diff_path)
+        .unwrap();
+
+    panic!(
+        "golden {} differs, psnr {:.4} (min {}), max delta {} (max {}), output written to {}, \
+        diff written to {}",
+        name,
+        metrics.psnr,
+        tolerance.min_psnr,
+        max_delta,
+        tolerance.max_delta,
+        actual_path.display(),
+        diff_path.display()
+    );
+}
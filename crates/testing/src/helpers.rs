@@ -19,6 +19,22 @@ pub fn get_sample_100x100_png_path() -> PathBuf {
     workspace_dir().join("assets/samples/sample_100x100.png")
 }
 
+pub fn get_sample_animated_gif_path() -> PathBuf {
+    workspace_dir().join("assets/samples/sample_animated.gif")
+}
+
+pub fn get_sample_lut_path() -> PathBuf {
+    workspace_dir().join("assets/samples/sample_lut.cube")
+}
+
+pub fn get_sample_kernel_json_path() -> PathBuf {
+    workspace_dir().join("assets/samples/sample_kernel.json")
+}
+
+pub fn get_sample_kernel_csv_path() -> PathBuf {
+    workspace_dir().join("assets/samples/sample_kernel.csv")
+}
+
 pub fn get_null_path() -> PathBuf {
     let workspace_dir = workspace_dir();
     if !workspace_dir.join("assets/null").exists() {
@@ -1,2 +1,4 @@
+pub mod golden;
 pub mod helpers;
+pub mod strategies;
 pub mod test_files;
@@ -0,0 +1,38 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::geometry::size::Size;
+
+// keeps every processor fast enough in debug builds while still covering sizes above the kernels
+pub const MAX_IMAGE_SIDE: usize = 12;
+
+// sides biased towards empty, single pixel and smaller than kernel images
+pub fn image_side_strategy() -> impl Strategy<Value = usize> {
+    prop_oneof![
+        1 => Just(0usize),
+        2 => Just(1usize),
+        4 => 2..=4usize,
+        3 => 5..=MAX_IMAGE_SIDE,
+    ]
+}
+
+pub fn fast_image_strategy() -> impl Strategy<Value = FastImage> {
+    (image_side_strategy(), image_side_strategy())
+        .prop_flat_map(|(width, height)| sized_fast_image_strategy(width, height))
+}
+
+// two images of the same size, for processors combining them pixel by pixel
+pub fn fast_image_pair_strategy() -> impl Strategy<Value = (FastImage, FastImage)> {
+    (image_side_strategy(), image_side_strategy()).prop_flat_map(|(width, height)| {
+        (
+            sized_fast_image_strategy(width, height),
+            sized_fast_image_strategy(width, height),
+        )
+    })
+}
+
+pub fn sized_fast_image_strategy(width: usize, height: usize) -> impl Strategy<Value = FastImage> {
+    vec(any::<u8>(), width * height * 4)
+        .prop_map(move |values| FastImage::from_rgba_vec(Size::new(width, height), values))
+}
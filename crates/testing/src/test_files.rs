@@ -1,6 +1,7 @@
 use std::fs::remove_file;
 use std::path::{Path, PathBuf};
 
+use crate::golden::get_golden_input_png_path;
 use crate::helpers::{get_null_path, get_sample_100x100_png_path};
 use assert_cmd::Command;
 use uuid::Uuid;
@@ -18,6 +19,13 @@ impl TestFiles {
         Self { input, output }
     }
 
+    pub fn new_golden_png() -> Self {
+        let input = get_golden_input_png_path();
+        let uuid = Uuid::new_v4();
+        let output = get_null_path().join(format!("{}.png", uuid));
+        Self { input, output }
+    }
+
     pub fn input(&self) -> &Path {
         &self.input
     }
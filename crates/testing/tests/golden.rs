@@ -0,0 +1,39 @@
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::{ReadFromFile, WriteToFile};
+use picturify_core::threading::progress::Progress;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::edge::canny::{
+    CannyEdgeDetectionType, CannyProcessor, CannyProcessorOptions,
+};
+use picturify_testing::golden::{assert_golden, GoldenTolerance};
+use picturify_testing::test_files::TestFiles;
+
+// canny edges are either black or white, a gradient right at a threshold may flip a few pixels
+// between platforms
+const CANNY_TOLERANCE: GoldenTolerance = GoldenTolerance {
+    min_psnr: 30.0,
+    max_delta: u8::MAX,
+};
+
+// processors without a command are checked here, on the same input as the cli goldens
+fn run_processor_golden(name: &str, processor: &dyn CpuProcessor, tolerance: GoldenTolerance) {
+    let test_files = TestFiles::new_golden_png();
+    let image = FastImage::read_from_file(test_files.input()).unwrap();
+
+    let output = processor.process(image, Progress::new()).unwrap();
+    output.write_to_file(test_files.output()).unwrap();
+
+    assert_golden(name, test_files.output(), tolerance);
+}
+
+#[test]
+fn canny_should_match_golden() {
+    let processor = CannyProcessor::new(CannyProcessorOptions {
+        sigma: 1.0,
+        radius: 2,
+        edge_detection_type: CannyEdgeDetectionType::Sobel,
+        low_threshold: 0.1,
+        high_threshold: 0.3,
+    });
+    run_processor_golden("canny", &processor, CANNY_TOLERANCE);
+}
@@ -0,0 +1,2 @@
+mod golden;
+mod processors;
@@ -0,0 +1,210 @@
+use proptest::prelude::*;
+
+use picturify_core::core::color_palette::ColorPalette;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::geometry::angle::Angle;
+use picturify_core::geometry::coord::Coord;
+use picturify_core::image::Rgba;
+use picturify_processing::common::color_space::ColorSpace;
+use picturify_processing::common::lut3d::{Lut3d, Lut3dInterpolation};
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::common::region::Mask;
+use picturify_processing::processors::color::brightness::{
+    BrightnessProcessor, BrightnessProcessorOptions,
+};
+use picturify_processing::processors::color::channel_extraction::{
+    ChannelExtractionProcessor, ChannelExtractionProcessorOptions,
+};
+use picturify_processing::processors::color::channel_merge::{
+    ChannelMergeProcessor, ChannelMergeProcessorOptions,
+};
+use picturify_processing::processors::color::curves::{
+    Curve, Curves, CurvesProcessor, CurvesProcessorOptions,
+};
+use picturify_processing::processors::color::extract_alpha::ExtractAlphaProcessor;
+use picturify_processing::processors::color::flatten::{FlattenProcessor, FlattenProcessorOptions};
+use picturify_processing::processors::color::gamma::{GammaProcessor, GammaProcessorOptions};
+use picturify_processing::processors::color::grayscale::{
+    GrayscaleProcessor, GrayscaleProcessorOptions, GrayscaleStrategy,
+};
+use picturify_processing::processors::color::hue_rotation::{
+    HueRotationProcessor, HueRotationProcessorOptions,
+};
+use picturify_processing::processors::color::levels::{
+    Levels, LevelsProcessor, LevelsProcessorOptions,
+};
+use picturify_processing::processors::color::lightness::{
+    LightnessProcessor, LightnessProcessorOptions, LightnessSpace,
+};
+use picturify_processing::processors::color::lut::{LutProcessor, LutProcessorOptions};
+use picturify_processing::processors::color::negative::{
+    NegativeProcessor, NegativeProcessorOptions,
+};
+use picturify_processing::processors::color::palette_quantization::{
+    Dithering, PaletteQuantizationProcessor, PaletteQuantizationProcessorOptions,
+};
+use picturify_processing::processors::color::quantization::{
+    QuantizationProcessor, QuantizationProcessorOptions,
+};
+use picturify_processing::processors::color::remapping::{
+    RemappingFunction, RemappingProcessor, RemappingProcessorOptions,
+};
+use picturify_processing::processors::color::saturation::{
+    SaturationProcessor, SaturationProcessorOptions,
+};
+use picturify_processing::processors::color::sepia::{SepiaProcessor, SepiaProcessorOptions};
+use picturify_processing::processors::color::set_alpha::{
+    SetAlphaProcessor, SetAlphaProcessorOptions,
+};
+use picturify_processing::processors::color::temperature::{
+    TemperatureProcessor, TemperatureProcessorOptions,
+};
+use picturify_processing::processors::color::threshold::{
+    ThresholdProcessor, ThresholdProcessorOptions,
+};
+use picturify_processing::processors::color::white_balance::{
+    WhiteBalanceMethod, WhiteBalanceProcessor, WhiteBalanceProcessorOptions,
+};
+use picturify_testing::strategies::fast_image_strategy;
+
+use crate::processors::{check_processors, config};
+
+fn identity_lut() -> Lut3d {
+    let table = (0..8)
+        .map(|index| [index & 1, (index >> 1) & 1, index >> 2].map(|value| value as f32))
+        .collect();
+    Lut3d::new(2, table).unwrap()
+}
+
+fn color_processors(image: &FastImage) -> Vec<Box<dyn CpuProcessor>> {
+    let mut processors: Vec<Box<dyn CpuProcessor>> = vec![
+        Box::new(BrightnessProcessor::new(BrightnessProcessorOptions {
+            factor: 1.5,
+        })),
+        Box::new(ChannelExtractionProcessor::new(
+            ChannelExtractionProcessorOptions {
+                space: ColorSpace::Hsv,
+                channel: 2,
+            },
+        )),
+        Box::new(ChannelMergeProcessor::new(ChannelMergeProcessorOptions {
+            space: ColorSpace::Lab,
+            channels: [Some(image.clone()), None, Some(image.clone())],
+        })),
+        Box::new(CurvesProcessor::new(CurvesProcessorOptions {
+            curves: Curves {
+                master: Curve::new(vec![(0.0, 0.1), (0.5, 0.6), (1.0, 0.9)]),
+                red: Curve::identity(),
+                green: Curve::identity(),
+                blue: Curve::identity(),
+            },
+            use_fast_approximation: true,
+        })),
+        Box::new(ExtractAlphaProcessor),
+        Box::new(FlattenProcessor::new(FlattenProcessorOptions {
+            background: Rgba([255, 0, 0, 255]),
+        })),
+        Box::new(HueRotationProcessor::new(HueRotationProcessorOptions {
+            angle: Angle::from_degrees(45.0),
+        })),
+        Box::new(LevelsProcessor::new(LevelsProcessorOptions {
+            levels: Levels {
+                input_black: 0.1,
+                input_white: 0.9,
+                gamma: 1.2,
+                output_black: 0.0,
+                output_white: 1.0,
+            },
+            use_fast_approximation: false,
+        })),
+        Box::new(LutProcessor::new(LutProcessorOptions {
+            lut: identity_lut(),
+            interpolation: Lut3dInterpolation::Tetrahedral,
+        })),
+        Box::new(PaletteQuantizationProcessor::new(
+            PaletteQuantizationProcessorOptions {
+                palette: ColorPalette::new(vec![[0, 0, 0], [255, 255, 255], [255, 0, 0]]).unwrap(),
+                dithering: Dithering::FloydSteinberg,
+            },
+        )),
+        Box::new(QuantizationProcessor::new(QuantizationProcessorOptions {
+            levels: 4,
+            use_fast_approximation: false,
+        })),
+        Box::new(RemappingProcessor::new(RemappingProcessorOptions {
+            function: RemappingFunction::Linear { min: 0.2, max: 0.8 },
+        })),
+        Box::new(SaturationProcessor::new(SaturationProcessorOptions {
+            factor: 1.5,
+            vibrance: 0.5,
+        })),
+        Box::new(SetAlphaProcessor::new(SetAlphaProcessorOptions {
+            alpha: Mask::from_image(image),
+        })),
+        Box::new(TemperatureProcessor::new(TemperatureProcessorOptions {
+            temperature: 3200.0,
+            tint: 10.0,
+        })),
+        Box::new(ThresholdProcessor::new(ThresholdProcessorOptions {
+            red_threshold: 128,
+            green_threshold: 64,
+            blue_threshold: 192,
+        })),
+        Box::new(WhiteBalanceProcessor::new(WhiteBalanceProcessorOptions {
+            method: WhiteBalanceMethod::Neutral,
+            point: Some(Coord::new(0, 0)),
+        })),
+    ];
+
+    for use_fast_approximation in [false, true] {
+        processors.push(Box::new(GammaProcessor::new(GammaProcessorOptions {
+            gamma: 2.2,
+            use_fast_approximation,
+        })));
+        processors.push(Box::new(NegativeProcessor::new(NegativeProcessorOptions {
+            use_fast_approximation,
+        })));
+        processors.push(Box::new(SepiaProcessor::new(SepiaProcessorOptions {
+            use_fast_approximation,
+        })));
+    }
+    for strategy in [
+        GrayscaleStrategy::Average,
+        GrayscaleStrategy::Lightness,
+        GrayscaleStrategy::Luminosity,
+    ] {
+        processors.push(Box::new(GrayscaleProcessor::new(
+            GrayscaleProcessorOptions {
+                strategy,
+                use_fast_approximation: false,
+            },
+        )));
+    }
+    for space in [LightnessSpace::Oklab, LightnessSpace::Lch] {
+        processors.push(Box::new(LightnessProcessor::new(
+            LightnessProcessorOptions { factor: 1.2, space },
+        )));
+    }
+    for method in [
+        WhiteBalanceMethod::GrayWorld,
+        WhiteBalanceMethod::WhitePatch,
+    ] {
+        processors.push(Box::new(WhiteBalanceProcessor::new(
+            WhiteBalanceProcessorOptions {
+                method,
+                point: None,
+            },
+        )));
+    }
+
+    processors
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn color_processors_should_keep_size(image in fast_image_strategy()) {
+        check_processors(color_processors(&image), &image)?;
+    }
+}
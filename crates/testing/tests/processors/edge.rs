@@ -0,0 +1,113 @@
+use proptest::prelude::*;
+
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::blob::laplacian_of_gaussian::{
+    LaplacianOfGaussianProcessor, LaplacianOfGaussianProcessorOptions,
+};
+use picturify_processing::processors::edge::canny::{
+    CannyEdgeDetectionType, CannyProcessor, CannyProcessorOptions,
+};
+use picturify_processing::processors::edge::difference_of_gaussians::{
+    DifferenceOfGaussiansProcessor, DifferenceOfGaussiansProcessorOptions,
+};
+use picturify_processing::processors::edge::emboss::{EmbossProcessor, EmbossProcessorOptions};
+use picturify_processing::processors::edge::gradient::{
+    GradientMode, GradientOperator, GradientProcessor, GradientProcessorOptions,
+};
+use picturify_processing::processors::edge::prewitt::{PrewittProcessor, PrewittProcessorOptions};
+use picturify_processing::processors::edge::prewitt_rgb::{
+    PrewittRgbProcessor, PrewittRgbProcessorOptions,
+};
+use picturify_processing::processors::edge::sobel::{SobelProcessor, SobelProcessorOptions};
+use picturify_processing::processors::edge::sobel_rgb::{
+    SobelRgbProcessor, SobelRgbProcessorOptions,
+};
+use picturify_processing::processors::edge::xdog::{XDogProcessor, XDogProcessorOptions};
+use picturify_testing::strategies::fast_image_strategy;
+
+use crate::processors::{check_processors, config};
+
+fn edge_processors() -> Vec<Box<dyn CpuProcessor>> {
+    let mut processors: Vec<Box<dyn CpuProcessor>> = vec![
+        Box::new(DifferenceOfGaussiansProcessor::new(
+            DifferenceOfGaussiansProcessorOptions {
+                sigma: 1.0,
+                k: 1.6,
+                gain: 4.0,
+            },
+        )),
+        Box::new(XDogProcessor::new(XDogProcessorOptions {
+            sigma: 1.0,
+            k: 1.6,
+            sharpness: 20.0,
+            epsilon: 0.5,
+            phi: 10.0,
+        })),
+    ];
+
+    for use_fast_approximation in [false, true] {
+        processors.push(Box::new(LaplacianOfGaussianProcessor::new(
+            LaplacianOfGaussianProcessorOptions {
+                radius: 2,
+                sigma: 1.0,
+                use_fast_approximation,
+            },
+        )));
+        processors.push(Box::new(EmbossProcessor::new(EmbossProcessorOptions {
+            use_fast_approximation,
+        })));
+        processors.push(Box::new(PrewittProcessor::new(PrewittProcessorOptions {
+            use_fast_approximation,
+        })));
+        processors.push(Box::new(PrewittRgbProcessor::new(
+            PrewittRgbProcessorOptions {
+                use_fast_approximation,
+            },
+        )));
+        processors.push(Box::new(SobelProcessor::new(SobelProcessorOptions {
+            use_fast_approximation,
+        })));
+        processors.push(Box::new(SobelRgbProcessor::new(SobelRgbProcessorOptions {
+            use_fast_approximation,
+        })));
+    }
+    for edge_detection_type in [
+        CannyEdgeDetectionType::Sobel,
+        CannyEdgeDetectionType::Prewitt,
+        CannyEdgeDetectionType::Scharr,
+    ] {
+        processors.push(Box::new(CannyProcessor::new(CannyProcessorOptions {
+            sigma: 1.0,
+            radius: 2,
+            edge_detection_type,
+            low_threshold: 0.1,
+            high_threshold: 0.3,
+        })));
+    }
+    for operator in [GradientOperator::Sobel, GradientOperator::Prewitt] {
+        for mode in [
+            GradientMode::Magnitude,
+            GradientMode::Orientation,
+            GradientMode::Dx,
+            GradientMode::Dy,
+        ] {
+            processors.push(Box::new(GradientProcessor::new(GradientProcessorOptions {
+                operator,
+                mode,
+                gain: 1.0,
+                use_fast_approximation: false,
+            })));
+        }
+    }
+
+    processors
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn edge_processors_should_keep_size(image in fast_image_strategy()) {
+        check_processors(edge_processors(), &image)?;
+    }
+}
@@ -0,0 +1,95 @@
+use proptest::prelude::*;
+
+use picturify_core::geometry::angle::Angle;
+use picturify_core::geometry::size::Size;
+use picturify_core::image::Rgba;
+use picturify_processing::processors::geometry::crop::{
+    CropBorder, CropProcessor, CropProcessorOptions,
+};
+use picturify_processing::processors::geometry::enlargement::{
+    EnlargementBorder, EnlargementProcessor, EnlargementProcessorOptions, EnlargementStrategy,
+};
+use picturify_processing::processors::geometry::rotate_fixed::{
+    RotateFixedProcessor, RotateFixedStrategy, RoteteFixedProcessorOptions,
+};
+use picturify_processing::processors::geometry::rotate_flexible::{
+    RotateFlexibleProcessor, RotateFlexibleProcessorOptions,
+};
+use picturify_processing::processors::geometry::scale::{
+    ScaleProcessor, ScaleProcessorOptions, ScaleStrategy,
+};
+use picturify_testing::strategies::{fast_image_strategy, image_side_strategy};
+
+use crate::processors::{check_processor, config};
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn crop_should_return_border_size(
+        image in fast_image_strategy(),
+        width in image_side_strategy(),
+        height in image_side_strategy(),
+        x_offset in image_side_strategy(),
+        y_offset in image_side_strategy(),
+    ) {
+        let crop_border = CropBorder::new(width, height, x_offset, y_offset);
+        let processor = CropProcessor::new(CropProcessorOptions { crop_border });
+        check_processor(&processor, &image, crop_border.into())?;
+    }
+
+    #[test]
+    fn enlargement_should_add_border(
+        image in fast_image_strategy(),
+        top in 0..4usize,
+        right in 0..4usize,
+        bottom in 0..4usize,
+        left in 0..4usize,
+    ) {
+        let processor = EnlargementProcessor::new(EnlargementProcessorOptions {
+            border: EnlargementBorder::new(top, right, bottom, left),
+            strategy: EnlargementStrategy::Constant(Rgba([0, 0, 0, 255]).into()),
+        });
+        let expected_size = image.size().increase_by(left + right, top + bottom);
+        check_processor(&processor, &image, expected_size)?;
+    }
+
+    #[test]
+    fn rotate_fixed_should_swap_sides(image in fast_image_strategy()) {
+        for strategy in [
+            RotateFixedStrategy::Deg90,
+            RotateFixedStrategy::Deg180,
+            RotateFixedStrategy::Deg270,
+        ] {
+            let processor = RotateFixedProcessor::new(RoteteFixedProcessorOptions { strategy });
+            let expected_size = match strategy {
+                RotateFixedStrategy::Deg180 => image.size(),
+                _ => image.size().rotate_90(),
+            };
+            check_processor(&processor, &image, expected_size)?;
+        }
+    }
+
+    #[test]
+    fn rotate_flexible_should_fit_rotated_image(
+        image in fast_image_strategy(),
+        degrees in -360.0f32..360.0,
+    ) {
+        let angle = Angle::from_degrees(degrees);
+        let processor = RotateFlexibleProcessor::new(RotateFlexibleProcessorOptions { angle });
+        check_processor(&processor, &image, image.size().rotate(angle))?;
+    }
+
+    #[test]
+    fn scale_should_return_target_size(
+        image in fast_image_strategy(),
+        width in image_side_strategy(),
+        height in image_side_strategy(),
+    ) {
+        for strategy in [ScaleStrategy::NearestNeighbor, ScaleStrategy::Bilinear] {
+            let size = Size::new(width, height);
+            let processor = ScaleProcessor::new(ScaleProcessorOptions { size, strategy });
+            check_processor(&processor, &image, size)?;
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use proptest::prelude::*;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::math::add::{AddProcessor, AddProcessorOptions};
+use picturify_processing::processors::math::multiply::{
+    MultiplyProcessor, MultiplyProcessorOptions,
+};
+use picturify_processing::processors::math::subtract::{
+    SubtractProcessor, SubtractProcessorOptions,
+};
+use picturify_testing::strategies::fast_image_pair_strategy;
+
+use crate::processors::{check_processors, config};
+
+fn math_processors(second_image: &FastImage) -> Vec<Box<dyn CpuProcessor>> {
+    vec![
+        Box::new(AddProcessor::new(AddProcessorOptions {
+            second_image: second_image.clone(),
+        })),
+        Box::new(MultiplyProcessor::new(MultiplyProcessorOptions {
+            second_image: second_image.clone(),
+        })),
+        Box::new(SubtractProcessor::new(SubtractProcessorOptions {
+            second_image: second_image.clone(),
+        })),
+    ]
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn math_processors_should_keep_size((image, second_image) in fast_image_pair_strategy()) {
+        check_processors(math_processors(&second_image), &image)?;
+    }
+}
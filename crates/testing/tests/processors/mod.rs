@@ -0,0 +1,64 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::geometry::size::Size;
+use picturify_core::threading::progress::Progress;
+use picturify_processing::common::processors::CpuProcessor;
+
+mod color;
+mod edge;
+mod geometry;
+mod math;
+mod noise;
+
+// every case runs all processors of a group, so fewer cases than the proptest default
+const CASES: u32 = 64;
+
+fn config() -> ProptestConfig {
+    ProptestConfig::with_cases(CASES)
+}
+
+// processors may reject an image with an error, but must not panic and must return the size
+// they promise
+fn check_processor(
+    processor: &dyn CpuProcessor,
+    image: &FastImage,
+    expected_size: Size,
+) -> Result<(), TestCaseError> {
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        processor.process(image.clone(), Progress::new())
+    }));
+    let result = result.map_err(|_| {
+        TestCaseError::fail(format!(
+            "{} panicked on a {:?} image",
+            processor.name(),
+            image.size()
+        ))
+    })?;
+
+    if let Ok(output) = result {
+        prop_assert_eq!(
+            output.size(),
+            expected_size,
+            "{} returned the wrong size for a {:?} image",
+            processor.name(),
+            image.size()
+        );
+    }
+
+    Ok(())
+}
+
+fn check_processors(
+    processors: Vec<Box<dyn CpuProcessor>>,
+    image: &FastImage,
+) -> Result<(), TestCaseError> {
+    for processor in processors {
+        check_processor(processor.as_ref(), image, image.size())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use proptest::prelude::*;
+
+use picturify_core::geometry::angle::Angle;
+use picturify_processing::common::kernels::convolution::ConvolutionKernel;
+use picturify_processing::common::processors::CpuProcessor;
+use picturify_processing::processors::noise::anisotropic_diffusion::{
+    AnisotropicDiffusionProcessor, AnisotropicDiffusionProcessorOptions, ConductionFunction,
+};
+use picturify_processing::processors::noise::anisotropic_kuwahara::{
+    AnisotropicKuwaharaProcessor, AnisotropicKuwaharaProcessorOptions,
+};
+use picturify_processing::processors::noise::bilateral_blur::{
+    BilateralBlurProcessor, BilateralBlurProcessorOptions,
+};
+use picturify_processing::processors::noise::convolve::{
+    ConvolveChannels, ConvolveProcessor, ConvolveProcessorOptions,
+};
+use picturify_processing::processors::noise::gaussian_blur::{
+    GaussianBlurProcessor, GaussianBlurProcessorOptions,
+};
+use picturify_processing::processors::noise::gaussian_noise::{
+    GaussianNoiseProcessor, GaussianNoiseProcessorOptions,
+};
+use picturify_processing::processors::noise::generalized_kuwahara::{
+    GeneralizedKuwaharaProcessor, GeneralizedKuwaharaProcessorOptions,
+};
+use picturify_processing::processors::noise::kuwahara::{
+    KuwaharaProcessor, KuwaharaProcessorOptions,
+};
+use picturify_processing::processors::noise::mean_blur::{
+    MeanBlurProcessor, MeanBlurProcessorOptions,
+};
+use picturify_processing::processors::noise::median_blur::{
+    MedianBlurProcessor, MedianBlurProcessorOptions,
+};
+use picturify_processing::processors::noise::motion_blur::{
+    MotionBlurProcessor, MotionBlurProcessorOptions,
+};
+use picturify_processing::processors::noise::non_local_means::{
+    NonLocalMeansProcessor, NonLocalMeansProcessorOptions,
+};
+use picturify_processing::processors::noise::poisson_noise::{
+    PoissonNoiseProcessor, PoissonNoiseProcessorOptions,
+};
+use picturify_processing::processors::noise::radial_blur::{
+    RadialBlurProcessor, RadialBlurProcessorOptions,
+};
+use picturify_processing::processors::noise::salt_and_pepper_noise::{
+    SaltAndPepperNoiseProcessor, SaltAndPepperNoiseProcessorOptions,
+};
+use picturify_processing::processors::noise::sharpen::{
+    SharpenMethod, SharpenProcessor, SharpenProcessorOptions,
+};
+use picturify_processing::processors::noise::speckle_noise::{
+    SpeckleNoiseProcessor, SpeckleNoiseProcessorOptions,
+};
+use picturify_processing::processors::noise::zoom_blur::{
+    ZoomBlurProcessor, ZoomBlurProcessorOptions,
+};
+use picturify_testing::strategies::fast_image_strategy;
+
+use crate::processors::{check_processors, config};
+
+// radius 2 is enough to exceed the smallest generated images
+const RADIUS: usize = 2;
+
+fn noise_processors() -> Vec<Box<dyn CpuProcessor>> {
+    let mut processors: Vec<Box<dyn CpuProcessor>> = vec![
+        Box::new(AnisotropicKuwaharaProcessor::new(
+            AnisotropicKuwaharaProcessorOptions {
+                radius: RADIUS,
+                sectors: 8,
+                sharpness: 8.0,
+                eccentricity: 1.0,
+                tensor_sigma: 2.0,
+            },
+        )),
+        Box::new(ConvolveProcessor::new(ConvolveProcessorOptions {
+            kernel: ConvolutionKernel::new_sharpen(),
+            normalize: true,
+            divisor: 1.0,
+            bias: 0.0,
+            anchor: None,
+            channels: ConvolveChannels {
+                red: true,
+                green: true,
+                blue: true,
+                alpha: true,
+            },
+        })),
+        Box::new(GaussianNoiseProcessor::new(GaussianNoiseProcessorOptions {
+            sigma: 20.0,
+            seed: Some(0),
+        })),
+        Box::new(GeneralizedKuwaharaProcessor::new(
+            GeneralizedKuwaharaProcessorOptions {
+                radius: RADIUS,
+                sectors: 8,
+                sharpness: 8.0,
+            },
+        )),
+        Box::new(KuwaharaProcessor::new(KuwaharaProcessorOptions {
+            radius: RADIUS,
+        })),
+        Box::new(MedianBlurProcessor::new(MedianBlurProcessorOptions {
+            radius: RADIUS,
+        })),
+        Box::new(MotionBlurProcessor::new(MotionBlurProcessorOptions {
+            angle: Angle::from_degrees(30.0),
+            length: 5.0,
+        })),
+        Box::new(NonLocalMeansProcessor::new(NonLocalMeansProcessorOptions {
+            search_radius: RADIUS,
+            patch_radius: 1,
+            strength: 10.0,
+        })),
+        Box::new(PoissonNoiseProcessor::new(PoissonNoiseProcessorOptions {
+            scale: 0.5,
+            seed: Some(0),
+        })),
+        Box::new(RadialBlurProcessor::new(RadialBlurProcessorOptions {
+            center: None,
+            angle: Angle::from_degrees(10.0),
+        })),
+        Box::new(SaltAndPepperNoiseProcessor::new(
+            SaltAndPepperNoiseProcessorOptions {
+                amount: 0.2,
+                salt_ratio: 0.5,
+                seed: Some(0),
+            },
+        )),
+        Box::new(SpeckleNoiseProcessor::new(SpeckleNoiseProcessorOptions {
+            sigma: 0.2,
+            seed: Some(0),
+        })),
+        Box::new(ZoomBlurProcessor::new(ZoomBlurProcessorOptions {
+            center: None,
+            strength: 0.3,
+        })),
+    ];
+
+    for function in [
+        ConductionFunction::Exponential,
+        ConductionFunction::Quadratic,
+    ] {
+        processors.push(Box::new(AnisotropicDiffusionProcessor::new(
+            AnisotropicDiffusionProcessorOptions {
+                iterations: 2,
+                kappa: 20.0,
+                lambda: 0.2,
+                function,
+            },
+        )));
+    }
+    for use_fast_approximation in [false, true] {
+        processors.push(Box::new(BilateralBlurProcessor::new(
+            BilateralBlurProcessorOptions {
+                radius: RADIUS,
+                sigma_spatial: 1.0,
+                sigma_intensity: 20.0,
+                use_fast_approximation,
+            },
+        )));
+        processors.push(Box::new(GaussianBlurProcessor::new(
+            GaussianBlurProcessorOptions {
+                radius: RADIUS,
+                sigma: 1.0,
+                use_fast_approximation,
+            },
+        )));
+        processors.push(Box::new(MeanBlurProcessor::new(MeanBlurProcessorOptions {
+            radius: RADIUS,
+            use_fast_approximation,
+        })));
+    }
+    for method in [
+        SharpenMethod::Kernel,
+        SharpenMethod::UnsharpMask,
+        SharpenMethod::HighPass,
+    ] {
+        for luminance_only in [false, true] {
+            processors.push(Box::new(SharpenProcessor::new(SharpenProcessorOptions {
+                method,
+                radius: RADIUS,
+                sigma: 1.0,
+                amount: 1.5,
+                threshold: 2.0,
+                luminance_only,
+                use_fast_approximation: false,
+            })));
+        }
+    }
+
+    processors
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn noise_processors_should_keep_size(image in fast_image_strategy()) {
+        check_processors(noise_processors(), &image)?;
+    }
+}
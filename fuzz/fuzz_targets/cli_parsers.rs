@@ -0,0 +1,40 @@
+#![no_main]
+
+use std::ffi::OsStr;
+use std::os::unix::ffi::OsStrExt;
+
+use clap::builder::TypedValueParser;
+use clap::Command;
+use libfuzzer_sys::fuzz_target;
+
+#[allow(dead_code)]
+#[path = "../../crates/cli/src/commands/parsers/mod.rs"]
+mod parsers;
+
+use parsers::angle::AngleValueParser;
+use parsers::channels::ChannelsValueParser;
+use parsers::color::ColorValueParser;
+use parsers::coord::CoordValueParser;
+use parsers::crop_border::CropBorderValueParser;
+use parsers::curve::CurveValueParser;
+use parsers::image_format::ImageFormatValueParser;
+use parsers::kernel::KernelValueParser;
+use parsers::scale_strategy::ScaleStrategyValueParser;
+use parsers::size::SizeValueParser;
+
+// every parser has to turn arbitrary input, including invalid utf-8, into a value or an error
+fuzz_target!(|data: &[u8]| {
+    let command = Command::new("fuzz");
+    let value = OsStr::from_bytes(data);
+
+    let _ = AngleValueParser::new().parse_ref(&command, None, value);
+    let _ = ChannelsValueParser::new().parse_ref(&command, None, value);
+    let _ = ColorValueParser::new().parse_ref(&command, None, value);
+    let _ = CoordValueParser::new().parse_ref(&command, None, value);
+    let _ = CropBorderValueParser::new().parse_ref(&command, None, value);
+    let _ = CurveValueParser::new().parse_ref(&command, None, value);
+    let _ = ImageFormatValueParser::new().parse_ref(&command, None, value);
+    let _ = KernelValueParser::new().parse_ref(&command, None, value);
+    let _ = ScaleStrategyValueParser::new().parse_ref(&command, None, value);
+    let _ = SizeValueParser::new().parse_ref(&command, None, value);
+});
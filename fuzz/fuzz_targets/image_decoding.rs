@@ -0,0 +1,26 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use picturify_core::conversions::icc::ColorProfile;
+use picturify_core::core::fast_image::FastImage;
+use picturify_core::core::io::{ReadFromBytes, WriteToBytes};
+use picturify_core::image::ImageFormat;
+
+// decoding may allocate up to the reader limit of 4 GiB, run with -rss_limit_mb=8192
+fuzz_target!(|data: &[u8]| {
+    // embedded profiles come from the same untrusted files
+    let _ = ColorProfile::from_icc(data);
+
+    let Ok(image) = FastImage::read_from_bytes(data) else {
+        return;
+    };
+    // some formats decode empty images, png can not store them
+    let (width, height): (usize, usize) = image.size().into();
+    if width == 0 || height == 0 {
+        return;
+    }
+    let bytes = image.write_to_bytes(ImageFormat::Png).unwrap();
+    let decoded = FastImage::read_from_bytes(&bytes).unwrap();
+    assert_eq!(image.size(), decoded.size());
+});